@@ -48,4 +48,16 @@ pub enum ImageError {
     /// Error when the kernel length is invalid.
     #[error("Invalid kernel length {0} and {1}")]
     InvalidKernelLength(usize, usize),
+
+    /// Error when the scale factor is invalid.
+    #[error("Invalid scale factor {0}")]
+    InvalidScaleFactor(f32),
+
+    /// Error when the standard deviations of a filter are invalid.
+    #[error("Invalid sigma values ({0}, {1})")]
+    InvalidSigmaValue(f32, f32),
+
+    /// Error when the size of a raw frame buffer does not match the image size.
+    #[error("Buffer size ({0} bytes) does not match the expected size ({1} bytes)")]
+    InvalidBufferSize(usize, usize),
 }
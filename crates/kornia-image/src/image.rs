@@ -1,4 +1,4 @@
-use kornia_tensor::{CpuAllocator, Tensor, Tensor2, Tensor3};
+use kornia_tensor::{CpuAllocator, DTypeElement, DynTensor, Tensor, Tensor2, Tensor3};
 
 use crate::error::ImageError;
 
@@ -195,6 +195,36 @@ impl<T, const C: usize> Image<T, C> {
         Tensor::from_raw_parts([size.height, size.width, C], data, len, CpuAllocator)?.try_into()
     }
 
+    /// Create a new image wrapping pixel data owned by a foreign owner without copying.
+    ///
+    /// Useful to wrap camera frames, e.g. GStreamer buffers, whose memory must be released by
+    /// their owner. The `release` callback is called when the image is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the image in pixels.
+    /// * `data` - A pointer to the pixel data.
+    /// * `release` - The callback releasing the pixel data.
+    ///
+    /// # Returns
+    ///
+    /// A new image wrapping the given pixel data.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be aligned for `T` and valid for reads and writes of
+    /// `width * height * C` elements until `release` is called.
+    pub unsafe fn from_foreign<F>(
+        size: ImageSize,
+        data: std::ptr::NonNull<T>,
+        release: F,
+    ) -> Result<Self, ImageError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        Tensor::from_foreign([size.height, size.width, C], data, CpuAllocator, release)?.try_into()
+    }
+
     /// Create a new image from a slice of pixel data.
     ///
     /// # Arguments
@@ -526,6 +556,41 @@ impl<T, const C: usize> TryInto<Tensor3<T, CpuAllocator>> for Image<T, C> {
     }
 }
 
+/// helper to convert a dynamic tensor to a kornia image with try into
+///
+/// The tensor must have shape `(H, W, C)`, or `(H, W)` for single channel images.
+impl<T: DTypeElement, const C: usize> TryFrom<DynTensor> for Image<T, C> {
+    type Error = ImageError;
+
+    fn try_from(value: DynTensor) -> Result<Self, Self::Error> {
+        match *value.shape() {
+            [_, _, _] => Self::try_from(value.try_into_tensor::<T, 3>()?),
+            [_, _] if C == 1 => {
+                let tensor = value.try_into_tensor::<T, 2>()?;
+                let [height, width] = tensor.shape;
+                Ok(Self(Tensor {
+                    storage: tensor.storage,
+                    shape: [height, width, C],
+                    strides: [width * C, C, 1],
+                }))
+            }
+            [_, _] => Err(ImageError::InvalidChannelShape(1, C)),
+            _ => Err(ImageError::InvalidImageShape(
+                kornia_tensor::TensorError::DimensionMismatch(format!(
+                    "cannot convert a tensor of rank {} into an image",
+                    value.rank()
+                )),
+            )),
+        }
+    }
+}
+
+impl<T: DTypeElement, const C: usize> From<Image<T, C>> for DynTensor {
+    fn from(image: Image<T, C>) -> Self {
+        DynTensor::from(image.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::image::{Image, ImageError, ImageSize};
@@ -725,6 +790,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_image_from_foreign() -> Result<(), ImageError> {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let mut data = vec![0u8, 1, 2, 3, 4, 5];
+        let released = Arc::new(AtomicBool::new(false));
+        let ptr = std::ptr::NonNull::new(data.as_mut_ptr()).expect("non null pointer");
+
+        let image = unsafe {
+            let released = released.clone();
+            Image::<_, 2>::from_foreign([3, 1].into(), ptr, move || {
+                released.store(true, Ordering::SeqCst)
+            })?
+        };
+        assert_eq!(image.size(), [3, 1].into());
+        assert_eq!(image.get_pixel(2, 0, 1)?, &5);
+
+        drop(image);
+        assert!(released.load(Ordering::SeqCst));
+        assert_eq!(data, vec![0, 1, 2, 3, 4, 5]);
+        Ok(())
+    }
+
     #[test]
     fn test_get_pixel() -> Result<(), ImageError> {
         let image = Image::<u8, 3>::new(
@@ -761,4 +850,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_image_dyn_tensor() -> Result<(), ImageError> {
+        use kornia_tensor::{DType, DynTensor};
+
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 1,
+            },
+            vec![1, 2, 3, 4, 5, 6],
+        )?;
+        let dyn_tensor = DynTensor::from(image);
+        assert_eq!(dyn_tensor.dtype(), DType::U8);
+        assert_eq!(dyn_tensor.shape(), &[1, 2, 3]);
+
+        let image = Image::<u8, 3>::try_from(dyn_tensor.clone())?;
+        assert_eq!(image.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert!(Image::<u8, 1>::try_from(dyn_tensor.clone()).is_err());
+        assert!(Image::<f32, 3>::try_from(dyn_tensor).is_err());
+
+        let gray = DynTensor::from_shape_vec(vec![2, 3], vec![0.5f32; 6])?;
+        let image = Image::<f32, 1>::try_from(gray.clone())?;
+        assert_eq!(image.size(), [3, 2].into());
+        assert_eq!(image.strides, [3, 1, 1]);
+        assert!(Image::<f32, 3>::try_from(gray).is_err());
+
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
 use kornia_image::Image;
-use kornia_imgproc::color::{gray_from_rgb, gray_from_rgb_u8};
+use kornia_imgproc::color::{
+    gray_from_rgb, gray_from_rgb_u8, rgb_from_bayer, rgb_from_nv12, rgb_from_yuyv, BayerPattern,
+    DemosaicMethod, YuvFormat, YuvRange, YuvStandard,
+};
 
 // vanilla version
 fn gray_vanilla_get_unchecked(
@@ -131,5 +134,84 @@ fn bench_grayscale(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_grayscale);
+fn bench_yuv(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Yuv");
+
+    for (width, height) in [(640, 480), (1280, 720), (1920, 1080)].iter() {
+        group.throughput(criterion::Throughput::Elements((*width * *height) as u64));
+
+        let parameter_string = format!("{}x{}", width, height);
+        let image_size = [*width, *height].into();
+
+        let yuyv = vec![128u8; YuvFormat::Yuyv.buffer_size(image_size)];
+        let nv12 = vec![128u8; YuvFormat::Nv12.buffer_size(image_size)];
+        let rgb = Image::<u8, 3>::from_size_val(image_size, 0).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("rgb_from_yuyv", &parameter_string),
+            &(&yuyv, &rgb),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| {
+                    black_box(rgb_from_yuyv(
+                        src,
+                        &mut dst,
+                        YuvStandard::Bt601,
+                        YuvRange::Limited,
+                    ))
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("rgb_from_nv12", &parameter_string),
+            &(&nv12, &rgb),
+            |b, i| {
+                let (src, mut dst) = (i.0, i.1.clone());
+                b.iter(|| {
+                    black_box(rgb_from_nv12(
+                        src,
+                        &mut dst,
+                        YuvStandard::Bt709,
+                        YuvRange::Limited,
+                    ))
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_bayer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Bayer");
+
+    for (width, height) in [(640, 480), (1280, 720), (1920, 1080)].iter() {
+        group.throughput(criterion::Throughput::Elements((*width * *height) as u64));
+
+        let parameter_string = format!("{}x{}", width, height);
+        let image_size = [*width, *height].into();
+
+        let raw = Image::<u16, 1>::new(
+            image_size,
+            (0..width * height).map(|i| (i % 4096) as u16).collect(),
+        )
+        .unwrap();
+        let rgb = Image::<u16, 3>::from_size_val(image_size, 0).unwrap();
+
+        for method in [DemosaicMethod::Bilinear, DemosaicMethod::Malvar] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("rgb_from_bayer_{method:?}"), &parameter_string),
+                &(&raw, &rgb),
+                |b, i| {
+                    let (src, mut dst) = (i.0, i.1.clone());
+                    b.iter(|| black_box(rgb_from_bayer(src, &mut dst, BayerPattern::Rggb, method)))
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_grayscale, bench_yuv, bench_bayer);
 criterion_main!(benches);
@@ -1,7 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
 use kornia_image::Image;
-use kornia_imgproc::filter::{box_blur_fast, gaussian_blur};
+use kornia_imgproc::filter::{box_blur_fast, gaussian_blur, BorderType};
 
 use image::RgbImage;
 use imageproc::filter::gaussian_blur_f32;
@@ -37,6 +37,7 @@ fn bench_filters(c: &mut Criterion) {
                             &mut dst,
                             (*kernel_size, *kernel_size),
                             (1.5, 1.5),
+                            BorderType::Constant(0.0),
                         ))
                     })
                 },
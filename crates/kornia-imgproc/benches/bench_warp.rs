@@ -2,7 +2,7 @@ use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criteri
 
 use kornia_image::Image;
 use kornia_imgproc::{
-    interpolation::InterpolationMode,
+    interpolation::{BorderType, InterpolationMode},
     warp::{get_rotation_matrix2d, warp_affine, warp_perspective},
 };
 
@@ -34,6 +34,7 @@ fn bench_warp_affine(c: &mut Criterion) {
                         black_box(&mut dst),
                         black_box(&m),
                         black_box(InterpolationMode::Bilinear),
+                        black_box(BorderType::Constant(0.0)),
                     )
                 })
             },
@@ -70,6 +71,7 @@ fn bench_warp_perspective(c: &mut Criterion) {
                         black_box(&mut dst),
                         black_box(&m),
                         black_box(InterpolationMode::Bilinear),
+                        black_box(BorderType::Constant(0.0)),
                     )
                 })
             },
@@ -0,0 +1,188 @@
+use std::f32::consts::TAU;
+
+use super::{convert_pixels, saturate_u8, unit_from_u8};
+use kornia_image::{Image, ImageError};
+
+#[inline]
+fn hls_from_rgb_pixel([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let l = (max + min) / 2.0;
+
+    if delta == 0.0 {
+        return [0.0, l, 0.0];
+    }
+
+    let s = if l < 0.5 {
+        delta / (max + min)
+    } else {
+        delta / (2.0 - max - min)
+    };
+
+    // hue sector in [0, 6)
+    let h = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+
+    [h / 6.0 * TAU, l, s]
+}
+
+#[inline]
+fn rgb_from_hls_pixel([h, l, s]: [f32; 3]) -> [f32; 3] {
+    let h = h * 12.0 / TAU;
+    let a = s * l.min(1.0 - l);
+    [0.0, 8.0, 4.0].map(|n| {
+        let k = (n + h).rem_euclid(12.0);
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    })
+}
+
+/// Convert an RGB image to HLS.
+///
+/// The conversion matches `kornia.color.rgb_to_hls`.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image with values in the range [0, 1].
+/// * `dst` - The output HLS image with the hue in radians in the range [0, 2π), and the
+///   lightness and the saturation in the range [0, 1].
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::hls_from_rgb;
+///
+/// let image = Image::<f32, 3>::new(
+///     ImageSize {
+///         width: 1,
+///         height: 1,
+///     },
+///     vec![0.0, 0.0, 1.0],
+/// )
+/// .unwrap();
+///
+/// let mut hls = Image::<f32, 3>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// hls_from_rgb(&image, &mut hls).unwrap();
+/// assert!((hls.as_slice()[0] - 4.0 * std::f32::consts::PI / 3.0).abs() < 1e-5);
+/// assert_eq!(hls.as_slice()[1..], [0.5, 1.0]);
+/// ```
+pub fn hls_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, hls_from_rgb_pixel)
+}
+
+/// Convert an HLS image to RGB.
+///
+/// This is the inverse of [`hls_from_rgb`] and matches `kornia.color.hls_to_rgb`.
+///
+/// # Arguments
+///
+/// * `src` - The input HLS image with the hue in radians in the range [0, 2π), and the
+///   lightness and the saturation in the range [0, 1].
+/// * `dst` - The output RGB image with values in the range [0, 1].
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_hls(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, rgb_from_hls_pixel)
+}
+
+/// Convert an RGB8 image to an HLS8 image.
+///
+/// The hue is scaled to the range [0, 255] like in [`super::hsv_from_rgb`], and the lightness
+/// and the saturation are scaled to the range [0, 255].
+///
+/// # Arguments
+///
+/// * `src` - The input RGB8 image.
+/// * `dst` - The output HLS8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn hls_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| {
+        let [h, l, s] = hls_from_rgb_pixel(p.map(unit_from_u8));
+        [h / TAU * 255.0, l * 255.0, s * 255.0].map(saturate_u8)
+    })
+}
+
+/// Convert an HLS8 image to an RGB8 image.
+///
+/// This is the inverse of [`hls_from_rgb_u8`].
+///
+/// # Arguments
+///
+/// * `src` - The input HLS8 image.
+/// * `dst` - The output RGB8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_hls_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |[h, l, s]| {
+        let hls = [h as f32 / 255.0 * TAU, unit_from_u8(l), unit_from_u8(s)];
+        rgb_from_hls_pixel(hls).map(|x| saturate_u8(x * 255.0))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn hls_roundtrip() -> Result<(), ImageError> {
+        let data = (0..48u32)
+            .flat_map(|i| {
+                [
+                    (i * 5 % 256) as f32 / 255.0,
+                    (i * 37 % 256) as f32 / 255.0,
+                    (255 - i * 3) as f32 / 255.0,
+                ]
+            })
+            .collect::<Vec<_>>();
+        let image = Image::<f32, 3>::new(
+            ImageSize {
+                width: 8,
+                height: 6,
+            },
+            data,
+        )?;
+
+        let mut hls = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::hls_from_rgb(&image, &mut hls)?;
+        let mut rgb = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::rgb_from_hls(&hls, &mut rgb)?;
+
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((a - b).abs() < 1e-5, "{a} != {b}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn hls_u8() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 3,
+                height: 1,
+            },
+            vec![0, 255, 0, 128, 64, 32, 10, 10, 10],
+        )?;
+
+        let mut hls = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::hls_from_rgb_u8(&image, &mut hls)?;
+        assert_eq!(hls.as_slice()[..3], [85, 128, 255]);
+        assert_eq!(hls.as_slice()[6..], [0, 10, 0]);
+
+        let mut rgb = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::rgb_from_hls_u8(&hls, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((*a as i32 - *b as i32).abs() <= 2, "{a} != {b}");
+        }
+        Ok(())
+    }
+}
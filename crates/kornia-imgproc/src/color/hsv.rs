@@ -1,3 +1,4 @@
+use super::{convert_pixels, saturate_u8};
 use crate::parallel;
 use kornia_image::{Image, ImageError};
 
@@ -45,7 +46,10 @@ use kornia_image::{Image, ImageError};
 /// assert_eq!(hsv.size().width, 4);
 /// assert_eq!(hsv.size().height, 5);
 /// ```
-pub fn hsv_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+pub fn hsv_from_rgb<T>(src: &Image<T, 3>, dst: &mut Image<T, 3>) -> Result<(), ImageError>
+where
+    T: Send + Sync + num_traits::Float,
+{
     if src.size() != dst.size() {
         return Err(ImageError::InvalidImageSize(
             src.cols(),
@@ -55,42 +59,49 @@ pub fn hsv_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(),
         ));
     }
 
+    let c255 = T::from(255.0).ok_or(ImageError::CastError)?;
+    let c360 = T::from(360.0).ok_or(ImageError::CastError)?;
+    let c60 = T::from(60.0).ok_or(ImageError::CastError)?;
+    let c6 = T::from(6.0).ok_or(ImageError::CastError)?;
+    let c2 = T::from(2.0).ok_or(ImageError::CastError)?;
+    let c4 = T::from(4.0).ok_or(ImageError::CastError)?;
+
     // compute the HSV values
     parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
         // Normalize the input to the range [0, 1]
-        let r = src_pixel[0] / 255.;
-        let g = src_pixel[1] / 255.;
-        let b = src_pixel[2] / 255.;
+        let r = src_pixel[0] / c255;
+        let g = src_pixel[1] / c255;
+        let b = src_pixel[2] / c255;
 
         let max = r.max(g).max(b);
         let min = r.min(g).min(b);
         let delta = max - min;
 
-        let h = if delta == 0.0 {
-            0.0
+        let h = if delta == T::zero() {
+            T::zero()
         } else if max == r {
-            60.0 * (((g - b) / delta) % 6.0)
+            c60 * (((g - b) / delta) % c6)
         } else if max == g {
-            60.0 * (((b - r) / delta) + 2.0)
+            c60 * (((b - r) / delta) + c2)
         } else {
-            60.0 * (((r - g) / delta) + 4.0)
+            c60 * (((r - g) / delta) + c4)
         };
 
         // Ensure h is in the range [0, 360)
 
-        let h = if h < 0.0 { h + 360.0 } else { h };
+        let h = if h < T::zero() { h + c360 } else { h };
 
         // scale h to [0, 255]
 
-        let h = (h / 360.0) * 255.0;
+        let h = (h / c360) * c255;
 
-        let s = if max == 0.0 {
-            0.0
+        let s = if max == T::zero() {
+            T::zero()
         } else {
-            (delta / max) * 255.0
+            (delta / max) * c255
         };
 
-        let v = max * 255.0;
+        let v = max * c255;
 
         dst_pixel[0] = h;
         dst_pixel[1] = s;
@@ -100,6 +111,104 @@ pub fn hsv_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(),
     Ok(())
 }
 
+/// Convert an HSV image to an RGB image.
+///
+/// This is the inverse of [`hsv_from_rgb`] and uses the same ranges.
+///
+/// # Arguments
+///
+/// * `src` - The input HSV image with the following channels:
+///   * H: The hue channel in the range [0, 255] (0-360 degrees).
+///   * S: The saturation channel in the range [0, 255].
+///   * V: The value channel in the range [0, 255].
+/// * `dst` - The output RGB image with values in the range [0, 255].
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::rgb_from_hsv;
+///
+/// let hsv = Image::<f32, 3>::new(
+///     ImageSize {
+///         width: 1,
+///         height: 1,
+///     },
+///     vec![85.0, 255.0, 255.0],
+/// )
+/// .unwrap();
+///
+/// let mut rgb = Image::<f32, 3>::from_size_val(hsv.size(), 0.0).unwrap();
+///
+/// rgb_from_hsv(&hsv, &mut rgb).unwrap();
+/// assert_eq!(rgb.as_slice(), &[0.0, 255.0, 0.0]);
+/// ```
+pub fn rgb_from_hsv<T>(src: &Image<T, 3>, dst: &mut Image<T, 3>) -> Result<(), ImageError>
+where
+    T: Send + Sync + num_traits::Float,
+{
+    let c255 = T::from(255.0).ok_or(ImageError::CastError)?;
+    let c6 = T::from(6.0).ok_or(ImageError::CastError)?;
+
+    convert_pixels(src, dst, |[h, s, v]| {
+        // sector of the hue in [0, 6) and position inside the sector
+        let h = (h / c255 * c6) % c6;
+        let h = if h < T::zero() { h + c6 } else { h };
+        let sector = h.floor();
+        let f = h - sector;
+
+        let s = s / c255;
+        let p = v * (T::one() - s);
+        let q = v * (T::one() - s * f);
+        let t = v * (T::one() - s * (T::one() - f));
+
+        match sector.to_u8().unwrap_or(0) {
+            0 => [v, t, p],
+            1 => [q, v, p],
+            2 => [p, v, t],
+            3 => [p, q, v],
+            4 => [t, p, v],
+            _ => [v, p, q],
+        }
+    })
+}
+
+/// Convert an RGB8 image to an HSV8 image.
+///
+/// The channels have the same ranges as [`hsv_from_rgb`], rounded to the nearest integer.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB8 image.
+/// * `dst` - The output HSV8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn hsv_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    let src = src.cast::<f32>()?;
+    let mut hsv = Image::<f32, 3>::from_size_val(src.size(), 0.0)?;
+    hsv_from_rgb(&src, &mut hsv)?;
+    convert_pixels(&hsv, dst, |p| p.map(saturate_u8))
+}
+
+/// Convert an HSV8 image to an RGB8 image.
+///
+/// This is the inverse of [`hsv_from_rgb_u8`].
+///
+/// # Arguments
+///
+/// * `src` - The input HSV8 image.
+/// * `dst` - The output RGB8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_hsv_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    let src = src.cast::<f32>()?;
+    let mut rgb = Image::<f32, 3>::from_size_val(src.size(), 0.0)?;
+    rgb_from_hsv(&src, &mut rgb)?;
+    convert_pixels(&rgb, dst, |p| p.map(saturate_u8))
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
@@ -137,4 +246,76 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn rgb_from_hsv_roundtrip() -> Result<(), ImageError> {
+        let data = (0..64u32)
+            .flat_map(|i| [(i * 4) as f32, (i * 37 % 256) as f32, (255 - i * 3) as f32])
+            .collect::<Vec<_>>();
+        let image = Image::<f32, 3>::new(
+            ImageSize {
+                width: 8,
+                height: 8,
+            },
+            data,
+        )?;
+
+        let mut hsv = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::hsv_from_rgb(&image, &mut hsv)?;
+        let mut rgb = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::rgb_from_hsv(&hsv, &mut rgb)?;
+
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((a - b).abs() < 1e-3, "{a} != {b}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn hsv_u8_roundtrip() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 3,
+                height: 1,
+            },
+            vec![255, 0, 0, 10, 200, 30, 90, 90, 90],
+        )?;
+
+        let mut hsv = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::hsv_from_rgb_u8(&image, &mut hsv)?;
+        assert_eq!(hsv.as_slice()[..3], [0, 255, 255]);
+        assert_eq!(hsv.as_slice()[6..], [0, 0, 90]);
+
+        let mut rgb = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::rgb_from_hsv_u8(&hsv, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((*a as i32 - *b as i32).abs() <= 2, "{a} != {b}");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn hsv_from_rgb_bf16() -> Result<(), ImageError> {
+        use half::bf16;
+
+        let image = Image::<_, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 1,
+            },
+            [255.0, 0.0, 0.0, 0.0, 0.0, 255.0]
+                .map(bf16::from_f32)
+                .to_vec(),
+        )?;
+        let mut hsv = Image::from_size_val(image.size(), bf16::ZERO)?;
+        super::hsv_from_rgb(&image, &mut hsv)?;
+
+        let expected = [0.0, 255.0, 255.0, 170.0, 255.0, 255.0];
+        for (x, y) in hsv.as_slice().iter().zip(expected) {
+            assert!((x.to_f32() - y).abs() < 1.0);
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,180 @@
+use super::{
+    convert_pixels, saturate_u8, unit_from_u8,
+    xyz::{
+        linear_from_srgb, linear_rgb_from_xyz, srgb_from_linear, xyz_from_linear_rgb, WHITE_D65,
+    },
+};
+use kornia_image::{Image, ImageError};
+
+/// The threshold between the linear and the cube root parts of the CIE Lab function.
+const THRESHOLD: f32 = 0.008856;
+
+#[inline]
+fn lab_from_rgb_pixel(rgb: [f32; 3]) -> [f32; 3] {
+    let xyz = xyz_from_linear_rgb(rgb.map(linear_from_srgb));
+    let [fx, fy, fz] = [0, 1, 2].map(|i| {
+        let t = xyz[i] / WHITE_D65[i];
+        if t > THRESHOLD {
+            t.cbrt()
+        } else {
+            7.787 * t + 4.0 / 29.0
+        }
+    });
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+#[inline]
+fn rgb_from_lab_pixel([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = (fy - b / 200.0).max(0.0);
+    let f = [fx, fy, fz];
+    let xyz = [0, 1, 2].map(|i| {
+        let t = if f[i] > 0.2068966 {
+            f[i].powi(3)
+        } else {
+            (f[i] - 4.0 / 29.0) / 7.787
+        };
+        t * WHITE_D65[i]
+    });
+    linear_rgb_from_xyz(xyz).map(|x| srgb_from_linear(x).clamp(0.0, 1.0))
+}
+
+/// Convert an RGB image to CIE Lab.
+///
+/// The conversion matches `kornia.color.rgb_to_lab`: the sRGB values are linearized and
+/// converted with the D65 reference white.
+///
+/// # Arguments
+///
+/// * `src` - The input sRGB image with values in the range [0, 1].
+/// * `dst` - The output Lab image with L in [0, 100], and a and b in [-128, 127].
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::lab_from_rgb;
+///
+/// let image = Image::<f32, 3>::new(
+///     ImageSize {
+///         width: 1,
+///         height: 1,
+///     },
+///     vec![1.0, 1.0, 1.0],
+/// )
+/// .unwrap();
+///
+/// let mut lab = Image::<f32, 3>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// lab_from_rgb(&image, &mut lab).unwrap();
+/// assert!((lab.as_slice()[0] - 100.0).abs() < 1e-3);
+/// ```
+pub fn lab_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, lab_from_rgb_pixel)
+}
+
+/// Convert a CIE Lab image to RGB.
+///
+/// This is the inverse of [`lab_from_rgb`] and matches `kornia.color.lab_to_rgb`, the output is
+/// clipped to the range [0, 1].
+///
+/// # Arguments
+///
+/// * `src` - The input Lab image with L in [0, 100], and a and b in [-128, 127].
+/// * `dst` - The output sRGB image with values in the range [0, 1].
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_lab(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, rgb_from_lab_pixel)
+}
+
+/// Convert an RGB8 image to an 8-bit CIE Lab image.
+///
+/// The values of [`lab_from_rgb`] are encoded as L * 255 / 100, a + 128 and b + 128.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB8 image.
+/// * `dst` - The output Lab8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn lab_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| {
+        let [l, a, b] = lab_from_rgb_pixel(p.map(unit_from_u8));
+        [l * 255.0 / 100.0, a + 128.0, b + 128.0].map(saturate_u8)
+    })
+}
+
+/// Convert an 8-bit CIE Lab image to an RGB8 image.
+///
+/// This is the inverse of [`lab_from_rgb_u8`].
+///
+/// # Arguments
+///
+/// * `src` - The input Lab8 image.
+/// * `dst` - The output RGB8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_lab_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |[l, a, b]| {
+        let lab = [l as f32 * 100.0 / 255.0, a as f32 - 128.0, b as f32 - 128.0];
+        rgb_from_lab_pixel(lab).map(|x| saturate_u8(x * 255.0))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn lab_reference_values() -> Result<(), ImageError> {
+        let image = Image::<f32, 3>::new(
+            ImageSize {
+                width: 3,
+                height: 1,
+            },
+            vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5],
+        )?;
+
+        let mut lab = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::lab_from_rgb(&image, &mut lab)?;
+
+        // reference values of sRGB red, black and middle gray
+        let expected = [53.24, 80.09, 67.20, 0.0, 0.0, 0.0, 53.39, 0.0, 0.0];
+        for (a, b) in lab.as_slice().iter().zip(expected) {
+            assert!((a - b).abs() < 0.05, "{a} != {b}");
+        }
+
+        let mut rgb = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::rgb_from_lab(&lab, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((a - b).abs() < 1e-4, "{a} != {b}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn lab_u8_roundtrip() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 4,
+                height: 1,
+            },
+            vec![255, 0, 0, 60, 120, 180, 128, 128, 128, 255, 255, 255],
+        )?;
+
+        let mut lab = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::lab_from_rgb_u8(&image, &mut lab)?;
+        assert_eq!(lab.as_slice()[9..], [255, 128, 128]);
+
+        let mut rgb = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::rgb_from_lab_u8(&lab, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((*a as i32 - *b as i32).abs() <= 3, "{a} != {b}");
+        }
+        Ok(())
+    }
+}
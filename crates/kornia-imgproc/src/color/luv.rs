@@ -0,0 +1,195 @@
+use super::{
+    convert_pixels, saturate_u8, unit_from_u8,
+    xyz::{
+        linear_from_srgb, linear_rgb_from_xyz, srgb_from_linear, xyz_from_linear_rgb, WHITE_D65,
+    },
+};
+use kornia_image::{Image, ImageError};
+
+/// A small value to avoid divisions by zero for black pixels.
+const EPS: f32 = 1e-12;
+
+/// The chromaticity coordinates u' and v' of the reference white.
+fn white_uv() -> (f32, f32) {
+    let [xw, yw, zw] = WHITE_D65;
+    let d = xw + 15.0 * yw + 3.0 * zw;
+    (4.0 * xw / d, 9.0 * yw / d)
+}
+
+#[inline]
+fn luv_from_rgb_pixel(rgb: [f32; 3]) -> [f32; 3] {
+    let [x, y, z] = xyz_from_linear_rgb(rgb.map(linear_from_srgb));
+    let l = if y > 0.008856 {
+        116.0 * y.cbrt() - 16.0
+    } else {
+        903.3 * y
+    };
+    let (u_w, v_w) = white_uv();
+    let d = x + 15.0 * y + 3.0 * z + EPS;
+    let u = 13.0 * l * (4.0 * x / d - u_w);
+    let v = 13.0 * l * (9.0 * y / d - v_w);
+    [l, u, v]
+}
+
+#[inline]
+fn rgb_from_luv_pixel([l, u, v]: [f32; 3]) -> [f32; 3] {
+    let y = if l > 7.999625 {
+        ((l + 16.0) / 116.0).powi(3)
+    } else {
+        l / 903.3
+    };
+    let (u_w, v_w) = white_uv();
+    let a = u_w + u / (13.0 * l + EPS);
+    let d = v_w + v / (13.0 * l + EPS);
+    let c = 3.0 * y * (5.0 * d - 3.0);
+    let z = ((a - 4.0) * c - 15.0 * a * d * y) / (12.0 * d);
+    let x = -(c / d + 3.0 * z);
+    linear_rgb_from_xyz([x, y, z]).map(srgb_from_linear)
+}
+
+/// Convert an RGB image to CIE Luv.
+///
+/// The conversion matches `kornia.color.rgb_to_luv`: the sRGB values are linearized and
+/// converted with the D65 reference white.
+///
+/// # Arguments
+///
+/// * `src` - The input sRGB image with values in the range [0, 1].
+/// * `dst` - The output Luv image with L in [0, 100], u in [-134, 220] and v in [-140, 122].
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::luv_from_rgb;
+///
+/// let image = Image::<f32, 3>::new(
+///     ImageSize {
+///         width: 1,
+///         height: 1,
+///     },
+///     vec![1.0, 1.0, 1.0],
+/// )
+/// .unwrap();
+///
+/// let mut luv = Image::<f32, 3>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// luv_from_rgb(&image, &mut luv).unwrap();
+/// assert!((luv.as_slice()[0] - 100.0).abs() < 1e-3);
+/// ```
+pub fn luv_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, luv_from_rgb_pixel)
+}
+
+/// Convert a CIE Luv image to RGB.
+///
+/// This is the inverse of [`luv_from_rgb`] and matches `kornia.color.luv_to_rgb`.
+///
+/// # Arguments
+///
+/// * `src` - The input Luv image with L in [0, 100], u in [-134, 220] and v in [-140, 122].
+/// * `dst` - The output sRGB image with values in the range [0, 1] for colors in the gamut.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_luv(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, rgb_from_luv_pixel)
+}
+
+/// Convert an RGB8 image to an 8-bit CIE Luv image.
+///
+/// The values of [`luv_from_rgb`] are encoded as L * 255 / 100, (u + 134) * 255 / 354 and
+/// (v + 140) * 255 / 262.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB8 image.
+/// * `dst` - The output Luv8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn luv_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| {
+        let [l, u, v] = luv_from_rgb_pixel(p.map(unit_from_u8));
+        [
+            l * 255.0 / 100.0,
+            (u + 134.0) * 255.0 / 354.0,
+            (v + 140.0) * 255.0 / 262.0,
+        ]
+        .map(saturate_u8)
+    })
+}
+
+/// Convert an 8-bit CIE Luv image to an RGB8 image.
+///
+/// This is the inverse of [`luv_from_rgb_u8`].
+///
+/// # Arguments
+///
+/// * `src` - The input Luv8 image.
+/// * `dst` - The output RGB8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_luv_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |[l, u, v]| {
+        let luv = [
+            l as f32 * 100.0 / 255.0,
+            u as f32 * 354.0 / 255.0 - 134.0,
+            v as f32 * 262.0 / 255.0 - 140.0,
+        ];
+        rgb_from_luv_pixel(luv).map(|x| saturate_u8(x * 255.0))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn luv_reference_values() -> Result<(), ImageError> {
+        let image = Image::<f32, 3>::new(
+            ImageSize {
+                width: 3,
+                height: 1,
+            },
+            vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2, 0.6, 0.9],
+        )?;
+
+        let mut luv = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::luv_from_rgb(&image, &mut luv)?;
+
+        // reference values of sRGB red and black
+        let expected = [53.24, 175.01, 37.76, 0.0, 0.0, 0.0];
+        for (a, b) in luv.as_slice().iter().zip(expected) {
+            assert!((a - b).abs() < 0.1, "{a} != {b}");
+        }
+
+        let mut rgb = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::rgb_from_luv(&luv, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((a - b).abs() < 1e-4, "{a} != {b}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn luv_u8_roundtrip() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 3,
+                height: 1,
+            },
+            vec![255, 0, 0, 60, 120, 180, 128, 128, 128],
+        )?;
+
+        let mut luv = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::luv_from_rgb_u8(&image, &mut luv)?;
+
+        let mut rgb = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::rgb_from_luv_u8(&luv, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((*a as i32 - *b as i32).abs() <= 3, "{a} != {b}");
+        }
+        Ok(())
+    }
+}
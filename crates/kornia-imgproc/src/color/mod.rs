@@ -1,5 +1,63 @@
+mod bayer;
 mod gray;
+mod hls;
 mod hsv;
+mod lab;
+mod luv;
+mod xyz;
+mod ycrcb;
+mod yuv;
 
+pub use bayer::{rgb_from_bayer, BayerPattern, DemosaicMethod};
 pub use gray::{bgr_from_rgb, gray_from_rgb, gray_from_rgb_u8, rgb_from_gray};
-pub use hsv::hsv_from_rgb;
+pub use hls::{hls_from_rgb, hls_from_rgb_u8, rgb_from_hls, rgb_from_hls_u8};
+pub use hsv::{hsv_from_rgb, hsv_from_rgb_u8, rgb_from_hsv, rgb_from_hsv_u8};
+pub use lab::{lab_from_rgb, lab_from_rgb_u8, rgb_from_lab, rgb_from_lab_u8};
+pub use luv::{luv_from_rgb, luv_from_rgb_u8, rgb_from_luv, rgb_from_luv_u8};
+pub use xyz::{rgb_from_xyz, rgb_from_xyz_u8, xyz_from_rgb, xyz_from_rgb_u8};
+pub use ycrcb::{rgb_from_ycrcb, rgb_from_ycrcb_u8, ycrcb_from_rgb, ycrcb_from_rgb_u8};
+pub use yuv::{
+    i420_from_rgb, nv12_from_rgb, rgb_from_i420, rgb_from_nv12, rgb_from_uyvy, rgb_from_yuyv,
+    uyvy_from_rgb, yuyv_from_rgb, YuvFormat, YuvRange, YuvStandard,
+};
+
+use crate::parallel;
+use kornia_image::{Image, ImageError};
+
+/// Apply a conversion to each pixel of a 3 channel image in parallel.
+fn convert_pixels<T1, T2>(
+    src: &Image<T1, 3>,
+    dst: &mut Image<T2, 3>,
+    f: impl Fn([T1; 3]) -> [T2; 3] + Send + Sync,
+) -> Result<(), ImageError>
+where
+    T1: Copy + Send + Sync,
+    T2: Copy + Send + Sync,
+{
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+        dst_pixel.copy_from_slice(&f([src_pixel[0], src_pixel[1], src_pixel[2]]));
+    });
+
+    Ok(())
+}
+
+/// Convert an 8-bit value in [0, 255] to a float value in [0, 1].
+#[inline]
+fn unit_from_u8(x: u8) -> f32 {
+    x as f32 / 255.0
+}
+
+/// Round and saturate a float value to an 8-bit value.
+#[inline]
+fn saturate_u8(x: f32) -> u8 {
+    x.round().clamp(0.0, 255.0) as u8
+}
@@ -0,0 +1,163 @@
+use super::{convert_pixels, saturate_u8, unit_from_u8};
+use kornia_image::{Image, ImageError};
+
+/// The CIE XYZ coordinates of the D65 reference white.
+pub(super) const WHITE_D65: [f32; 3] = [0.95047, 1.0, 1.08883];
+
+/// Convert a linear RGB pixel to CIE XYZ with the D65 reference white.
+#[inline]
+pub(super) fn xyz_from_linear_rgb([r, g, b]: [f32; 3]) -> [f32; 3] {
+    [
+        0.412453 * r + 0.357580 * g + 0.180423 * b,
+        0.212671 * r + 0.715160 * g + 0.072169 * b,
+        0.019334 * r + 0.119193 * g + 0.950227 * b,
+    ]
+}
+
+/// Convert a CIE XYZ pixel with the D65 reference white to linear RGB.
+#[inline]
+pub(super) fn linear_rgb_from_xyz([x, y, z]: [f32; 3]) -> [f32; 3] {
+    [
+        3.240_481_3 * x - 1.537_151_5 * y - 0.498_536_33 * z,
+        -0.969_255 * x + 1.875_99 * y + 0.041_555_926 * z,
+        0.055_646_64 * x - 0.204_041_34 * y + 1.057_311 * z,
+    ]
+}
+
+/// Remove the sRGB gamma of a value in [0, 1].
+#[inline]
+pub(super) fn linear_from_srgb(x: f32) -> f32 {
+    if x > 0.04045 {
+        ((x + 0.055) / 1.055).powf(2.4)
+    } else {
+        x / 12.92
+    }
+}
+
+/// Apply the sRGB gamma to a linear value in [0, 1].
+#[inline]
+pub(super) fn srgb_from_linear(x: f32) -> f32 {
+    if x > 0.0031308 {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    } else {
+        12.92 * x
+    }
+}
+
+/// Convert an RGB image to CIE XYZ.
+///
+/// The conversion matches `kornia.color.rgb_to_xyz`: the RGB values are assumed to be linear and
+/// are converted with the sRGB primaries and the D65 reference white.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image with values in the range [0, 1].
+/// * `dst` - The output XYZ image with X in [0, 0.95], Y in [0, 1] and Z in [0, 1.09].
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::xyz_from_rgb;
+///
+/// let image = Image::<f32, 3>::new(
+///     ImageSize {
+///         width: 1,
+///         height: 1,
+///     },
+///     vec![1.0, 1.0, 1.0],
+/// )
+/// .unwrap();
+///
+/// let mut xyz = Image::<f32, 3>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// xyz_from_rgb(&image, &mut xyz).unwrap();
+/// assert!((xyz.as_slice()[1] - 1.0).abs() < 1e-5);
+/// ```
+pub fn xyz_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, xyz_from_linear_rgb)
+}
+
+/// Convert a CIE XYZ image to RGB.
+///
+/// This is the inverse of [`xyz_from_rgb`] and matches `kornia.color.xyz_to_rgb`.
+///
+/// # Arguments
+///
+/// * `src` - The input XYZ image.
+/// * `dst` - The output RGB image with values in the range [0, 1] for colors in the gamut.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_xyz(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, linear_rgb_from_xyz)
+}
+
+/// Convert an RGB8 image to an 8-bit CIE XYZ image.
+///
+/// The values of [`xyz_from_rgb`] are scaled by 255 and saturated.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB8 image.
+/// * `dst` - The output XYZ8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn xyz_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| {
+        xyz_from_linear_rgb(p.map(unit_from_u8)).map(|x| saturate_u8(x * 255.0))
+    })
+}
+
+/// Convert an 8-bit CIE XYZ image to an RGB8 image.
+///
+/// This is the inverse of [`xyz_from_rgb_u8`].
+///
+/// # Arguments
+///
+/// * `src` - The input XYZ8 image.
+/// * `dst` - The output RGB8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_xyz_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| {
+        linear_rgb_from_xyz(p.map(unit_from_u8)).map(|x| saturate_u8(x * 255.0))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn xyz_roundtrip() -> Result<(), ImageError> {
+        let image = Image::<f32, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.2, 0.5, 0.8],
+        )?;
+
+        let mut xyz = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::xyz_from_rgb(&image, &mut xyz)?;
+        // the columns of the conversion matrix
+        assert_eq!(xyz.as_slice()[..3], [0.412453, 0.212671, 0.019334]);
+
+        let mut rgb = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::rgb_from_xyz(&xyz, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((a - b).abs() < 1e-4, "{a} != {b}");
+        }
+
+        let image = Image::<u8, 3>::new(
+            image.size(),
+            image.as_slice().iter().map(|x| (x * 255.0) as u8).collect(),
+        )?;
+        let mut xyz = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::xyz_from_rgb_u8(&image, &mut xyz)?;
+        assert_eq!(xyz.as_slice()[..3], [105, 54, 5]);
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use super::{convert_pixels, saturate_u8};
+use kornia_image::{Image, ImageError};
+
+#[inline]
+fn ycrcb_from_rgb_pixel([r, g, b]: [f32; 3], delta: f32) -> [f32; 3] {
+    let y = 0.299 * r + 0.587 * g + 0.114 * b;
+    let cr = (r - y) * 0.713 + delta;
+    let cb = (b - y) * 0.564 + delta;
+    [y, cr, cb]
+}
+
+#[inline]
+fn rgb_from_ycrcb_pixel([y, cr, cb]: [f32; 3], delta: f32) -> [f32; 3] {
+    let cr = cr - delta;
+    let cb = cb - delta;
+    [y + 1.403 * cr, y - 0.714 * cr - 0.344 * cb, y + 1.773 * cb]
+}
+
+/// Convert an RGB image to YCrCb.
+///
+/// The coefficients match `kornia.color.rgb_to_ycbcr`, with the chroma channels in the Cr, Cb
+/// order.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image with values in the range [0, 1].
+/// * `dst` - The output YCrCb image with values in the range [0, 1], the chroma channels are
+///   centered at 0.5.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::ycrcb_from_rgb;
+///
+/// let image = Image::<f32, 3>::new(
+///     ImageSize {
+///         width: 1,
+///         height: 1,
+///     },
+///     vec![0.5, 0.5, 0.5],
+/// )
+/// .unwrap();
+///
+/// let mut ycrcb = Image::<f32, 3>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// ycrcb_from_rgb(&image, &mut ycrcb).unwrap();
+/// assert!((ycrcb.as_slice()[1] - 0.5).abs() < 1e-6);
+/// ```
+pub fn ycrcb_from_rgb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| ycrcb_from_rgb_pixel(p, 0.5))
+}
+
+/// Convert a YCrCb image to RGB.
+///
+/// This is the inverse of [`ycrcb_from_rgb`] and matches `kornia.color.ycbcr_to_rgb`.
+///
+/// # Arguments
+///
+/// * `src` - The input YCrCb image with values in the range [0, 1].
+/// * `dst` - The output RGB image with values in the range [0, 1].
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_ycrcb(src: &Image<f32, 3>, dst: &mut Image<f32, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| rgb_from_ycrcb_pixel(p, 0.5))
+}
+
+/// Convert an RGB8 image to a YCrCb8 image.
+///
+/// The chroma channels are centered at 128.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB8 image.
+/// * `dst` - The output YCrCb8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn ycrcb_from_rgb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| {
+        ycrcb_from_rgb_pixel(p.map(|x| x as f32), 128.0).map(saturate_u8)
+    })
+}
+
+/// Convert a YCrCb8 image to an RGB8 image.
+///
+/// This is the inverse of [`ycrcb_from_rgb_u8`].
+///
+/// # Arguments
+///
+/// * `src` - The input YCrCb8 image.
+/// * `dst` - The output RGB8 image.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn rgb_from_ycrcb_u8(src: &Image<u8, 3>, dst: &mut Image<u8, 3>) -> Result<(), ImageError> {
+    convert_pixels(src, dst, |p| {
+        rgb_from_ycrcb_pixel(p.map(|x| x as f32), 128.0).map(saturate_u8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn ycrcb_roundtrip() -> Result<(), ImageError> {
+        let image = Image::<f32, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 1,
+            },
+            vec![1.0, 0.0, 0.0, 0.2, 0.6, 0.9],
+        )?;
+
+        let mut ycrcb = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::ycrcb_from_rgb(&image, &mut ycrcb)?;
+        let expected = [0.299, 0.5 + 0.701 * 0.713, 0.5 - 0.299 * 0.564];
+        for (a, b) in ycrcb.as_slice().iter().zip(expected) {
+            assert!((a - b).abs() < 1e-6, "{a} != {b}");
+        }
+
+        let mut rgb = Image::<f32, 3>::from_size_val(image.size(), 0.0)?;
+        super::rgb_from_ycrcb(&ycrcb, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((a - b).abs() < 1e-2, "{a} != {b}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn ycrcb_u8() -> Result<(), ImageError> {
+        let image = Image::<u8, 3>::new(
+            ImageSize {
+                width: 2,
+                height: 1,
+            },
+            vec![255, 0, 0, 100, 100, 100],
+        )?;
+
+        let mut ycrcb = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::ycrcb_from_rgb_u8(&image, &mut ycrcb)?;
+        assert_eq!(ycrcb.as_slice(), &[76, 255, 85, 100, 128, 128]);
+
+        let mut rgb = Image::<u8, 3>::from_size_val(image.size(), 0)?;
+        super::rgb_from_ycrcb_u8(&ycrcb, &mut rgb)?;
+        for (a, b) in rgb.as_slice().iter().zip(image.as_slice()) {
+            assert!((*a as i32 - *b as i32).abs() <= 2, "{a} != {b}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,753 @@
+use kornia_image::{Image, ImageError, ImageSize};
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+
+/// The number of fractional bits of the fixed point coefficients.
+const SHIFT: i32 = 16;
+const HALF: i32 = 1 << (SHIFT - 1);
+
+/// The standard defining the YUV (Y'CbCr) conversion matrix.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum YuvStandard {
+    /// ITU-R BT.601, used by standard definition video and most webcams.
+    #[default]
+    Bt601,
+    /// ITU-R BT.709, used by high definition video.
+    Bt709,
+}
+
+impl YuvStandard {
+    /// Returns the luma weights of the red and blue channels.
+    fn kr_kb(&self) -> (f64, f64) {
+        match self {
+            YuvStandard::Bt601 => (0.299, 0.114),
+            YuvStandard::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+/// The range of the YUV values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum YuvRange {
+    /// Limited (studio, TV) range: Y in [16, 235] and U, V in [16, 240].
+    #[default]
+    Limited,
+    /// Full (PC, JPEG) range: Y, U and V in [0, 255].
+    Full,
+}
+
+impl YuvRange {
+    /// Returns the luma offset, the luma scale and the chroma scale with respect to full range.
+    fn scales(&self) -> (i32, f64, f64) {
+        match self {
+            YuvRange::Limited => (16, 219.0 / 255.0, 224.0 / 255.0),
+            YuvRange::Full => (0, 1.0, 1.0),
+        }
+    }
+}
+
+/// The memory layout of a YUV frame.
+///
+/// The chroma planes of odd sized frames are rounded up, e.g. a 5x3 NV12 frame has a 3x2 chroma
+/// plane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YuvFormat {
+    /// Packed 4:2:2 with the byte order Y0 U Y1 V, also known as YUY2.
+    Yuyv,
+    /// Packed 4:2:2 with the byte order U Y0 V Y1.
+    Uyvy,
+    /// Planar 4:2:0 with a Y plane followed by an interleaved UV plane.
+    Nv12,
+    /// Planar 4:2:0 with a Y plane followed by a U plane and a V plane, also known as YU12.
+    I420,
+}
+
+impl YuvFormat {
+    /// Returns the number of bytes of a frame with the given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the frame in pixels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_image::ImageSize;
+    /// use kornia_imgproc::color::YuvFormat;
+    ///
+    /// let size = ImageSize { width: 640, height: 480 };
+    /// assert_eq!(YuvFormat::Yuyv.buffer_size(size), 640 * 480 * 2);
+    /// assert_eq!(YuvFormat::Nv12.buffer_size(size), 640 * 480 * 3 / 2);
+    /// ```
+    pub fn buffer_size(&self, size: ImageSize) -> usize {
+        let cw = size.width.div_ceil(2);
+        let ch = size.height.div_ceil(2);
+        match self {
+            YuvFormat::Yuyv | YuvFormat::Uyvy => 4 * cw * size.height,
+            YuvFormat::Nv12 | YuvFormat::I420 => size.width * size.height + 2 * cw * ch,
+        }
+    }
+
+    /// Returns the offsets of Y0, U, Y1 and V in a packed macropixel.
+    fn packed_offsets(&self) -> [usize; 4] {
+        match self {
+            YuvFormat::Uyvy => [1, 0, 3, 2],
+            _ => [0, 1, 2, 3],
+        }
+    }
+}
+
+fn fixed(x: f64) -> i32 {
+    (x * (1 << SHIFT) as f64).round() as i32
+}
+
+fn clamp_u8(x: i32) -> u8 {
+    x.clamp(0, 255) as u8
+}
+
+/// Fixed point coefficients of the YUV to RGB conversion.
+struct YuvToRgb {
+    y_off: i32,
+    y_mul: i32,
+    v_r: i32,
+    u_g: i32,
+    v_g: i32,
+    u_b: i32,
+}
+
+impl YuvToRgb {
+    fn new(standard: YuvStandard, range: YuvRange) -> Self {
+        let (kr, kb) = standard.kr_kb();
+        let kg = 1.0 - kr - kb;
+        let (y_off, y_scale, c_scale) = range.scales();
+        Self {
+            y_off,
+            y_mul: fixed(1.0 / y_scale),
+            v_r: fixed(2.0 * (1.0 - kr) / c_scale),
+            u_g: fixed(2.0 * kb * (1.0 - kb) / kg / c_scale),
+            v_g: fixed(2.0 * kr * (1.0 - kr) / kg / c_scale),
+            u_b: fixed(2.0 * (1.0 - kb) / c_scale),
+        }
+    }
+
+    #[inline]
+    fn convert(&self, y: u8, u: u8, v: u8, rgb: &mut [u8]) {
+        let y = (y as i32 - self.y_off) * self.y_mul + HALF;
+        let u = u as i32 - 128;
+        let v = v as i32 - 128;
+        rgb[0] = clamp_u8((y + self.v_r * v) >> SHIFT);
+        rgb[1] = clamp_u8((y - self.u_g * u - self.v_g * v) >> SHIFT);
+        rgb[2] = clamp_u8((y + self.u_b * u) >> SHIFT);
+    }
+}
+
+/// Fixed point coefficients of the RGB to YUV conversion.
+struct RgbToYuv {
+    y_off: i32,
+    y: [i32; 3],
+    u: [i32; 3],
+    v: [i32; 3],
+}
+
+impl RgbToYuv {
+    fn new(standard: YuvStandard, range: YuvRange) -> Self {
+        let (kr, kb) = standard.kr_kb();
+        let kg = 1.0 - kr - kb;
+        let (y_off, y_scale, c_scale) = range.scales();
+        let cb = c_scale / (2.0 * (1.0 - kb));
+        let cr = c_scale / (2.0 * (1.0 - kr));
+        Self {
+            y_off,
+            y: [
+                fixed(kr * y_scale),
+                fixed(kg * y_scale),
+                fixed(kb * y_scale),
+            ],
+            u: [fixed(-kr * cb), fixed(-kg * cb), fixed((1.0 - kb) * cb)],
+            v: [fixed((1.0 - kr) * cr), fixed(-kg * cr), fixed(-kb * cr)],
+        }
+    }
+
+    #[inline]
+    fn dot(w: &[i32; 3], rgb: [i32; 3]) -> i32 {
+        (w[0] * rgb[0] + w[1] * rgb[1] + w[2] * rgb[2] + HALF) >> SHIFT
+    }
+
+    #[inline]
+    fn luma(&self, rgb: &[u8]) -> u8 {
+        let rgb = [rgb[0] as i32, rgb[1] as i32, rgb[2] as i32];
+        clamp_u8(Self::dot(&self.y, rgb) + self.y_off)
+    }
+
+    /// Compute the chroma of the mean of a block of pixels.
+    #[inline]
+    fn chroma<'a>(&self, pixels: impl Iterator<Item = &'a [u8]>) -> (u8, u8) {
+        let (mut sum, mut n) = ([0i32; 3], 0);
+        for p in pixels {
+            sum[0] += p[0] as i32;
+            sum[1] += p[1] as i32;
+            sum[2] += p[2] as i32;
+            n += 1;
+        }
+        let rgb = sum.map(|s| (s + n / 2) / n);
+        (
+            clamp_u8(Self::dot(&self.u, rgb) + 128),
+            clamp_u8(Self::dot(&self.v, rgb) + 128),
+        )
+    }
+}
+
+fn check_buffer(format: YuvFormat, size: ImageSize, len: usize) -> Result<(), ImageError> {
+    let expected = format.buffer_size(size);
+    if len != expected {
+        return Err(ImageError::InvalidBufferSize(len, expected));
+    }
+    Ok(())
+}
+
+/// Convert a packed 4:2:2 frame to RGB.
+fn rgb_from_yuv422(
+    src: &[u8],
+    dst: &mut Image<u8, 3>,
+    format: YuvFormat,
+    coef: YuvToRgb,
+) -> Result<(), ImageError> {
+    check_buffer(format, dst.size(), src.len())?;
+    if dst.cols() == 0 || dst.rows() == 0 {
+        return Ok(());
+    }
+    let [y0, u, y1, v] = format.packed_offsets();
+    let cols = dst.cols();
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(3 * cols)
+        .zip(src.par_chunks_exact(4 * cols.div_ceil(2)))
+        .for_each(|(dst_row, src_row)| {
+            dst_row
+                .chunks_mut(6)
+                .zip(src_row.chunks_exact(4))
+                .for_each(|(rgb, yuv)| {
+                    coef.convert(yuv[y0], yuv[u], yuv[v], &mut rgb[..3]);
+                    if rgb.len() == 6 {
+                        coef.convert(yuv[y1], yuv[u], yuv[v], &mut rgb[3..]);
+                    }
+                });
+        });
+
+    Ok(())
+}
+
+/// Convert an RGB image to a packed 4:2:2 frame.
+fn yuv422_from_rgb(
+    src: &Image<u8, 3>,
+    dst: &mut [u8],
+    format: YuvFormat,
+    coef: RgbToYuv,
+) -> Result<(), ImageError> {
+    check_buffer(format, src.size(), dst.len())?;
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+    let [y0, u, y1, v] = format.packed_offsets();
+    let cols = src.cols();
+
+    dst.par_chunks_exact_mut(4 * cols.div_ceil(2))
+        .zip(src.as_slice().par_chunks_exact(3 * cols))
+        .for_each(|(dst_row, src_row)| {
+            dst_row
+                .chunks_exact_mut(4)
+                .zip(src_row.chunks(6))
+                .for_each(|(yuv, rgb)| {
+                    let (cu, cv) = coef.chroma(rgb.chunks_exact(3));
+                    yuv[y0] = coef.luma(&rgb[..3]);
+                    // replicate the last pixel of odd rows
+                    yuv[y1] = coef.luma(&rgb[rgb.len() - 3..]);
+                    yuv[u] = cu;
+                    yuv[v] = cv;
+                });
+        });
+
+    Ok(())
+}
+
+/// A chroma plane of a 4:2:0 frame: the data, the row stride and the step between samples.
+type ChromaPlane<'a> = (&'a [u8], usize, usize);
+
+/// Convert a planar 4:2:0 frame to RGB.
+fn rgb_from_yuv420(
+    y_plane: &[u8],
+    u_plane: ChromaPlane,
+    v_plane: ChromaPlane,
+    dst: &mut Image<u8, 3>,
+    coef: YuvToRgb,
+) {
+    let cols = dst.cols();
+    let (u_data, u_stride, u_step) = u_plane;
+    let (v_data, v_stride, v_step) = v_plane;
+
+    // process the rows by pairs sharing the same chroma row
+    dst.as_slice_mut()
+        .par_chunks_mut(2 * 3 * cols)
+        .zip(y_plane.par_chunks(2 * cols))
+        .enumerate()
+        .for_each(|(j, (dst_rows, y_rows))| {
+            let u_row = &u_data[j * u_stride..];
+            let v_row = &v_data[j * v_stride..];
+            dst_rows
+                .chunks_exact_mut(3 * cols)
+                .zip(y_rows.chunks_exact(cols))
+                .for_each(|(dst_row, y_row)| {
+                    dst_row
+                        .chunks_exact_mut(3)
+                        .zip(y_row.iter())
+                        .enumerate()
+                        .for_each(|(x, (rgb, &y))| {
+                            let cx = x / 2;
+                            coef.convert(y, u_row[cx * u_step], v_row[cx * v_step], rgb);
+                        });
+                });
+        });
+}
+
+/// Convert the rows of an RGB image to the luma rows of a planar 4:2:0 frame, and call `put`
+/// with the chroma of each 2x2 block.
+fn yuv420_rows_from_rgb(
+    coef: &RgbToYuv,
+    src_rows: &[u8],
+    y_rows: &mut [u8],
+    cols: usize,
+    mut put: impl FnMut(usize, u8, u8),
+) {
+    y_rows
+        .iter_mut()
+        .zip(src_rows.chunks_exact(3))
+        .for_each(|(y, rgb)| *y = coef.luma(rgb));
+
+    let (top, bottom) = src_rows.split_at(src_rows.len().min(3 * cols));
+    for cx in 0..cols.div_ceil(2) {
+        let x0 = 3 * 2 * cx;
+        let x1 = (x0 + 6).min(3 * cols);
+        let block = top[x0..x1]
+            .chunks_exact(3)
+            .chain(bottom.get(x0..x1).unwrap_or_default().chunks_exact(3));
+        let (u, v) = coef.chroma(block);
+        put(cx, u, v);
+    }
+}
+
+/// Convert a YUYV (YUY2) frame to an RGB image.
+///
+/// # Arguments
+///
+/// * `src` - The packed YUYV frame with the size of `dst`.
+/// * `dst` - The output RGB image.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `src` does not match the size of `dst`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::{rgb_from_yuyv, YuvFormat, YuvRange, YuvStandard};
+///
+/// let size = ImageSize { width: 4, height: 2 };
+/// let yuyv = vec![128u8; YuvFormat::Yuyv.buffer_size(size)];
+///
+/// let mut rgb = Image::<u8, 3>::from_size_val(size, 0).unwrap();
+/// rgb_from_yuyv(&yuyv, &mut rgb, YuvStandard::Bt601, YuvRange::Full).unwrap();
+/// assert_eq!(rgb.as_slice()[..3], [128, 128, 128]);
+/// ```
+pub fn rgb_from_yuyv(
+    src: &[u8],
+    dst: &mut Image<u8, 3>,
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    rgb_from_yuv422(src, dst, YuvFormat::Yuyv, YuvToRgb::new(standard, range))
+}
+
+/// Convert a UYVY frame to an RGB image.
+///
+/// # Arguments
+///
+/// * `src` - The packed UYVY frame with the size of `dst`.
+/// * `dst` - The output RGB image.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `src` does not match the size of `dst`.
+pub fn rgb_from_uyvy(
+    src: &[u8],
+    dst: &mut Image<u8, 3>,
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    rgb_from_yuv422(src, dst, YuvFormat::Uyvy, YuvToRgb::new(standard, range))
+}
+
+/// Convert an NV12 frame to an RGB image.
+///
+/// # Arguments
+///
+/// * `src` - The NV12 frame with the size of `dst`: the Y plane followed by the interleaved UV
+///   plane.
+/// * `dst` - The output RGB image.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `src` does not match the size of `dst`.
+pub fn rgb_from_nv12(
+    src: &[u8],
+    dst: &mut Image<u8, 3>,
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    check_buffer(YuvFormat::Nv12, dst.size(), src.len())?;
+    if dst.cols() == 0 || dst.rows() == 0 {
+        return Ok(());
+    }
+    let (y_plane, uv_plane) = src.split_at(dst.cols() * dst.rows());
+    let stride = 2 * dst.cols().div_ceil(2);
+    rgb_from_yuv420(
+        y_plane,
+        (uv_plane, stride, 2),
+        (&uv_plane[1..], stride, 2),
+        dst,
+        YuvToRgb::new(standard, range),
+    );
+    Ok(())
+}
+
+/// Convert an I420 (YU12) frame to an RGB image.
+///
+/// # Arguments
+///
+/// * `src` - The I420 frame with the size of `dst`: the Y plane followed by the U and V planes.
+/// * `dst` - The output RGB image.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `src` does not match the size of `dst`.
+pub fn rgb_from_i420(
+    src: &[u8],
+    dst: &mut Image<u8, 3>,
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    check_buffer(YuvFormat::I420, dst.size(), src.len())?;
+    if dst.cols() == 0 || dst.rows() == 0 {
+        return Ok(());
+    }
+    let (y_plane, uv_planes) = src.split_at(dst.cols() * dst.rows());
+    let (u_plane, v_plane) = uv_planes.split_at(uv_planes.len() / 2);
+    let stride = dst.cols().div_ceil(2);
+    rgb_from_yuv420(
+        y_plane,
+        (u_plane, stride, 1),
+        (v_plane, stride, 1),
+        dst,
+        YuvToRgb::new(standard, range),
+    );
+    Ok(())
+}
+
+/// Convert an RGB image to a YUYV (YUY2) frame.
+///
+/// The chroma of each pair of pixels is computed from their mean color.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `dst` - The output packed YUYV frame with the size of `src`.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `dst` does not match the size of `src`.
+pub fn yuyv_from_rgb(
+    src: &Image<u8, 3>,
+    dst: &mut [u8],
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    yuv422_from_rgb(src, dst, YuvFormat::Yuyv, RgbToYuv::new(standard, range))
+}
+
+/// Convert an RGB image to a UYVY frame.
+///
+/// The chroma of each pair of pixels is computed from their mean color.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `dst` - The output packed UYVY frame with the size of `src`.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `dst` does not match the size of `src`.
+pub fn uyvy_from_rgb(
+    src: &Image<u8, 3>,
+    dst: &mut [u8],
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    yuv422_from_rgb(src, dst, YuvFormat::Uyvy, RgbToYuv::new(standard, range))
+}
+
+/// Convert an RGB image to an NV12 frame.
+///
+/// The chroma of each 2x2 block of pixels is computed from their mean color.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `dst` - The output NV12 frame with the size of `src`.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `dst` does not match the size of `src`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::color::{nv12_from_rgb, rgb_from_nv12, YuvFormat, YuvRange, YuvStandard};
+///
+/// let size = ImageSize { width: 4, height: 2 };
+/// let rgb = Image::<u8, 3>::from_size_val(size, 255).unwrap();
+///
+/// let mut nv12 = vec![0u8; YuvFormat::Nv12.buffer_size(size)];
+/// nv12_from_rgb(&rgb, &mut nv12, YuvStandard::Bt709, YuvRange::Limited).unwrap();
+/// assert_eq!(nv12[0], 235);
+///
+/// let mut rgb2 = Image::<u8, 3>::from_size_val(size, 0).unwrap();
+/// rgb_from_nv12(&nv12, &mut rgb2, YuvStandard::Bt709, YuvRange::Limited).unwrap();
+/// assert_eq!(rgb2.as_slice(), rgb.as_slice());
+/// ```
+pub fn nv12_from_rgb(
+    src: &Image<u8, 3>,
+    dst: &mut [u8],
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    check_buffer(YuvFormat::Nv12, src.size(), dst.len())?;
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+    let coef = RgbToYuv::new(standard, range);
+    let cols = src.cols();
+    let (y_plane, uv_plane) = dst.split_at_mut(cols * src.rows());
+
+    y_plane
+        .par_chunks_mut(2 * cols)
+        .zip(uv_plane.par_chunks_mut(2 * cols.div_ceil(2)))
+        .zip(src.as_slice().par_chunks(2 * 3 * cols))
+        .for_each(|((y_rows, uv_row), src_rows)| {
+            yuv420_rows_from_rgb(&coef, src_rows, y_rows, cols, |cx, u, v| {
+                uv_row[2 * cx] = u;
+                uv_row[2 * cx + 1] = v;
+            });
+        });
+
+    Ok(())
+}
+
+/// Convert an RGB image to an I420 (YU12) frame.
+///
+/// The chroma of each 2x2 block of pixels is computed from their mean color.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `dst` - The output I420 frame with the size of `src`.
+/// * `standard` - The standard of the conversion matrix.
+/// * `range` - The range of the YUV values.
+///
+/// # Errors
+///
+/// Returns an error if the length of `dst` does not match the size of `src`.
+pub fn i420_from_rgb(
+    src: &Image<u8, 3>,
+    dst: &mut [u8],
+    standard: YuvStandard,
+    range: YuvRange,
+) -> Result<(), ImageError> {
+    check_buffer(YuvFormat::I420, src.size(), dst.len())?;
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+    let coef = RgbToYuv::new(standard, range);
+    let cols = src.cols();
+    let (y_plane, uv_planes) = dst.split_at_mut(cols * src.rows());
+    let (u_plane, v_plane) = uv_planes.split_at_mut(uv_planes.len() / 2);
+    let stride = cols.div_ceil(2);
+
+    y_plane
+        .par_chunks_mut(2 * cols)
+        .zip(u_plane.par_chunks_mut(stride))
+        .zip(v_plane.par_chunks_mut(stride))
+        .zip(src.as_slice().par_chunks(2 * 3 * cols))
+        .for_each(|(((y_rows, u_row), v_row), src_rows)| {
+            yuv420_rows_from_rgb(&coef, src_rows, y_rows, cols, |cx, u, v| {
+                u_row[cx] = u;
+                v_row[cx] = v;
+            });
+        });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Convert = fn(&Image<u8, 3>, &mut [u8], YuvStandard, YuvRange) -> Result<(), ImageError>;
+    type ConvertBack =
+        fn(&[u8], &mut Image<u8, 3>, YuvStandard, YuvRange) -> Result<(), ImageError>;
+
+    const FORMATS: [(YuvFormat, Convert, ConvertBack); 4] = [
+        (YuvFormat::Yuyv, yuyv_from_rgb, rgb_from_yuyv),
+        (YuvFormat::Uyvy, uyvy_from_rgb, rgb_from_uyvy),
+        (YuvFormat::Nv12, nv12_from_rgb, rgb_from_nv12),
+        (YuvFormat::I420, i420_from_rgb, rgb_from_i420),
+    ];
+
+    #[test]
+    fn test_yuv_reference_values() -> Result<(), ImageError> {
+        // pure red, in the reference tables of the standards
+        let size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let red = Image::<u8, 3>::new(size, [255, 0, 0].repeat(4))?;
+        let mut yuyv = vec![0u8; YuvFormat::Yuyv.buffer_size(size)];
+
+        yuyv_from_rgb(&red, &mut yuyv, YuvStandard::Bt601, YuvRange::Limited)?;
+        assert_eq!(yuyv[..4], [81, 90, 81, 240]);
+        yuyv_from_rgb(&red, &mut yuyv, YuvStandard::Bt709, YuvRange::Limited)?;
+        assert_eq!(yuyv[..4], [63, 102, 63, 240]);
+        yuyv_from_rgb(&red, &mut yuyv, YuvStandard::Bt601, YuvRange::Full)?;
+        assert_eq!(yuyv[..4], [76, 85, 76, 255]);
+
+        // limited range black and white
+        let mut rgb = Image::<u8, 3>::from_size_val(size, 0)?;
+        rgb_from_yuyv(
+            &[16, 128, 235, 128].repeat(2),
+            &mut rgb,
+            YuvStandard::Bt709,
+            YuvRange::Limited,
+        )?;
+        assert_eq!(rgb.as_slice()[..6], [0, 0, 0, 255, 255, 255]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_yuv_roundtrip() -> Result<(), ImageError> {
+        // odd size to exercise the rounded up chroma planes, smooth colors so that the chroma
+        // subsampling does not lose information
+        let size = ImageSize {
+            width: 7,
+            height: 5,
+        };
+        let rgb = Image::<u8, 3>::new(size, vec![0; 7 * 5 * 3])?;
+        let mut rgb = rgb;
+        rgb.as_slice_mut()
+            .chunks_exact_mut(3)
+            .enumerate()
+            .for_each(|(i, p)| {
+                let (x, y) = (i % 7, i / 7);
+                p.copy_from_slice(&[100 + 4 * x as u8, 60 + 8 * y as u8, 180 - 2 * x as u8]);
+            });
+
+        for (format, convert, convert_back) in FORMATS {
+            for standard in [YuvStandard::Bt601, YuvStandard::Bt709] {
+                for range in [YuvRange::Limited, YuvRange::Full] {
+                    let mut yuv = vec![0u8; format.buffer_size(size)];
+                    convert(&rgb, &mut yuv, standard, range)?;
+                    let mut rgb2 = Image::<u8, 3>::from_size_val(size, 0)?;
+                    convert_back(&yuv, &mut rgb2, standard, range)?;
+                    for (a, b) in rgb.as_slice().iter().zip(rgb2.as_slice()) {
+                        assert!(
+                            (*a as i32 - *b as i32).abs() <= 12,
+                            "{format:?} {standard:?} {range:?}: {a} != {b}"
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_yuv_gray_exact() -> Result<(), ImageError> {
+        // without chroma the luma is converted back exactly in full range
+        let size = ImageSize {
+            width: 4,
+            height: 4,
+        };
+        let data = (0..16u8).flat_map(|v| [v * 16; 3]).collect::<Vec<_>>();
+        let rgb = Image::<u8, 3>::new(size, data)?;
+        for (format, convert, convert_back) in FORMATS {
+            let mut yuv = vec![0u8; format.buffer_size(size)];
+            convert(&rgb, &mut yuv, YuvStandard::Bt601, YuvRange::Full)?;
+            let mut rgb2 = Image::<u8, 3>::from_size_val(size, 0)?;
+            convert_back(&yuv, &mut rgb2, YuvStandard::Bt601, YuvRange::Full)?;
+            assert_eq!(rgb2.as_slice(), rgb.as_slice(), "{format:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_yuv_buffer_size() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 3,
+        };
+        assert_eq!(YuvFormat::Yuyv.buffer_size(size), 3 * 3 * 4);
+        assert_eq!(YuvFormat::Nv12.buffer_size(size), 15 + 2 * 3 * 2);
+
+        let mut rgb = Image::<u8, 3>::from_size_val(size, 0)?;
+        let res = rgb_from_i420(&[0; 10], &mut rgb, YuvStandard::Bt601, YuvRange::Full);
+        assert!(matches!(res, Err(ImageError::InvalidBufferSize(10, 27))));
+
+        let mut nv12 = vec![0u8; YuvFormat::Nv12.buffer_size(size) + 1];
+        let res = nv12_from_rgb(&rgb, &mut nv12, YuvStandard::Bt601, YuvRange::Full);
+        assert!(matches!(res, Err(ImageError::InvalidBufferSize(28, 27))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_yuv_empty() -> Result<(), ImageError> {
+        for (width, height) in [(0, 0), (0, 3), (3, 0)] {
+            let size = ImageSize { width, height };
+            for (format, convert, convert_back) in FORMATS {
+                assert_eq!(format.buffer_size(size), 0, "{format:?}");
+                let rgb = Image::<u8, 3>::from_size_val(size, 0)?;
+                convert(&rgb, &mut [], YuvStandard::Bt601, YuvRange::Full)?;
+                let mut rgb = Image::<u8, 3>::from_size_val(size, 0)?;
+                convert_back(&[], &mut rgb, YuvStandard::Bt601, YuvRange::Full)?;
+
+                let res = convert_back(&[0; 4], &mut rgb, YuvStandard::Bt601, YuvRange::Full);
+                assert!(matches!(res, Err(ImageError::InvalidBufferSize(4, 0))));
+            }
+        }
+        Ok(())
+    }
+}
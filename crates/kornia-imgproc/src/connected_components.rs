@@ -0,0 +1,367 @@
+use num_traits::Zero;
+use rayon::{iter::ParallelIterator, slice::ParallelSliceMut};
+
+use kornia_image::{Image, ImageError};
+
+/// The neighbors of a pixel considered connected to it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Connectivity {
+    /// The horizontal and vertical neighbors.
+    Four,
+    /// The horizontal, vertical and diagonal neighbors.
+    #[default]
+    Eight,
+}
+
+/// An axis aligned rectangle in pixel coordinates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BoundingBox {
+    /// The x coordinate of the top-left corner.
+    pub x: usize,
+    /// The y coordinate of the top-left corner.
+    pub y: usize,
+    /// The width of the rectangle.
+    pub width: usize,
+    /// The height of the rectangle.
+    pub height: usize,
+}
+
+/// The statistics of a connected component.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentStats {
+    /// The label of the component in the label map.
+    pub label: u32,
+    /// The number of pixels of the component.
+    pub area: usize,
+    /// The smallest rectangle containing the component.
+    pub bbox: BoundingBox,
+    /// The mean x and y coordinates of the pixels of the component.
+    pub centroid: [f64; 2],
+}
+
+/// Find the representative of a provisional label, compressing the path on the way.
+fn find_root(parents: &mut [u32], mut label: u32) -> u32 {
+    while parents[label as usize] != label {
+        let parent = parents[label as usize];
+        parents[label as usize] = parents[parent as usize];
+        label = parent;
+    }
+    label
+}
+
+/// Merge the sets of two provisional labels, the smallest label becomes the representative.
+fn union(parents: &mut [u32], a: u32, b: u32) -> u32 {
+    let (a, b) = (find_root(parents, a), find_root(parents, b));
+    let (root, child) = if a < b { (a, b) } else { (b, a) };
+    parents[child as usize] = root;
+    root
+}
+
+/// Label the connected components of the non-zero pixels of an image.
+///
+/// The components are labelled from 1 in the raster order of their first pixel, the background
+/// keeps the label 0.
+///
+/// # Arguments
+///
+/// * `src` - The input image, usually a binary mask from [`crate::threshold`].
+/// * `dst` - The output label map.
+/// * `connectivity` - The neighbors of a pixel connected to it.
+///
+/// # Returns
+///
+/// The statistics of the components, the component with label `i` being at index `i - 1`.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::connected_components::{connected_components, Connectivity};
+///
+/// let image = Image::<u8, 1>::new(
+///     ImageSize {
+///         width: 4,
+///         height: 2,
+///     },
+///     vec![255, 0, 0, 255, 0, 255, 0, 255],
+/// )
+/// .unwrap();
+///
+/// let mut labels = Image::<u32, 1>::from_size_val(image.size(), 0).unwrap();
+///
+/// let stats = connected_components(&image, &mut labels, Connectivity::Four).unwrap();
+/// assert_eq!(labels.as_slice(), &[1, 0, 0, 2, 0, 3, 0, 2]);
+/// assert_eq!(stats[1].area, 2);
+/// ```
+pub fn connected_components<T>(
+    src: &Image<T, 1>,
+    dst: &mut Image<u32, 1>,
+    connectivity: Connectivity,
+) -> Result<Vec<ComponentStats>, ImageError>
+where
+    T: Copy + PartialEq + Zero,
+{
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let cols = src.cols();
+    let src_data = src.as_slice();
+    let labels = dst.as_slice_mut();
+
+    // first pass: assign provisional labels and record their equivalences
+    let mut parents = vec![0u32];
+    for (i, &value) in src_data.iter().enumerate() {
+        if value.is_zero() {
+            labels[i] = 0;
+            continue;
+        }
+
+        let (x, y) = (i % cols, i / cols);
+        let mut neighbors = [0u32; 4];
+        if x > 0 {
+            neighbors[0] = labels[i - 1];
+        }
+        if y > 0 {
+            neighbors[1] = labels[i - cols];
+            if connectivity == Connectivity::Eight {
+                if x > 0 {
+                    neighbors[2] = labels[i - cols - 1];
+                }
+                if x + 1 < cols {
+                    neighbors[3] = labels[i - cols + 1];
+                }
+            }
+        }
+
+        let mut label = 0;
+        for &n in neighbors.iter().filter(|&&n| n != 0) {
+            label = if label == 0 {
+                n
+            } else {
+                union(&mut parents, label, n)
+            };
+        }
+        if label == 0 {
+            label = parents.len() as u32;
+            parents.push(label);
+        }
+        labels[i] = label;
+    }
+
+    // resolve the equivalences to consecutive labels, the representative of a set is the
+    // provisional label of its first pixel so the raster order is preserved
+    let mut lut = vec![0u32; parents.len()];
+    let mut num_components = 0;
+    for label in 1..parents.len() as u32 {
+        let root = find_root(&mut parents, label);
+        if root == label {
+            num_components += 1;
+            lut[label as usize] = num_components;
+        } else {
+            lut[label as usize] = lut[root as usize];
+        }
+    }
+
+    // second pass: relabel and accumulate the statistics
+    let mut stats = (1..=num_components)
+        .map(|label| ComponentStats {
+            label,
+            area: 0,
+            bbox: BoundingBox {
+                x: usize::MAX,
+                y: usize::MAX,
+                width: 0,
+                height: 0,
+            },
+            centroid: [0.0; 2],
+        })
+        .collect::<Vec<_>>();
+
+    for (i, label) in labels.iter_mut().enumerate() {
+        if *label == 0 {
+            continue;
+        }
+        *label = lut[*label as usize];
+
+        let (x, y) = (i % cols, i / cols);
+        let s = &mut stats[*label as usize - 1];
+        s.area += 1;
+        s.centroid[0] += x as f64;
+        s.centroid[1] += y as f64;
+        // the width and height hold the max coordinates until the end
+        s.bbox.x = s.bbox.x.min(x);
+        s.bbox.y = s.bbox.y.min(y);
+        s.bbox.width = s.bbox.width.max(x);
+        s.bbox.height = s.bbox.height.max(y);
+    }
+
+    for s in stats.iter_mut() {
+        s.centroid = s.centroid.map(|c| c / s.area as f64);
+        s.bbox.width = s.bbox.width + 1 - s.bbox.x;
+        s.bbox.height = s.bbox.height + 1 - s.bbox.y;
+    }
+
+    Ok(stats)
+}
+
+/// Remove the connected components smaller than an area from a label map.
+///
+/// The pixels of the removed components are set to 0 and the remaining components are relabelled
+/// from 1, keeping their order.
+///
+/// # Arguments
+///
+/// * `labels` - The label map from [`connected_components`].
+/// * `stats` - The statistics of the components from [`connected_components`].
+/// * `min_area` - The minimum number of pixels of the components to keep.
+///
+/// # Returns
+///
+/// The statistics of the remaining components with their new labels.
+pub fn filter_components_by_area(
+    labels: &mut Image<u32, 1>,
+    stats: &[ComponentStats],
+    min_area: usize,
+) -> Vec<ComponentStats> {
+    let mut lut = vec![0u32; stats.len() + 1];
+    let mut kept = Vec::new();
+    for s in stats.iter().filter(|s| s.area >= min_area) {
+        let label = kept.len() as u32 + 1;
+        lut[s.label as usize] = label;
+        kept.push(ComponentStats { label, ..s.clone() });
+    }
+
+    let cols = labels.cols().max(1);
+    labels
+        .as_slice_mut()
+        .par_chunks_exact_mut(cols)
+        .for_each(|row| {
+            for label in row.iter_mut() {
+                *label = lut.get(*label as usize).copied().unwrap_or(0);
+            }
+        });
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoundingBox, Connectivity};
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn connected_components_connectivity() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 5,
+        };
+        #[rustfmt::skip]
+        let image = Image::<u8, 1>::new(
+            size,
+            vec![
+                1, 1, 0, 0, 0, 1,
+                0, 0, 1, 0, 0, 1,
+                0, 1, 1, 0, 1, 1,
+                0, 0, 0, 0, 0, 0,
+                1, 0, 1, 1, 1, 1,
+            ],
+        )?;
+        let mut labels = Image::<u32, 1>::from_size_val(size, 0)?;
+
+        let stats = super::connected_components(&image, &mut labels, Connectivity::Four)?;
+        #[rustfmt::skip]
+        assert_eq!(
+            labels.as_slice(),
+            &[
+                1, 1, 0, 0, 0, 2,
+                0, 0, 3, 0, 0, 2,
+                0, 3, 3, 0, 2, 2,
+                0, 0, 0, 0, 0, 0,
+                4, 0, 5, 5, 5, 5,
+            ]
+        );
+        assert_eq!(stats.len(), 5);
+        assert_eq!(stats[1].area, 4);
+        assert_eq!(
+            stats[1].bbox,
+            BoundingBox {
+                x: 4,
+                y: 0,
+                width: 2,
+                height: 3
+            }
+        );
+        assert_eq!(stats[4].centroid, [3.5, 4.0]);
+
+        // the diagonal joins the top-left components
+        let stats = super::connected_components(&image, &mut labels, Connectivity::Eight)?;
+        assert_eq!(stats.len(), 4);
+        assert_eq!(labels.as_slice()[13], 1);
+        assert_eq!(stats[0].area, 5);
+        assert_eq!(
+            stats[0].bbox,
+            BoundingBox {
+                x: 0,
+                y: 0,
+                width: 3,
+                height: 3
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn connected_components_merge_order() -> Result<(), ImageError> {
+        // a U shape whose arms get different provisional labels
+        let size = ImageSize {
+            width: 5,
+            height: 3,
+        };
+        #[rustfmt::skip]
+        let image = Image::<f32, 1>::new(
+            size,
+            vec![
+                0.0, 1.0, 0.0, 1.0, 0.0,
+                1.0, 1.0, 0.0, 1.0, 0.0,
+                0.0, 1.0, 1.0, 1.0, 0.0,
+            ],
+        )?;
+        let mut labels = Image::<u32, 1>::from_size_val(size, 0)?;
+        let stats = super::connected_components(&image, &mut labels, Connectivity::Four)?;
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].area, 8);
+        assert!(labels
+            .as_slice()
+            .iter()
+            .zip(image.as_slice())
+            .all(|(&l, &v)| (l == 1) == (v != 0.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn filter_components() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 1,
+        };
+        let image = Image::<u8, 1>::new(size, vec![1, 0, 1, 1, 0])?;
+        let mut labels = Image::<u32, 1>::from_size_val(size, 0)?;
+        let stats = super::connected_components(&image, &mut labels, Connectivity::Eight)?;
+        assert_eq!(labels.as_slice(), &[1, 0, 2, 2, 0]);
+
+        let kept = super::filter_components_by_area(&mut labels, &stats, 2);
+        assert_eq!(labels.as_slice(), &[0, 0, 1, 1, 0]);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].label, 1);
+        assert_eq!(kept[0].area, 2);
+        Ok(())
+    }
+}
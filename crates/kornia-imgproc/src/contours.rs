@@ -0,0 +1,722 @@
+use kornia_image::{Image, ImageError};
+
+use crate::connected_components::BoundingBox;
+
+/// The offsets (dy, dx) of the 8 neighbors of a pixel, counterclockwise from the east neighbor
+/// as displayed on the image.
+const NEIGHBORS: [(isize, isize); 8] = [
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// The contours returned by [`find_contours`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RetrievalMode {
+    /// Only the outer borders of the components, without hierarchy.
+    External,
+    /// All the borders, without hierarchy.
+    List,
+    /// All the borders with the full hierarchy of components and holes.
+    #[default]
+    Tree,
+}
+
+/// The points of the borders kept in the contours.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContourApproximation {
+    /// All the pixels of the border.
+    None,
+    /// The end points of the horizontal, vertical and diagonal segments of the border.
+    #[default]
+    Simple,
+}
+
+/// A border of a binary image.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Contour {
+    /// The (x, y) coordinates of the pixels of the border, in tracing order.
+    pub points: Vec<(i64, i64)>,
+    /// Whether the contour is the border of a hole inside a component.
+    pub is_hole: bool,
+    /// The index of the contour surrounding this one.
+    pub parent: Option<usize>,
+    /// The indices of the contours directly inside this one.
+    pub children: Vec<usize>,
+}
+
+/// A rotated rectangle.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RotatedRect {
+    /// The (x, y) coordinates of the center of the rectangle.
+    pub center: (f64, f64),
+    /// The width and height of the rectangle.
+    pub size: (f64, f64),
+    /// The angle between the x axis and the width side in radians, in the range [0, π/2).
+    pub angle: f64,
+}
+
+impl RotatedRect {
+    /// The 4 corners of the rectangle.
+    pub fn corners(&self) -> [(f64, f64); 4] {
+        let (sin, cos) = self.angle.sin_cos();
+        let (hw, hh) = (self.size.0 / 2.0, self.size.1 / 2.0);
+        [(-hw, -hh), (hw, -hh), (hw, hh), (-hw, hh)].map(|(u, v)| {
+            (
+                self.center.0 + u * cos - v * sin,
+                self.center.1 + u * sin + v * cos,
+            )
+        })
+    }
+}
+
+/// A border found while scanning the image.
+struct Border {
+    points: Vec<(i64, i64)>,
+    is_hole: bool,
+    parent: usize,
+}
+
+/// Follow a border from its first pixel and mark its pixels in the labels.
+///
+/// `labels` is the image padded with a frame of zeros, `start` the padded index of the first
+/// pixel and `from` the direction of its 0-pixel neighbor where the search starts.
+fn follow_border(
+    labels: &mut [i32],
+    stride: usize,
+    start: usize,
+    from: usize,
+    nbd: i32,
+) -> Vec<(i64, i64)> {
+    let to_point = |i: usize| ((i % stride) as i64 - 1, (i / stride) as i64 - 1);
+    let neighbor = |i: usize, d: usize| {
+        let (dy, dx) = NEIGHBORS[d % 8];
+        (i as isize + dy * stride as isize + dx) as usize
+    };
+
+    // look clockwise for the first non-zero neighbor
+    let Some(first_dir) = (0..8)
+        .map(|k| (from + 8 - k) % 8)
+        .find(|&d| labels[neighbor(start, d)] != 0)
+    else {
+        labels[start] = -nbd;
+        return vec![to_point(start)];
+    };
+
+    let first = neighbor(start, first_dir);
+    let mut points = Vec::new();
+    let mut current = start;
+    // the direction from the current pixel to the previous one
+    let mut prev_dir = first_dir;
+    loop {
+        points.push(to_point(current));
+
+        // look counterclockwise for the next non-zero neighbor
+        let mut east_is_zero = false;
+        let mut next_dir = prev_dir;
+        for k in 1..=8 {
+            let d = (prev_dir + k) % 8;
+            if labels[neighbor(current, d)] != 0 {
+                next_dir = d;
+                break;
+            }
+            if d == 0 {
+                east_is_zero = true;
+            }
+        }
+        let next = neighbor(current, next_dir);
+
+        if east_is_zero {
+            labels[current] = -nbd;
+        } else if labels[current] == 1 {
+            labels[current] = nbd;
+        }
+
+        if next == start && current == first {
+            break;
+        }
+        current = next;
+        // the direction pointing back to the previous pixel
+        prev_dir = (next_dir + 4) % 8;
+    }
+
+    points
+}
+
+/// Keep the end points of the straight segments of a closed border.
+fn compress_segments(points: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    let n = points.len();
+    if n <= 2 {
+        return points;
+    }
+    let step = |a: (i64, i64), b: (i64, i64)| (b.0 - a.0, b.1 - a.1);
+    (0..n)
+        .filter(|&k| {
+            let (prev, p, next) = (points[(k + n - 1) % n], points[k], points[(k + 1) % n]);
+            step(prev, p) != step(p, next)
+        })
+        .map(|k| points[k])
+        .collect()
+}
+
+/// Find the contours of the non-zero regions of a binary image.
+///
+/// The borders are followed with the algorithm of Suzuki and Abe (1985), like
+/// `cv2.findContours`: the components are 8-connected and their holes 4-connected. The pixels
+/// outside of the image are considered zero.
+///
+/// # Arguments
+///
+/// * `src` - The input binary image.
+/// * `mode` - The contours to return and their hierarchy.
+/// * `approximation` - The points of the borders kept in the contours.
+///
+/// # Returns
+///
+/// The contours in the order of their first pixel in the image.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::contours::{find_contours, ContourApproximation, RetrievalMode};
+///
+/// let mut image = Image::<u8, 1>::from_size_val(
+///     ImageSize {
+///         width: 5,
+///         height: 4,
+///     },
+///     0,
+/// )
+/// .unwrap();
+/// for y in 1..3 {
+///     for x in 1..4 {
+///         image.as_slice_mut()[y * 5 + x] = 255;
+///     }
+/// }
+///
+/// let contours =
+///     find_contours(&image, RetrievalMode::External, ContourApproximation::Simple).unwrap();
+/// assert_eq!(contours.len(), 1);
+/// assert_eq!(contours[0].points, vec![(1, 1), (1, 2), (3, 2), (3, 1)]);
+/// ```
+pub fn find_contours(
+    src: &Image<u8, 1>,
+    mode: RetrievalMode,
+    approximation: ContourApproximation,
+) -> Result<Vec<Contour>, ImageError> {
+    let (cols, rows) = (src.cols(), src.rows());
+    let stride = cols + 2;
+
+    // the image padded with a frame of zeros, the non-zero pixels are set to 1
+    let mut labels = vec![0i32; stride * (rows + 2)];
+    for (dst_row, src_row) in labels
+        .chunks_exact_mut(stride)
+        .skip(1)
+        .zip(src.as_slice().chunks_exact(cols.max(1)))
+    {
+        for (l, &v) in dst_row[1..=cols].iter_mut().zip(src_row) {
+            *l = (v != 0) as i32;
+        }
+    }
+
+    // the frame is the border with number 1
+    let mut borders = vec![Border {
+        points: Vec::new(),
+        is_hole: true,
+        parent: 0,
+    }];
+
+    for y in 1..=rows {
+        let mut lnbd = 1;
+        for x in 1..=cols {
+            let i = y * stride + x;
+            let value = labels[i];
+            if value == 0 {
+                continue;
+            }
+
+            let start = if value == 1 && labels[i - 1] == 0 {
+                // the west neighbor is zero
+                Some((false, 4))
+            } else if value >= 1 && labels[i + 1] == 0 {
+                if value > 1 {
+                    lnbd = value;
+                }
+                Some((true, 0))
+            } else {
+                None
+            };
+
+            if let Some((is_hole, from)) = start {
+                let nbd = borders.len() as i32 + 1;
+                let last = &borders[lnbd as usize - 1];
+                let parent = if is_hole == last.is_hole {
+                    last.parent
+                } else {
+                    lnbd as usize - 1
+                };
+                let points = follow_border(&mut labels, stride, i, from, nbd);
+                borders.push(Border {
+                    points,
+                    is_hole,
+                    parent,
+                });
+            }
+
+            if labels[i] != 1 {
+                lnbd = labels[i].abs();
+            }
+        }
+    }
+
+    // drop the frame and the borders not requested, and remap the parents
+    let keep = |b: &Border| match mode {
+        RetrievalMode::External => !b.is_hole && b.parent == 0,
+        RetrievalMode::List | RetrievalMode::Tree => true,
+    };
+    let mut index = vec![None; borders.len()];
+    let mut contours: Vec<Contour> = Vec::new();
+    for (k, border) in borders.into_iter().enumerate().skip(1) {
+        if !keep(&border) {
+            continue;
+        }
+        index[k] = Some(contours.len());
+        let parent = match mode {
+            RetrievalMode::Tree => index[border.parent],
+            _ => None,
+        };
+        if let Some(p) = parent {
+            let child = contours.len();
+            contours[p].children.push(child);
+        }
+        let points = match approximation {
+            ContourApproximation::None => border.points,
+            ContourApproximation::Simple => compress_segments(border.points),
+        };
+        contours.push(Contour {
+            points,
+            is_hole: border.is_hole,
+            parent,
+            children: Vec::new(),
+        });
+    }
+
+    Ok(contours)
+}
+
+/// Compute the area enclosed by a closed polygon.
+///
+/// # Arguments
+///
+/// * `points` - The vertices of the polygon, in clockwise or counterclockwise order.
+///
+/// # Returns
+///
+/// The absolute area computed with the shoelace formula.
+pub fn contour_area(points: &[(i64, i64)]) -> f64 {
+    let n = points.len();
+    let twice_area = (0..n)
+        .map(|k| {
+            let (a, b) = (points[k], points[(k + 1) % n]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum::<i64>();
+    twice_area.abs() as f64 / 2.0
+}
+
+/// Compute the length of a polyline.
+///
+/// # Arguments
+///
+/// * `points` - The vertices of the polyline.
+/// * `closed` - Whether the last vertex is connected to the first one.
+pub fn arc_length(points: &[(i64, i64)], closed: bool) -> f64 {
+    let segment = |a: &(i64, i64), b: &(i64, i64)| {
+        (((b.0 - a.0) * (b.0 - a.0) + (b.1 - a.1) * (b.1 - a.1)) as f64).sqrt()
+    };
+    let open = points
+        .windows(2)
+        .map(|w| segment(&w[0], &w[1]))
+        .sum::<f64>();
+    match (closed, points.first(), points.last()) {
+        (true, Some(first), Some(last)) => open + segment(last, first),
+        _ => open,
+    }
+}
+
+/// Compute the smallest axis aligned rectangle containing a set of points.
+///
+/// The rectangle covers the pixels of the points, so a single point has a size of 1x1.
+///
+/// # Arguments
+///
+/// * `points` - The points with non-negative coordinates.
+pub fn bounding_rect(points: &[(i64, i64)]) -> BoundingBox {
+    let Some(&(x0, y0)) = points.first() else {
+        return BoundingBox::default();
+    };
+    let (min_x, min_y, max_x, max_y) =
+        points
+            .iter()
+            .fold((x0, y0, x0, y0), |(min_x, min_y, max_x, max_y), &(x, y)| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            });
+    BoundingBox {
+        x: min_x.max(0) as usize,
+        y: min_y.max(0) as usize,
+        width: (max_x - min_x + 1) as usize,
+        height: (max_y - min_y + 1) as usize,
+    }
+}
+
+/// Compute the convex hull of a set of points.
+///
+/// # Arguments
+///
+/// * `points` - The input points.
+///
+/// # Returns
+///
+/// The vertices of the hull without collinear points, starting from the leftmost point and in
+/// clockwise order as displayed on the image.
+pub fn convex_hull(points: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let cross = |o: (i64, i64), a: (i64, i64), b: (i64, i64)| {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+
+    // Andrew's monotone chain, the lower chain followed by the upper chain
+    let mut hull: Vec<(i64, i64)> = Vec::with_capacity(2 * sorted.len());
+    for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let base = hull.len();
+        for p in pass {
+            while hull.len() >= base + 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // the last point is the first of the next chain
+        hull.pop();
+    }
+
+    hull
+}
+
+/// Compute the rotated rectangle of minimum area containing a set of points.
+///
+/// The rectangle has a side aligned with an edge of the convex hull, which is found with the
+/// rotating calipers.
+///
+/// # Arguments
+///
+/// * `points` - The input points.
+///
+/// # Returns
+///
+/// The rectangle through the centers of the extreme points, with a zero width or height for
+/// collinear points.
+pub fn min_area_rect(points: &[(i64, i64)]) -> RotatedRect {
+    let hull = convex_hull(points);
+    let to_f64 = |p: (i64, i64)| (p.0 as f64, p.1 as f64);
+
+    let (mut best, mut best_area) = (RotatedRect::default(), f64::INFINITY);
+    match hull.len() {
+        0 => return best,
+        1 => {
+            best.center = to_f64(hull[0]);
+            return best;
+        }
+        _ => {}
+    }
+
+    for k in 0..hull.len() {
+        let (a, b) = (to_f64(hull[k]), to_f64(hull[(k + 1) % hull.len()]));
+        let length = (b.0 - a.0).hypot(b.1 - a.1);
+        let u = ((b.0 - a.0) / length, (b.1 - a.1) / length);
+        let v = (-u.1, u.0);
+
+        // the extent of the hull along the edge and its normal
+        let (mut min_u, mut max_u, mut min_v, mut max_v) = (f64::MAX, f64::MIN, f64::MAX, f64::MIN);
+        for &p in hull.iter() {
+            let p = to_f64(p);
+            let (pu, pv) = (p.0 * u.0 + p.1 * u.1, p.0 * v.0 + p.1 * v.1);
+            min_u = min_u.min(pu);
+            max_u = max_u.max(pu);
+            min_v = min_v.min(pv);
+            max_v = max_v.max(pv);
+        }
+
+        let area = (max_u - min_u) * (max_v - min_v);
+        if area < best_area {
+            best_area = area;
+            let (cu, cv) = ((min_u + max_u) / 2.0, (min_v + max_v) / 2.0);
+            best = RotatedRect {
+                center: (cu * u.0 + cv * v.0, cu * u.1 + cv * v.1),
+                size: (max_u - min_u, max_v - min_v),
+                angle: u.1.atan2(u.0),
+            };
+        }
+    }
+
+    // the rectangle is symmetric, bring the angle to [0, π/2)
+    best.angle = best.angle.rem_euclid(std::f64::consts::PI);
+    if best.angle >= std::f64::consts::FRAC_PI_2 {
+        best.angle -= std::f64::consts::FRAC_PI_2;
+        best.size = (best.size.1, best.size.0);
+    }
+
+    best
+}
+
+/// Simplify an open polyline with the Douglas-Peucker algorithm.
+fn douglas_peucker(points: &[(i64, i64)], epsilon: f64) -> Vec<(i64, i64)> {
+    let n = points.len();
+    if n <= 2 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+    let mut stack = vec![(0, n - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let (a, b) = (points[first], points[last]);
+        let (dx, dy) = ((b.0 - a.0) as f64, (b.1 - a.1) as f64);
+        let length = dx.hypot(dy);
+        let distance = |p: (i64, i64)| {
+            let (px, py) = ((p.0 - a.0) as f64, (p.1 - a.1) as f64);
+            if length == 0.0 {
+                px.hypot(py)
+            } else {
+                (px * dy - py * dx).abs() / length
+            }
+        };
+
+        let farthest = (first + 1..last)
+            .map(|k| (k, distance(points[k])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((k, d)) = farthest {
+            if d > epsilon {
+                keep[k] = true;
+                stack.push((first, k));
+                stack.push((k, last));
+            }
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&p, k)| k.then_some(p))
+        .collect()
+}
+
+/// Approximate a polyline with fewer vertices with the Douglas-Peucker algorithm.
+///
+/// # Arguments
+///
+/// * `points` - The vertices of the polyline.
+/// * `epsilon` - The maximum distance between the polyline and its approximation.
+/// * `closed` - Whether the last vertex is connected to the first one.
+///
+/// # Returns
+///
+/// The subset of the vertices of the approximation.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::contours::approx_poly_dp;
+///
+/// let points = [(0, 0), (1, 0), (2, 1), (3, 0), (4, 0), (4, 4)];
+/// assert_eq!(approx_poly_dp(&points, 1.5, false), vec![(0, 0), (4, 0), (4, 4)]);
+/// ```
+pub fn approx_poly_dp(points: &[(i64, i64)], epsilon: f64, closed: bool) -> Vec<(i64, i64)> {
+    if !closed || points.len() <= 2 {
+        return douglas_peucker(points, epsilon);
+    }
+
+    // split the closed polygon at the vertex farthest from the first one
+    let first = points[0];
+    let (split, _) = points
+        .iter()
+        .enumerate()
+        .map(|(k, p)| (k, (p.0 - first.0).pow(2) + (p.1 - first.1).pow(2)))
+        .max_by_key(|&(_, d)| d)
+        .unwrap_or((0, 0));
+    if split == 0 {
+        return vec![first];
+    }
+
+    let mut closing = points[split..].to_vec();
+    closing.push(first);
+    let mut approx = douglas_peucker(&points[..=split], epsilon);
+    approx.pop();
+    approx.extend(douglas_peucker(&closing, epsilon));
+    approx.pop();
+    approx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContourApproximation, RetrievalMode};
+    use crate::connected_components::BoundingBox;
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    /// A filled square of side 5 with a one pixel hole, and an isolated pixel.
+    fn square_with_hole() -> Result<Image<u8, 1>, ImageError> {
+        let mut image = Image::<u8, 1>::from_size_val(
+            ImageSize {
+                width: 9,
+                height: 7,
+            },
+            0,
+        )?;
+        for y in 1..6 {
+            for x in 1..6 {
+                image.as_slice_mut()[y * 9 + x] = 255;
+            }
+        }
+        image.as_slice_mut()[3 * 9 + 3] = 0;
+        image.as_slice_mut()[5 * 9 + 7] = 1;
+        Ok(image)
+    }
+
+    #[test]
+    fn find_contours_hierarchy() -> Result<(), ImageError> {
+        let image = square_with_hole()?;
+
+        let contours =
+            super::find_contours(&image, RetrievalMode::Tree, ContourApproximation::Simple)?;
+        assert_eq!(contours.len(), 3);
+
+        let outer = &contours[0];
+        assert!(!outer.is_hole);
+        assert_eq!(outer.parent, None);
+        assert_eq!(outer.children, vec![1]);
+        assert_eq!(outer.points, vec![(1, 1), (1, 5), (5, 5), (5, 1)]);
+        assert_eq!(super::contour_area(&outer.points), 16.0);
+
+        let hole = &contours[1];
+        assert!(hole.is_hole);
+        assert_eq!(hole.parent, Some(0));
+        assert_eq!(hole.points.len(), 4);
+        assert_eq!(super::contour_area(&hole.points), 2.0);
+
+        assert_eq!(contours[2].points, vec![(7, 5)]);
+        assert_eq!(contours[2].parent, None);
+
+        let contours =
+            super::find_contours(&image, RetrievalMode::List, ContourApproximation::None)?;
+        assert_eq!(contours.len(), 3);
+        assert_eq!(contours[0].points.len(), 16);
+        assert!(contours.iter().all(|c| c.parent.is_none()));
+
+        let contours =
+            super::find_contours(&image, RetrievalMode::External, ContourApproximation::None)?;
+        assert_eq!(contours.len(), 2);
+        assert!(contours.iter().all(|c| !c.is_hole));
+        Ok(())
+    }
+
+    #[test]
+    fn find_contours_nested() -> Result<(), ImageError> {
+        // a ring with a pixel inside its hole
+        #[rustfmt::skip]
+        let image = Image::<u8, 1>::new(
+            ImageSize {
+                width: 5,
+                height: 5,
+            },
+            vec![
+                1, 1, 1, 1, 1,
+                1, 0, 0, 0, 1,
+                1, 0, 1, 0, 1,
+                1, 0, 0, 0, 1,
+                1, 1, 1, 1, 1,
+            ],
+        )?;
+
+        let contours =
+            super::find_contours(&image, RetrievalMode::Tree, ContourApproximation::Simple)?;
+        let parents = contours.iter().map(|c| c.parent).collect::<Vec<_>>();
+        assert_eq!(parents, vec![None, Some(0), Some(1)]);
+        assert_eq!(contours[2].points, vec![(2, 2)]);
+
+        let contours = super::find_contours(
+            &image,
+            RetrievalMode::External,
+            ContourApproximation::Simple,
+        )?;
+        assert_eq!(contours.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn contour_geometry() {
+        let square = [(0, 0), (0, 4), (4, 4), (4, 0)];
+        assert_eq!(super::arc_length(&square, true), 16.0);
+        assert_eq!(super::arc_length(&square, false), 12.0);
+        assert_eq!(
+            super::bounding_rect(&square),
+            BoundingBox {
+                x: 0,
+                y: 0,
+                width: 5,
+                height: 5
+            }
+        );
+
+        let points = [(2, 2), (0, 0), (4, 0), (1, 1), (4, 4), (2, 0), (0, 4)];
+        assert_eq!(
+            super::convex_hull(&points),
+            vec![(0, 0), (4, 0), (4, 4), (0, 4)]
+        );
+    }
+
+    #[test]
+    fn min_area_rect_rotated() {
+        let diamond = [(0, 2), (2, 0), (4, 2), (2, 4), (2, 2)];
+        let rect = super::min_area_rect(&diamond);
+        assert!((rect.center.0 - 2.0).abs() < 1e-9);
+        assert!((rect.center.1 - 2.0).abs() < 1e-9);
+        assert!((rect.size.0 * rect.size.1 - 8.0).abs() < 1e-9);
+        assert!((rect.angle - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+        for (x, y) in rect.corners() {
+            assert!(diamond
+                .iter()
+                .any(|p| (p.0 as f64 - x).abs() < 1e-9 && (p.1 as f64 - y).abs() < 1e-9));
+        }
+
+        let segment = super::min_area_rect(&[(0, 0), (3, 0)]);
+        assert_eq!(segment.size, (3.0, 0.0));
+        assert_eq!(segment.center, (1.5, 0.0));
+    }
+
+    #[test]
+    fn approx_poly_dp_closed() {
+        // a square traced pixel by pixel with a small bump
+        let mut points = (0..10).map(|x| (x, 0)).collect::<Vec<_>>();
+        points.extend((0..10).map(|y| (10, y)));
+        points.extend((0..10).map(|x| (10 - x, 10)));
+        points.extend((0..10).map(|y| (0, 10 - y)));
+        points[5] = (5, 1);
+
+        let approx = super::approx_poly_dp(&points, 1.5, true);
+        assert_eq!(approx, vec![(0, 0), (10, 0), (10, 10), (0, 10)]);
+    }
+}
@@ -8,7 +8,8 @@ use rayon::{
 /// Compute the mean and standard deviation of an image.
 ///
 /// The mean and standard deviation are computed for each channel
-/// of the image in one pass.
+/// of the image in one pass. For reductions over arbitrary tensor
+/// dimensions see `kornia_tensor_ops::ops`.
 ///
 /// # Arguments
 ///
@@ -39,29 +40,31 @@ use rayon::{
 /// assert_eq!(std, [93.5183805462862, 93.5183805462862, 93.5183805462862]);
 /// assert_eq!(mean, [111.25, 112.25, 113.25]);
 /// ```
-pub fn std_mean(image: &Image<u8, 3>) -> ([f64; 3], [f64; 3]) {
-    let (sum, sq_sum) = image.as_slice().chunks_exact(3).fold(
-        ([0f64; 3], [0f64; 3]),
+pub fn std_mean<T, const C: usize>(image: &Image<T, C>) -> ([f64; C], [f64; C])
+where
+    T: Copy + Into<f64>,
+{
+    let (sum, sq_sum) = image.as_slice().chunks_exact(C).fold(
+        ([0f64; C], [0f64; C]),
         |(mut sum, mut sq_sum), pixel| {
             sum.iter_mut()
                 .zip(pixel.iter())
-                .for_each(|(s, &p)| *s += p as f64);
+                .for_each(|(s, &p)| *s += p.into());
             sq_sum
                 .iter_mut()
                 .zip(pixel.iter())
-                .for_each(|(s, &p)| *s += (p as f64).powi(2));
+                .for_each(|(s, &p)| *s += p.into().powi(2));
             (sum, sq_sum)
         },
     );
 
     let n = (image.width() * image.height()) as f64;
-    let mean = [sum[0] / n, sum[1] / n, sum[2] / n];
+    let mean = sum.map(|s| s / n);
 
-    let variance = [
-        (sq_sum[0] / n - mean[0].powi(2)).sqrt(),
-        (sq_sum[1] / n - mean[1].powi(2)).sqrt(),
-        (sq_sum[2] / n - mean[2].powi(2)).sqrt(),
-    ];
+    let mut variance = [0f64; C];
+    for (i, v) in variance.iter_mut().enumerate() {
+        *v = (sq_sum[i] / n - mean[i].powi(2)).sqrt();
+    }
 
     (variance, mean)
 }
@@ -277,6 +280,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_std_mean_f32_gray() -> Result<(), ImageError> {
+        let image = Image::<f32, 1>::new(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            vec![1.0, 2.0, 3.0, 4.0],
+        )?;
+
+        let (std, mean) = super::std_mean(&image);
+        assert_eq!(std, [1.118033988749895]);
+        assert_eq!(mean, [2.5]);
+        Ok(())
+    }
+
     #[test]
     fn test_bitwise_and() -> Result<(), ImageError> {
         let image = Image::<u8, 3>::new(
@@ -0,0 +1,240 @@
+use kornia_image::{Image, ImageError};
+
+use crate::{
+    filter::{box_blur, sobel_gradients, BorderType},
+    parallel,
+};
+
+/// The averaged products of the derivatives of an image, returned as (dx * dx, dy * dy, dx * dy).
+fn structure_tensor(
+    src: &Image<f32, 1>,
+    block_size: usize,
+) -> Result<[Image<f32, 1>; 3], ImageError> {
+    if block_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(block_size, block_size));
+    }
+
+    let mut dx = Image::from_size_val(src.size(), 0.0)?;
+    let mut dy = Image::from_size_val(src.size(), 0.0)?;
+    sobel_gradients(src, &mut dx, &mut dy, 3, BorderType::Reflect101)?;
+
+    let mut products = [
+        Image::from_size_val(src.size(), 0.0)?,
+        Image::from_size_val(src.size(), 0.0)?,
+        Image::from_size_val(src.size(), 0.0)?,
+    ];
+    parallel::par_iter_rows_val_two(&dx, &dx, &mut products[0], |&a, &b, p| *p = a * b);
+    parallel::par_iter_rows_val_two(&dy, &dy, &mut products[1], |&a, &b, p| *p = a * b);
+    parallel::par_iter_rows_val_two(&dx, &dy, &mut products[2], |&a, &b, p| *p = a * b);
+
+    let mut tensor = [
+        Image::from_size_val(src.size(), 0.0)?,
+        Image::from_size_val(src.size(), 0.0)?,
+        Image::from_size_val(src.size(), 0.0)?,
+    ];
+    for (product, t) in products.iter().zip(tensor.iter_mut()) {
+        box_blur(product, t, (block_size, block_size), BorderType::Reflect101)?;
+    }
+
+    Ok(tensor)
+}
+
+/// Compute the Harris corner response of an image.
+///
+/// The response is `det(M) - k * trace(M)^2` with `M` the structure tensor, the sobel derivatives
+/// products averaged over a block. It is positive on the corners, negative on the edges and close
+/// to zero on the flat regions.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W).
+/// * `dst` - The destination image with shape (H, W).
+/// * `block_size` - The size of the block over which the derivatives are averaged, an odd number.
+/// * `k` - The sensitivity of the detector, usually in [0.04, 0.06].
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+pub fn harris_response(
+    src: &Image<f32, 1>,
+    dst: &mut Image<f32, 1>,
+    block_size: usize,
+    k: f32,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+
+    let [xx, yy, xy] = structure_tensor(src, block_size)?;
+    let (xx, yy, xy) = (xx.as_slice(), yy.as_slice(), xy.as_slice());
+    parallel::par_iter_rows_indexed(dst, |y, row| {
+        let offset = y * row.len();
+        for (x, r) in row.iter_mut().enumerate() {
+            let (a, c, b) = (xx[offset + x], yy[offset + x], xy[offset + x]);
+            let trace = a + c;
+            *r = a * c - b * b - k * trace * trace;
+        }
+    });
+
+    Ok(())
+}
+
+/// Compute the Shi-Tomasi corner response of an image.
+///
+/// The response is the smallest eigenvalue of the structure tensor, the sobel derivatives products
+/// averaged over a block. It is large on the corners only.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W).
+/// * `dst` - The destination image with shape (H, W).
+/// * `block_size` - The size of the block over which the derivatives are averaged, an odd number.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::features::{non_max_suppression, shi_tomasi_response};
+///
+/// let size = ImageSize {
+///     width: 8,
+///     height: 8,
+/// };
+/// // a bright square in the bottom right corner
+/// let image = Image::<f32, 1>::new(
+///     size,
+///     (0..64).map(|i| if i % 8 >= 4 && i / 8 >= 4 { 1.0 } else { 0.0 }).collect(),
+/// )
+/// .unwrap();
+///
+/// let mut response = Image::<f32, 1>::from_size_val(size, 0.0).unwrap();
+/// shi_tomasi_response(&image, &mut response, 3).unwrap();
+///
+/// let keypoints = non_max_suppression(&response, 0.1, 3.0, Some(1));
+/// assert_eq!(keypoints[0].position, [4.0, 4.0]);
+/// ```
+pub fn shi_tomasi_response(
+    src: &Image<f32, 1>,
+    dst: &mut Image<f32, 1>,
+    block_size: usize,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+
+    let [xx, yy, xy] = structure_tensor(src, block_size)?;
+    let (xx, yy, xy) = (xx.as_slice(), yy.as_slice(), xy.as_slice());
+    parallel::par_iter_rows_indexed(dst, |y, row| {
+        let offset = y * row.len();
+        for (x, r) in row.iter_mut().enumerate() {
+            let (a, c, b) = (xx[offset + x], yy[offset + x], xy[offset + x]);
+            let half_diff = 0.5 * (a - c);
+            *r = 0.5 * (a + c) - (half_diff * half_diff + b * b).sqrt();
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::non_max_suppression;
+    use kornia_image::ImageSize;
+
+    /// A bright square on a dark background.
+    fn square(size: usize, start: usize, end: usize) -> Result<Image<f32, 1>, ImageError> {
+        Image::new(
+            ImageSize {
+                width: size,
+                height: size,
+            },
+            (0..size * size)
+                .map(|i| {
+                    let (x, y) = (i % size, i / size);
+                    if (start..end).contains(&x) && (start..end).contains(&y) {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn harris_corners() -> Result<(), ImageError> {
+        let img = square(16, 4, 12)?;
+        let mut response = Image::from_size_val(img.size(), 0.0)?;
+        harris_response(&img, &mut response, 3, 0.04)?;
+
+        let at = |x: usize, y: usize| response.as_slice()[y * 16 + x];
+        assert!(at(4, 4) > 0.0 && at(11, 11) > 0.0);
+        assert!(at(8, 4) < 0.0 && at(4, 8) < 0.0);
+        assert_eq!(at(8, 8), 0.0);
+        assert_eq!(at(0, 15), 0.0);
+
+        let keypoints = non_max_suppression(&response, 0.0, 3.0, None);
+        let mut positions = keypoints.iter().map(|k| k.position).collect::<Vec<_>>();
+        positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            positions,
+            [[4.0, 4.0], [4.0, 11.0], [11.0, 4.0], [11.0, 11.0]]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn shi_tomasi_corners() -> Result<(), ImageError> {
+        let img = square(16, 4, 12)?;
+        let mut response = Image::from_size_val(img.size(), 0.0)?;
+        shi_tomasi_response(&img, &mut response, 3)?;
+
+        let at = |x: usize, y: usize| response.as_slice()[y * 16 + x];
+        assert!(at(4, 4) > 1.0);
+        assert!(at(8, 4).abs() < 1e-6 && at(8, 8).abs() < 1e-6);
+        assert!(response.as_slice().iter().all(|&r| r >= -1e-6));
+
+        let keypoints = non_max_suppression(&response, 0.5, 3.0, Some(2));
+        assert_eq!(keypoints.len(), 2);
+        assert_eq!(keypoints[0].response, keypoints[1].response);
+
+        let mut dst = Image::from_size_val([4, 4].into(), 0.0)?;
+        assert!(shi_tomasi_response(&img, &mut dst, 3).is_err());
+        let mut dst = Image::from_size_val(img.size(), 0.0)?;
+        assert!(harris_response(&img, &mut dst, 2, 0.04).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn corners_empty_image() -> Result<(), ImageError> {
+        for size in [[0, 4], [4, 0], [0, 0]] {
+            let img = Image::from_size_val(size.into(), 0.0)?;
+            let mut dst = Image::from_size_val(size.into(), 0.0)?;
+            harris_response(&img, &mut dst, 3, 0.04)?;
+            shi_tomasi_response(&img, &mut dst, 3)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+use kornia_image::{Image, ImageError};
+
+use crate::parallel;
+
+/// The offsets of the Bresenham circle of radius 3 around a pixel, clockwise from the top.
+const CIRCLE: [(isize, isize); 16] = [
+    (0, -3),
+    (1, -3),
+    (2, -2),
+    (3, -1),
+    (3, 0),
+    (3, 1),
+    (2, 2),
+    (1, 3),
+    (0, 3),
+    (-1, 3),
+    (-2, 2),
+    (-3, 1),
+    (-3, 0),
+    (-3, -1),
+    (-2, -2),
+    (-1, -3),
+];
+
+/// The variant of the FAST detector.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FastType {
+    /// A corner has at least 9 contiguous pixels of the circle brighter or darker than it.
+    #[default]
+    Fast9,
+    /// A corner has at least 12 contiguous pixels of the circle brighter or darker than it.
+    Fast12,
+}
+
+impl FastType {
+    /// The minimum length of the arc of the circle.
+    fn arc_length(&self) -> usize {
+        match self {
+            FastType::Fast9 => 9,
+            FastType::Fast12 => 12,
+        }
+    }
+}
+
+/// The length of the longest circular run of true values.
+fn longest_arc(mask: u16) -> usize {
+    if mask == u16::MAX {
+        return 16;
+    }
+    // duplicate the circle to find the runs wrapping around
+    let mut mask = (mask as u32) | ((mask as u32) << 16);
+    let mut length = 0;
+    while mask != 0 {
+        mask &= mask << 1;
+        length += 1;
+    }
+    length
+}
+
+/// Compute the FAST corner response of an image.
+///
+/// A pixel is a corner if a contiguous arc of the circle of 16 pixels around it is brighter or
+/// darker than the pixel by more than the threshold (Rosten and Drummond). The response of a
+/// corner is the sum of the absolute differences between the pixel and the brighter or darker
+/// pixels of the circle, minus the threshold, and is zero elsewhere. The pixels closer than 3
+/// pixels to the border are not corners.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W).
+/// * `dst` - The destination image with shape (H, W).
+/// * `threshold` - The minimum intensity difference between the pixel and the arc.
+/// * `fast_type` - The minimum length of the arc.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::features::{fast_response, non_max_suppression, FastType};
+///
+/// let size = ImageSize {
+///     width: 12,
+///     height: 12,
+/// };
+/// // a bright square in the bottom right corner
+/// let image = Image::<f32, 1>::new(
+///     size,
+///     (0..144).map(|i| if i % 12 >= 6 && i / 12 >= 6 { 1.0 } else { 0.0 }).collect(),
+/// )
+/// .unwrap();
+///
+/// let mut response = Image::<f32, 1>::from_size_val(size, 0.0).unwrap();
+/// fast_response(&image, &mut response, 0.5, FastType::Fast9).unwrap();
+///
+/// let keypoints = non_max_suppression(&response, 0.0, 3.0, None);
+/// assert_eq!(keypoints.len(), 1);
+/// assert_eq!(keypoints[0].position, [6.0, 6.0]);
+/// ```
+pub fn fast_response(
+    src: &Image<f32, 1>,
+    dst: &mut Image<f32, 1>,
+    threshold: f32,
+    fast_type: FastType,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let (cols, rows) = (src.cols(), src.rows());
+    if cols == 0 || rows == 0 {
+        return Ok(());
+    }
+
+    let arc_length = fast_type.arc_length();
+    // an arc covers at least this number of the 4 pixels at the top, right, bottom and left
+    let min_compass = arc_length / 4;
+    let offsets = CIRCLE.map(|(dx, dy)| dy * cols as isize + dx);
+    let src_data = src.as_slice();
+
+    parallel::par_iter_rows_indexed(dst, |y, row| {
+        row.fill(0.0);
+        if y < 3 || y + 3 >= rows {
+            return;
+        }
+
+        for (x, r) in row
+            .iter_mut()
+            .enumerate()
+            .take(cols.saturating_sub(3))
+            .skip(3)
+        {
+            let i = (y * cols + x) as isize;
+            let center = src_data[i as usize];
+            let circle = offsets.map(|o| src_data[(i + o) as usize]);
+
+            // reject most of the pixels with the 4 compass pixels first
+            let (mut brighter, mut darker) = (0, 0);
+            for p in [circle[0], circle[4], circle[8], circle[12]] {
+                brighter += (p > center + threshold) as usize;
+                darker += (p < center - threshold) as usize;
+            }
+            if brighter < min_compass && darker < min_compass {
+                continue;
+            }
+
+            let (mut brighter_mask, mut darker_mask) = (0u16, 0u16);
+            let (mut brighter_sum, mut darker_sum) = (0.0, 0.0);
+            for (k, &p) in circle.iter().enumerate() {
+                if p > center + threshold {
+                    brighter_mask |= 1 << k;
+                    brighter_sum += p - center - threshold;
+                } else if p < center - threshold {
+                    darker_mask |= 1 << k;
+                    darker_sum += center - p - threshold;
+                }
+            }
+
+            if longest_arc(brighter_mask) >= arc_length {
+                *r = brighter_sum;
+            }
+            if longest_arc(darker_mask) >= arc_length {
+                *r = r.max(darker_sum);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::non_max_suppression;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn longest_circular_arc() {
+        assert_eq!(longest_arc(0), 0);
+        assert_eq!(longest_arc(u16::MAX), 16);
+        assert_eq!(longest_arc(0b0000_0001_1111_0000), 5);
+        // the arc wraps around the first pixel
+        assert_eq!(longest_arc(0b1110_0000_0000_0111), 6);
+    }
+
+    #[test]
+    fn fast_corners() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 20,
+            height: 20,
+        };
+        // a dark square on a bright background
+        let img = Image::<f32, 1>::new(
+            size,
+            (0..400)
+                .map(|i| {
+                    let (x, y) = (i % 20, i / 20);
+                    if (5..15).contains(&x) && (5..15).contains(&y) {
+                        0.2
+                    } else {
+                        0.9
+                    }
+                })
+                .collect(),
+        )?;
+        let mut response = Image::from_size_val(size, 0.0)?;
+
+        fast_response(&img, &mut response, 0.3, FastType::Fast9)?;
+        let at = |r: &Image<f32, 1>, x: usize, y: usize| r.as_slice()[y * 20 + x];
+        // the 11 darker pixels of the circle of the corner
+        assert!((at(&response, 5, 5) - 11.0 * 0.4).abs() < 1e-5);
+        assert_eq!(at(&response, 10, 5), 0.0);
+        assert_eq!(at(&response, 10, 10), 0.0);
+
+        let keypoints = non_max_suppression(&response, 0.0, 3.0, None);
+        let mut positions = keypoints.iter().map(|k| k.position).collect::<Vec<_>>();
+        positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            positions,
+            [[5.0, 5.0], [5.0, 14.0], [14.0, 5.0], [14.0, 14.0]]
+        );
+
+        // the corner pixels have an arc of 11 pixels, longer arcs are needed with FAST-12
+        fast_response(&img, &mut response, 0.3, FastType::Fast12)?;
+        assert_eq!(at(&response, 5, 5), 0.0);
+        assert!(response.as_slice().iter().all(|&r| r == 0.0));
+
+        // a too high threshold
+        fast_response(&img, &mut response, 0.8, FastType::Fast9)?;
+        assert!(response.as_slice().iter().all(|&r| r == 0.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fast_empty_image() -> Result<(), ImageError> {
+        for size in [[0, 0], [0, 3], [3, 0]] {
+            let img = Image::from_size_val(size.into(), 0.0)?;
+            let mut dst = Image::from_size_val(size.into(), 0.0)?;
+            fast_response(&img, &mut dst, 0.3, FastType::Fast9)?;
+        }
+
+        Ok(())
+    }
+}
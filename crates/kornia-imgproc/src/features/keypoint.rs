@@ -0,0 +1,196 @@
+use kornia_image::Image;
+use rayon::prelude::*;
+
+/// A keypoint detected in an image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Keypoint {
+    /// The x and y coordinates of the keypoint in pixels of the input image.
+    pub position: [f32; 2],
+    /// The strength of the keypoint, the larger the better.
+    pub response: f32,
+    /// The scale of the image the keypoint was detected in, relative to the input image.
+    pub scale: f32,
+    /// The orientation of the keypoint in radians, 0 if not computed.
+    pub angle: f32,
+}
+
+impl Keypoint {
+    /// Create a keypoint detected at the scale of the input image, without orientation.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the keypoint.
+    /// * `y` - The y coordinate of the keypoint.
+    /// * `response` - The strength of the keypoint.
+    pub fn new(x: f32, y: f32, response: f32) -> Self {
+        Self {
+            position: [x, y],
+            response,
+            scale: 1.0,
+            angle: 0.0,
+        }
+    }
+}
+
+/// Extract the keypoints of a response map with non-maximum suppression.
+///
+/// The keypoints are the local maxima of the response over their 3x3 neighborhood which are
+/// above the threshold. They are selected by decreasing response, skipping the keypoints closer
+/// than the minimum distance to a selected keypoint, until the maximum number is reached.
+///
+/// # Arguments
+///
+/// * `response` - The response map of a detector, e.g. [`super::harris_response`].
+/// * `threshold` - The response above which the pixels are keypoints.
+/// * `min_distance` - The minimum euclidean distance in pixels between two keypoints, 0 to keep
+///   all the local maxima.
+/// * `max_keypoints` - The maximum number of keypoints to return, None to return all of them.
+///
+/// # Returns
+///
+/// The keypoints sorted by decreasing response, the keypoints with the same response are sorted
+/// in raster order.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::features::non_max_suppression;
+///
+/// let response = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 5,
+///         height: 1,
+///     },
+///     vec![0.0, 2.0, 1.0, 0.0, 3.0],
+/// )
+/// .unwrap();
+///
+/// let keypoints = non_max_suppression(&response, 0.5, 0.0, None);
+/// assert_eq!(keypoints.len(), 2);
+/// assert_eq!(keypoints[0].position, [4.0, 0.0]);
+/// assert_eq!(keypoints[1].position, [1.0, 0.0]);
+/// ```
+pub fn non_max_suppression(
+    response: &Image<f32, 1>,
+    threshold: f32,
+    min_distance: f32,
+    max_keypoints: Option<usize>,
+) -> Vec<Keypoint> {
+    let (cols, rows) = (response.cols(), response.rows());
+    if cols == 0 || rows == 0 || max_keypoints == Some(0) {
+        return Vec::new();
+    }
+
+    // the local maxima, a plateau keeps its pixels which have no equal neighbor before them
+    let data = response.as_slice();
+    let mut candidates = data
+        .par_chunks_exact(cols)
+        .enumerate()
+        .flat_map_iter(|(y, row)| {
+            row.iter().enumerate().filter_map(move |(x, &v)| {
+                if v <= threshold {
+                    return None;
+                }
+                for ny in y.saturating_sub(1)..=(y + 1).min(rows - 1) {
+                    for nx in x.saturating_sub(1)..=(x + 1).min(cols - 1) {
+                        let n = data[ny * cols + nx];
+                        let before = (ny, nx) < (y, x);
+                        if n > v || (before && n == v) {
+                            return None;
+                        }
+                    }
+                }
+                Some(Keypoint::new(x as f32, y as f32, v))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // the sort is stable so that the ties stay in raster order
+    candidates.sort_by(|a, b| b.response.total_cmp(&a.response));
+
+    let max_keypoints = max_keypoints.unwrap_or(usize::MAX);
+    if min_distance <= 0.0 {
+        candidates.truncate(max_keypoints);
+        return candidates;
+    }
+
+    // greedy selection with a grid of cells of the size of the minimum distance, so that only the
+    // neighboring cells need to be checked
+    let cell_size = min_distance;
+    let grid_cols = (cols as f32 / cell_size).ceil() as usize + 1;
+    let grid_rows = (rows as f32 / cell_size).ceil() as usize + 1;
+    let mut grid = vec![Vec::<[f32; 2]>::new(); grid_cols * grid_rows];
+    let min_distance_sq = min_distance * min_distance;
+
+    let mut keypoints = Vec::new();
+    for keypoint in candidates {
+        let [x, y] = keypoint.position;
+        let (cx, cy) = ((x / cell_size) as usize, (y / cell_size) as usize);
+        let too_close = (cy.saturating_sub(1)..=(cy + 1).min(grid_rows - 1)).any(|gy| {
+            (cx.saturating_sub(1)..=(cx + 1).min(grid_cols - 1)).any(|gx| {
+                grid[gy * grid_cols + gx]
+                    .iter()
+                    .any(|[kx, ky]| (kx - x) * (kx - x) + (ky - y) * (ky - y) < min_distance_sq)
+            })
+        });
+        if too_close {
+            continue;
+        }
+
+        grid[cy * grid_cols + cx].push(keypoint.position);
+        keypoints.push(keypoint);
+        if keypoints.len() == max_keypoints {
+            break;
+        }
+    }
+
+    keypoints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::{ImageError, ImageSize};
+
+    #[test]
+    fn non_max_suppression_selection() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 4,
+        };
+        #[rustfmt::skip]
+        let response = Image::<f32, 1>::new(
+            size,
+            vec![
+                5.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+                0.0, 0.0, 4.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0, 2.0, 2.0,
+                0.0, 3.0, 0.0, 0.0, 0.0, 0.0,
+            ],
+        )?;
+
+        // the plateau keeps a single keypoint
+        let keypoints = non_max_suppression(&response, 0.0, 0.0, None);
+        let positions = keypoints.iter().map(|k| k.position).collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            [[0.0, 0.0], [2.0, 1.0], [1.0, 3.0], [4.0, 2.0], [5.0, 0.0]]
+        );
+        assert_eq!(keypoints[0].response, 5.0);
+        assert_eq!(keypoints[0].scale, 1.0);
+
+        // the threshold and the maximum number of keypoints
+        let keypoints = non_max_suppression(&response, 1.0, 0.0, Some(3));
+        assert_eq!(keypoints.len(), 3);
+        assert_eq!(keypoints[2].position, [1.0, 3.0]);
+        assert_eq!(non_max_suppression(&response, 5.0, 0.0, None), []);
+
+        // the keypoints closer than 2.5 pixels to a stronger keypoint are removed
+        let keypoints = non_max_suppression(&response, 0.0, 2.5, None);
+        let positions = keypoints.iter().map(|k| k.position).collect::<Vec<_>>();
+        assert_eq!(positions, [[0.0, 0.0], [1.0, 3.0], [4.0, 2.0]]);
+
+        Ok(())
+    }
+}
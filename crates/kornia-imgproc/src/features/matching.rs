@@ -0,0 +1,151 @@
+use rayon::prelude::*;
+
+/// A match between a query descriptor and a train descriptor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// The index of the descriptor in the query set.
+    pub query: usize,
+    /// The index of the descriptor in the train set.
+    pub train: usize,
+    /// The Hamming distance between the two descriptors.
+    pub distance: u32,
+}
+
+/// Compute the Hamming distance between two binary descriptors.
+///
+/// # Arguments
+///
+/// * `a` - The first descriptor.
+/// * `b` - The second descriptor, with the same length as the first one.
+///
+/// # Returns
+///
+/// The number of bits which differ between the descriptors.
+pub fn hamming_distance(a: &[u8], b: &[u8]) -> u32 {
+    a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum()
+}
+
+/// The index of a descriptor and its distance to another descriptor.
+type Neighbor = Option<(usize, u32)>;
+
+/// The two nearest train descriptors of a descriptor.
+fn two_nearest<D: AsRef<[u8]>>(descriptor: &D, train: &[D]) -> (Neighbor, Neighbor) {
+    let mut best: Neighbor = None;
+    let mut second: Neighbor = None;
+    for (i, t) in train.iter().enumerate() {
+        let distance = hamming_distance(descriptor.as_ref(), t.as_ref());
+        if best.map_or(true, |(_, d)| distance < d) {
+            second = best;
+            best = Some((i, distance));
+        } else if second.map_or(true, |(_, d)| distance < d) {
+            second = Some((i, distance));
+        }
+    }
+    (best, second)
+}
+
+/// Match two sets of binary descriptors by brute force with the Hamming distance.
+///
+/// Each query descriptor is matched to its nearest train descriptor, the match is kept if it
+/// passes the ratio test and the cross check when they are enabled.
+///
+/// # Arguments
+///
+/// * `query` - The descriptors to match, e.g. from [`super::orb_detect_and_compute`].
+/// * `train` - The descriptors to search the matches in.
+/// * `max_ratio` - The maximum ratio between the distances of the nearest and of the second
+///   nearest train descriptors (Lowe's ratio test), None to disable the test.
+/// * `cross_check` - Keep only the matches whose query descriptor is also the nearest query
+///   descriptor of the train descriptor.
+///
+/// # Returns
+///
+/// The matches, sorted by query index.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::features::match_descriptors;
+///
+/// let query = [[0b0000_1111u8; 4], [0b1111_0000; 4]];
+/// let train = [[0b1111_0001u8; 4], [0b0000_0111; 4], [0b0000_0000; 4]];
+///
+/// let matches = match_descriptors(&query, &train, None, true);
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!((matches[0].query, matches[0].train, matches[0].distance), (0, 1, 4));
+/// assert_eq!((matches[1].query, matches[1].train, matches[1].distance), (1, 0, 4));
+/// ```
+pub fn match_descriptors<D: AsRef<[u8]> + Sync>(
+    query: &[D],
+    train: &[D],
+    max_ratio: Option<f32>,
+    cross_check: bool,
+) -> Vec<Match> {
+    let reverse = if cross_check {
+        train
+            .par_iter()
+            .map(|t| two_nearest(t, query).0.map(|(i, _)| i))
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    query
+        .par_iter()
+        .enumerate()
+        .filter_map(|(query_idx, q)| {
+            let (best, second) = two_nearest(q, train);
+            let (train_idx, distance) = best?;
+            if let (Some(ratio), Some((_, second_distance))) = (max_ratio, second) {
+                if distance as f32 >= ratio * second_distance as f32 {
+                    return None;
+                }
+            }
+            if cross_check && reverse[train_idx] != Some(query_idx) {
+                return None;
+            }
+            Some(Match {
+                query: query_idx,
+                train: train_idx,
+                distance,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamming() {
+        assert_eq!(hamming_distance(&[0xff, 0x00], &[0xff, 0x00]), 0);
+        assert_eq!(hamming_distance(&[0xff, 0x00], &[0x0f, 0x01]), 5);
+        assert_eq!(hamming_distance(&[0u8; 32], &[0xff; 32]), 256);
+    }
+
+    #[test]
+    fn match_ratio_and_cross_check() {
+        let query = [[0b0000_0000u8], [0b1111_1111], [0b0011_1100]];
+        let train = [[0b0000_0001u8], [0b1111_1110], [0b0011_0000], [0b0000_1100]];
+
+        // the nearest train descriptors, the third query is at 2 bits of both the third and
+        // fourth train descriptors and is matched to the first one found
+        let matches = match_descriptors(&query, &train, None, false);
+        let pairs = matches
+            .iter()
+            .map(|m| (m.query, m.train, m.distance))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, [(0, 0, 1), (1, 1, 1), (2, 2, 2)]);
+
+        // the ambiguous match is rejected by the ratio test
+        let matches = match_descriptors(&query, &train, Some(0.8), false);
+        assert_eq!(matches.iter().map(|m| m.query).collect::<Vec<_>>(), [0, 1]);
+
+        // the first query is as near to the third train descriptor as the third query
+        let matches = match_descriptors(&query, &train, None, true);
+        assert_eq!(matches.iter().map(|m| m.query).collect::<Vec<_>>(), [0, 1]);
+
+        assert!(match_descriptors(&query, &[] as &[[u8; 1]], None, true).is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+//! Feature detection
+//!
+//! This module provides keypoint detectors, binary descriptors and descriptor matching.
+
+/// Harris and Shi-Tomasi corner responses
+mod corners;
+pub use corners::*;
+
+/// FAST corner detector
+mod fast;
+pub use fast::*;
+
+/// Hessian response
+mod hessian;
+pub use hessian::*;
+
+/// Keypoints and non-maximum suppression
+mod keypoint;
+pub use keypoint::*;
+
+/// Binary descriptors matching
+mod matching;
+pub use matching::*;
+
+/// ORB detector and descriptor
+mod orb;
+pub use orb::*;
@@ -0,0 +1,391 @@
+use kornia_image::{Image, ImageError};
+use rayon::prelude::*;
+
+use super::{fast_response, harris_response, non_max_suppression, FastType, Keypoint};
+use crate::{
+    filter::{gaussian_blur, BorderType},
+    pyramid::GaussianPyramid,
+};
+
+/// A 256 bits binary descriptor, the bit `i` being the bit `i % 8` of the byte `i / 8`.
+pub type OrbDescriptor = [u8; 32];
+
+/// The radius of the patch used to compute the orientation of the keypoints.
+const PATCH_RADIUS: usize = 15;
+
+/// The radius within which the points of the BRIEF pattern are sampled.
+const PATTERN_RADIUS: f32 = 13.0;
+
+/// The configuration of the ORB detector and descriptor.
+#[derive(Clone, Debug)]
+pub struct OrbConfig {
+    /// The maximum number of keypoints to detect over all the levels.
+    pub num_features: usize,
+    /// The number of levels of the image pyramid.
+    pub num_levels: usize,
+    /// The ratio between the sizes of two consecutive levels of the pyramid.
+    pub scale_factor: f32,
+    /// The threshold of the FAST detector, in the units of the image intensity.
+    pub fast_threshold: f32,
+}
+
+impl Default for OrbConfig {
+    /// The default configuration for images with intensities in [0, 1].
+    fn default() -> Self {
+        Self {
+            num_features: 500,
+            num_levels: 8,
+            scale_factor: 1.2,
+            fast_threshold: 20.0 / 255.0,
+        }
+    }
+}
+
+/// The BRIEF sampling pattern: 256 pairs of points drawn from an isotropic Gaussian.
+///
+/// The points are drawn with a fixed seed so that the descriptors of different images can be
+/// compared, and are kept within a disk so that the rotated pattern stays within the patch.
+fn brief_pattern() -> Vec<[[f32; 2]; 2]> {
+    // xorshift64 generator with the Box-Muller transform
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut uniform = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        ((state >> 40) as f32 + 0.5) / (1u64 << 24) as f32
+    };
+    let sigma = (2 * PATCH_RADIUS + 1) as f32 / 5.0;
+    let mut point = move || loop {
+        let (u, v) = (uniform(), uniform());
+        let r = sigma * (-2.0 * u.ln()).sqrt();
+        let (sin, cos) = (2.0 * std::f32::consts::PI * v).sin_cos();
+        let p = [r * cos, r * sin];
+        if r <= PATTERN_RADIUS {
+            return p;
+        }
+    };
+    (0..256).map(|_| [point(), point()]).collect()
+}
+
+/// The orientation of a keypoint, the angle of the vector to the intensity centroid of the patch.
+fn intensity_centroid_angle(image: &Image<f32, 1>, x: usize, y: usize) -> f32 {
+    let cols = image.cols();
+    let data = image.as_slice();
+    let r = PATCH_RADIUS as isize;
+    let (mut m10, mut m01) = (0.0, 0.0);
+    for dy in -r..=r {
+        // the half width of the disk on this row
+        let w = ((r * r - dy * dy) as f32).sqrt() as isize;
+        let row = (y as isize + dy) as usize * cols;
+        for dx in -w..=w {
+            let v = data[row + (x as isize + dx) as usize];
+            m10 += dx as f32 * v;
+            m01 += dy as f32 * v;
+        }
+    }
+    m01.atan2(m10)
+}
+
+/// The steered BRIEF descriptor of a keypoint, comparing the pairs of the rotated pattern.
+fn steered_brief(
+    smoothed: &Image<f32, 1>,
+    pattern: &[[[f32; 2]; 2]],
+    x: usize,
+    y: usize,
+    angle: f32,
+) -> OrbDescriptor {
+    let cols = smoothed.cols();
+    let data = smoothed.as_slice();
+    let (sin, cos) = angle.sin_cos();
+    let sample = |[px, py]: [f32; 2]| {
+        let rx = (cos * px - sin * py).round() as isize;
+        let ry = (sin * px + cos * py).round() as isize;
+        data[(y as isize + ry) as usize * cols + (x as isize + rx) as usize]
+    };
+
+    let mut descriptor = [0u8; 32];
+    for (i, &[p, q]) in pattern.iter().enumerate() {
+        if sample(p) < sample(q) {
+            descriptor[i / 8] |= 1 << (i % 8);
+        }
+    }
+    descriptor
+}
+
+/// Detect the ORB keypoints of an image and compute their descriptors.
+///
+/// The keypoints are detected with FAST-9 in each level of a Gaussian pyramid and the strongest
+/// ones are kept according to their Harris response, the number of keypoints per level decreasing
+/// with the area of the level (Rublee et al.). Each keypoint is oriented by the intensity centroid
+/// of its patch and described by a BRIEF descriptor rotated by this orientation.
+///
+/// The BRIEF pattern is sampled from a Gaussian with a fixed seed, so the descriptors are not
+/// compatible with the ones of OpenCV.
+///
+/// # Arguments
+///
+/// * `src` - The source grayscale image with shape (H, W).
+/// * `config` - The configuration of the detector.
+///
+/// # Returns
+///
+/// The keypoints, in the coordinates of the source image, and their descriptors. The keypoints
+/// are ordered by level and by decreasing response within a level, and empty for an empty image.
+///
+/// # Errors
+///
+/// Returns an error if the scale factor is not greater than 1.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::Image;
+/// use kornia_imgproc::features::{orb_detect_and_compute, OrbConfig};
+///
+/// let image = Image::<f32, 1>::new(
+///     [64, 64].into(),
+///     (0..64 * 64)
+///         .map(|i| if (i % 64 / 16 + i / 64 / 16) % 2 == 0 { 0.0 } else { 1.0 })
+///         .collect(),
+/// )
+/// .unwrap();
+///
+/// let (keypoints, descriptors) = orb_detect_and_compute(&image, &OrbConfig::default()).unwrap();
+/// assert!(!keypoints.is_empty());
+/// assert_eq!(keypoints.len(), descriptors.len());
+/// ```
+pub fn orb_detect_and_compute(
+    src: &Image<f32, 1>,
+    config: &OrbConfig,
+) -> Result<(Vec<Keypoint>, Vec<OrbDescriptor>), ImageError> {
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let pyramid = GaussianPyramid::new(src, config.num_levels, config.scale_factor)?;
+    let pattern = brief_pattern();
+
+    // the number of features of a level is proportional to its area
+    let factor = 1.0 / (config.scale_factor * config.scale_factor);
+    let num_levels = pyramid.num_levels() as i32;
+    let first_level_features =
+        config.num_features as f32 * (1.0 - factor) / (1.0 - factor.powi(num_levels));
+
+    let mut keypoints = Vec::new();
+    let mut descriptors = Vec::new();
+    for (level_idx, level) in pyramid.levels().iter().enumerate() {
+        let (cols, rows) = (level.cols(), level.rows());
+        let remaining = config.num_features - keypoints.len();
+        let num_features = if level_idx as i32 == num_levels - 1 {
+            remaining
+        } else {
+            ((first_level_features * factor.powi(level_idx as i32)).round() as usize).min(remaining)
+        };
+        if num_features == 0 || cols <= 2 * PATCH_RADIUS || rows <= 2 * PATCH_RADIUS {
+            continue;
+        }
+
+        let mut fast = Image::from_size_val(level.size(), 0.0)?;
+        fast_response(level, &mut fast, config.fast_threshold, FastType::Fast9)?;
+        // the patches of the keypoints must lie within the image
+        fast.as_slice_mut()
+            .par_chunks_exact_mut(cols)
+            .enumerate()
+            .for_each(|(y, row)| {
+                if y < PATCH_RADIUS || y + PATCH_RADIUS >= rows {
+                    row.fill(0.0);
+                } else {
+                    row[..PATCH_RADIUS].fill(0.0);
+                    row[cols - PATCH_RADIUS..].fill(0.0);
+                }
+            });
+
+        // rank the FAST corners by their Harris response
+        let mut harris = Image::from_size_val(level.size(), 0.0)?;
+        harris_response(level, &mut harris, 7, 0.04)?;
+        let mut level_keypoints = non_max_suppression(&fast, 0.0, 0.0, Some(2 * num_features));
+        for keypoint in level_keypoints.iter_mut() {
+            let [x, y] = keypoint.position;
+            keypoint.response = harris.as_slice()[y as usize * cols + x as usize];
+        }
+        level_keypoints.sort_by(|a, b| b.response.total_cmp(&a.response));
+        level_keypoints.truncate(num_features);
+
+        let mut smoothed = Image::from_size_val(level.size(), 0.0)?;
+        gaussian_blur(
+            level,
+            &mut smoothed,
+            (7, 7),
+            (2.0, 2.0),
+            BorderType::Reflect101,
+        )?;
+
+        // map the pixel centers of the level to the source image
+        let scale = config.scale_factor.powi(level_idx as i32);
+        let (ratio_x, ratio_y) = (
+            src.cols() as f32 / cols as f32,
+            src.rows() as f32 / rows as f32,
+        );
+        let (level_keypoints, level_descriptors): (Vec<_>, Vec<_>) = level_keypoints
+            .par_iter()
+            .map(|keypoint| {
+                let (x, y) = (keypoint.position[0] as usize, keypoint.position[1] as usize);
+                let angle = intensity_centroid_angle(level, x, y);
+                let descriptor = steered_brief(&smoothed, &pattern, x, y, angle);
+                let keypoint = Keypoint {
+                    position: [
+                        (x as f32 + 0.5) * ratio_x - 0.5,
+                        (y as f32 + 0.5) * ratio_y - 0.5,
+                    ],
+                    response: keypoint.response,
+                    scale,
+                    angle,
+                };
+                (keypoint, descriptor)
+            })
+            .unzip();
+
+        keypoints.extend(level_keypoints);
+        descriptors.extend(level_descriptors);
+    }
+
+    Ok((keypoints, descriptors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::{match_descriptors, Match};
+    use kornia_image::ImageSize;
+
+    /// An image of random overlapping rectangles, sampled at an offset and rotated by quarter turns.
+    fn rectangles(size: usize, offset: (usize, usize), quarter_turns: usize) -> Image<f32, 1> {
+        let mut state = 12345u64;
+        let mut random = move |n: usize| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as usize % n
+        };
+        let canvas = 2 * size;
+        let mut data = vec![0.5f32; canvas * canvas];
+        for _ in 0..120 {
+            let (x, y) = (random(canvas), random(canvas));
+            let (w, h) = (4 + random(20), 4 + random(20));
+            let v = random(256) as f32 / 255.0;
+            for yy in y..(y + h).min(canvas) {
+                data[yy * canvas + x..yy * canvas + (x + w).min(canvas)].fill(v);
+            }
+        }
+
+        let image_data = (0..size * size)
+            .map(|i| {
+                let (mut x, mut y) = (i % size, i / size);
+                for _ in 0..quarter_turns {
+                    (x, y) = (y, size - 1 - x);
+                }
+                data[(y + offset.1) * canvas + x + offset.0]
+            })
+            .collect();
+        Image::new(
+            ImageSize {
+                width: size,
+                height: size,
+            },
+            image_data,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn orb_keypoints_and_descriptors() -> Result<(), ImageError> {
+        let img = rectangles(128, (0, 0), 0);
+        let config = OrbConfig {
+            num_features: 100,
+            ..Default::default()
+        };
+        let (keypoints, descriptors) = orb_detect_and_compute(&img, &config)?;
+
+        assert!(keypoints.len() > 50 && keypoints.len() <= 100);
+        assert_eq!(keypoints.len(), descriptors.len());
+        assert!(keypoints.iter().any(|k| k.scale > 1.0));
+        for k in &keypoints {
+            let [x, y] = k.position;
+            assert!((0.0..128.0).contains(&x) && (0.0..128.0).contains(&y));
+            assert!((-std::f32::consts::PI..=std::f32::consts::PI).contains(&k.angle));
+        }
+
+        // the descriptors are deterministic
+        let (_, again) = orb_detect_and_compute(&img, &config)?;
+        assert_eq!(descriptors, again);
+
+        assert!(orb_detect_and_compute(
+            &img,
+            &OrbConfig {
+                scale_factor: 1.0,
+                ..Default::default()
+            }
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn orb_empty_image() -> Result<(), ImageError> {
+        for size in [[0, 0], [0, 3], [3, 0]] {
+            let img = Image::from_size_val(size.into(), 0.0)?;
+            let (keypoints, descriptors) = orb_detect_and_compute(&img, &OrbConfig::default())?;
+            assert!(keypoints.is_empty() && descriptors.is_empty());
+        }
+
+        Ok(())
+    }
+
+    /// The fraction of the matches whose keypoints are related by the transform.
+    fn inlier_ratio(
+        matches: &[Match],
+        query: &[Keypoint],
+        train: &[Keypoint],
+        transform: impl Fn([f32; 2]) -> [f32; 2],
+    ) -> f32 {
+        let inliers = matches
+            .iter()
+            .filter(|m| {
+                let [x, y] = transform(query[m.query].position);
+                let [tx, ty] = train[m.train].position;
+                (x - tx).hypot(y - ty) < 2.0
+            })
+            .count();
+        inliers as f32 / matches.len() as f32
+    }
+
+    #[test]
+    fn orb_matching_translation_and_rotation() -> Result<(), ImageError> {
+        let config = OrbConfig::default();
+        let img = rectangles(128, (8, 8), 0);
+        let (keypoints, descriptors) = orb_detect_and_compute(&img, &config)?;
+
+        // the same scene shifted by (5, 3) pixels
+        let shifted = rectangles(128, (13, 11), 0);
+        let (shifted_keypoints, shifted_descriptors) = orb_detect_and_compute(&shifted, &config)?;
+        let matches = match_descriptors(&descriptors, &shifted_descriptors, None, true);
+        assert!(matches.len() > 30);
+        let ratio = inlier_ratio(&matches, &keypoints, &shifted_keypoints, |[x, y]| {
+            [x - 5.0, y - 3.0]
+        });
+        assert!(ratio > 0.8, "inlier ratio {ratio}");
+
+        // the same scene rotated by a quarter turn, the descriptors are steered by the orientation
+        let rotated = rectangles(128, (8, 8), 1);
+        let (rotated_keypoints, rotated_descriptors) = orb_detect_and_compute(&rotated, &config)?;
+        let matches = match_descriptors(&descriptors, &rotated_descriptors, Some(0.8), true);
+        assert!(matches.len() > 20);
+        let ratio = inlier_ratio(&matches, &keypoints, &rotated_keypoints, |[x, y]| {
+            [127.0 - y, x]
+        });
+        assert!(ratio > 0.6, "inlier ratio {ratio}");
+
+        Ok(())
+    }
+}
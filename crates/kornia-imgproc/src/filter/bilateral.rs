@@ -0,0 +1,214 @@
+use kornia_image::{Image, ImageError};
+
+use super::kernel_indices;
+use crate::{
+    interpolation::{BorderType, InterpolationPixel},
+    parallel,
+};
+
+/// Blur an image with a bilateral filter.
+///
+/// Each pixel is replaced by the average of its neighbors weighted by both their distance to the
+/// pixel and the difference of their colors, so that the edges are preserved. The color
+/// difference is the euclidean distance over the channels, in the units of the pixel type.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel_size` - The diameter of the neighborhood, an odd number.
+/// * `sigma_color` - The standard deviation of the gaussian over the color difference.
+/// * `sigma_space` - The standard deviation of the gaussian over the pixel distance.
+/// * `border` - The extrapolation of the pixels outside of the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Errors
+///
+/// Returns an error if the kernel size is even, if a sigma is not positive or if the images have
+/// different sizes.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::filter::{bilateral_filter, BorderType};
+///
+/// let image = Image::<u8, 1>::new(
+///     ImageSize {
+///         width: 4,
+///         height: 1,
+///     },
+///     vec![10, 12, 200, 202],
+/// )
+/// .unwrap();
+///
+/// let mut dst = Image::<u8, 1>::from_size_val(image.size(), 0).unwrap();
+///
+/// bilateral_filter(&image, &mut dst, 3, 20.0, 5.0, BorderType::Replicate).unwrap();
+/// assert_eq!(dst.as_slice(), &[11, 11, 201, 201]);
+/// ```
+pub fn bilateral_filter<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    kernel_size: usize,
+    sigma_color: f32,
+    sigma_space: f32,
+    border: BorderType,
+) -> Result<(), ImageError> {
+    if kernel_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(kernel_size, kernel_size));
+    }
+
+    if sigma_color.is_nan() || sigma_color <= 0.0 || sigma_space.is_nan() || sigma_space <= 0.0 {
+        return Err(ImageError::InvalidSigmaValue(sigma_color, sigma_space));
+    }
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let cols = src.cols();
+    if cols == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+
+    let x_indices = kernel_indices(cols, kernel_size, border);
+    let y_indices = kernel_indices(src.rows(), kernel_size, border);
+    let value = border.constant_value();
+    let src_data = src.as_slice();
+
+    let radius = (kernel_size / 2) as f32;
+    let space_coeff = -0.5 / (sigma_space * sigma_space);
+    let color_coeff = -0.5 / (sigma_color * sigma_color);
+    let space_weights = (0..kernel_size * kernel_size)
+        .map(|i| {
+            let dx = (i % kernel_size) as f32 - radius;
+            let dy = (i / kernel_size) as f32 - radius;
+            ((dx * dx + dy * dy) * space_coeff).exp()
+        })
+        .collect::<Vec<_>>();
+
+    parallel::par_iter_rows_indexed(dst, |y, dst_row| {
+        let y_taps = &y_indices[y * kernel_size..(y + 1) * kernel_size];
+        for (x, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+            let x_taps = &x_indices[x * kernel_size..(x + 1) * kernel_size];
+            let center = &src_data[(y * cols + x) * C..(y * cols + x + 1) * C];
+            let center = std::array::from_fn::<f32, C, _>(|ch| center[ch].to_f32());
+
+            let mut acc = [0.0; C];
+            let mut weight_sum = 0.0;
+            let taps = y_taps
+                .iter()
+                .flat_map(|y_tap| x_taps.iter().map(move |x_tap| (y_tap, x_tap)));
+            for ((y_tap, x_tap), space_weight) in taps.zip(&space_weights) {
+                let neighbor = match (y_tap, x_tap) {
+                    (Some(r), Some(c)) => {
+                        let pixel = &src_data[(r * cols + c) * C..(r * cols + c + 1) * C];
+                        std::array::from_fn::<f32, C, _>(|ch| pixel[ch].to_f32())
+                    }
+                    _ => [value; C],
+                };
+
+                let color_distance = neighbor
+                    .iter()
+                    .zip(&center)
+                    .map(|(n, c)| (n - c) * (n - c))
+                    .sum::<f32>();
+                let weight = space_weight * (color_distance * color_coeff).exp();
+
+                for (a, n) in acc.iter_mut().zip(&neighbor) {
+                    *a += weight * n;
+                }
+                weight_sum += weight;
+            }
+
+            // the center pixel has a unit color weight so the sum is positive
+            for (d, a) in dst_pixel.iter_mut().zip(acc) {
+                *d = T::from_f32(a / weight_sum);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn bilateral_filter_preserves_edges() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 3,
+        };
+        // a noisy step between two flat regions
+        let img = Image::<f32, 3>::new(
+            size,
+            (0..24)
+                .flat_map(|i| {
+                    let base = if i % 8 < 4 { 0.2 } else { 0.8 };
+                    let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+                    [base + noise, base, 1.0 - base]
+                })
+                .collect(),
+        )?;
+        let mut dst = Image::<f32, 3>::from_size_val(size, 0.0)?;
+        bilateral_filter(&img, &mut dst, 5, 0.1, 2.0, BorderType::Reflect101)?;
+
+        for (i, (d, s)) in dst
+            .as_slice()
+            .chunks_exact(3)
+            .zip(img.as_slice().chunks_exact(3))
+            .enumerate()
+        {
+            // the noise is smoothed but the step is kept
+            assert!((d[0] - s[1]).abs() < 0.01, "pixel {i}: {d:?}");
+            assert!((d[1] - s[1]).abs() < 1e-3, "pixel {i}: {d:?}");
+            assert!((d[2] - s[2]).abs() < 1e-3, "pixel {i}: {d:?}");
+        }
+
+        // a large color sigma blurs like a gaussian filter
+        bilateral_filter(&img, &mut dst, 5, 1e3, 2.0, BorderType::Reflect101)?;
+        let pixel = |x: usize| dst.as_slice()[(8 + x) * 3 + 1];
+        assert!(pixel(3) > 0.3 && pixel(4) < 0.7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bilateral_filter_constant_border() -> Result<(), ImageError> {
+        let img = Image::<u8, 1>::from_size_val([3, 3].into(), 100)?;
+        let mut dst = Image::<u8, 1>::from_size_val(img.size(), 0)?;
+
+        // a border far in color is ignored, a close border is averaged
+        bilateral_filter(&img, &mut dst, 3, 10.0, 10.0, BorderType::Constant(255.0))?;
+        assert_eq!(dst.as_slice(), &[100; 9]);
+        bilateral_filter(&img, &mut dst, 3, 1e4, 1e4, BorderType::Constant(109.0))?;
+        assert_eq!(dst.as_slice()[4], 100);
+        assert_eq!(dst.as_slice()[0], 105);
+
+        assert!(bilateral_filter(&img, &mut dst, 4, 1.0, 1.0, BorderType::Replicate).is_err());
+        for (sigma_color, sigma_space) in [(0.0, 1.0), (1.0, -1.0), (f32::NAN, 1.0)] {
+            assert!(matches!(
+                bilateral_filter(
+                    &img,
+                    &mut dst,
+                    3,
+                    sigma_color,
+                    sigma_space,
+                    BorderType::Replicate
+                ),
+                Err(ImageError::InvalidSigmaValue(..))
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use kornia_image::{Image, ImageError};
+
+use super::{sobel_gradients, BorderType};
+use crate::parallel;
+
+/// The norm used to compute the magnitude of the gradient.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientNorm {
+    /// The sum of the absolute values of the derivatives, faster to compute.
+    L1,
+    /// The euclidean norm of the derivatives, more accurate.
+    L2,
+}
+
+/// Detect the edges of an image with the Canny algorithm.
+///
+/// The gradient is computed with the sobel operator, thinned by non-maximum suppression along
+/// its direction and thresholded with hysteresis: the pixels above the high threshold are edges,
+/// and the pixels above the low threshold are edges if they are connected to an edge. The image
+/// is mirrored at the borders.
+///
+/// # Arguments
+///
+/// * `src` - The input grayscale image.
+/// * `dst` - The output edge map, with 255 for the edges and 0 elsewhere.
+/// * `low_threshold` - The gradient magnitude above which the pixels connected to an edge are
+///   edges.
+/// * `high_threshold` - The gradient magnitude above which the pixels are edges.
+/// * `aperture` - The size of the sobel kernel, an odd number greater than 1.
+/// * `norm` - The norm of the gradient magnitude.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::filter::{canny, GradientNorm};
+///
+/// let size = ImageSize {
+///     width: 6,
+///     height: 4,
+/// };
+/// let image = Image::<f32, 1>::new(
+///     size,
+///     (0..24).map(|i| if i % 6 < 3 { 0.0 } else { 1.0 }).collect(),
+/// )
+/// .unwrap();
+///
+/// let mut edges = Image::<u8, 1>::from_size_val(size, 0).unwrap();
+///
+/// canny(&image, &mut edges, 1.0, 2.0, 3, GradientNorm::L2).unwrap();
+/// assert_eq!(edges.as_slice()[..6], [0, 0, 255, 0, 0, 0]);
+/// ```
+pub fn canny(
+    src: &Image<f32, 1>,
+    dst: &mut Image<u8, 1>,
+    low_threshold: f32,
+    high_threshold: f32,
+    aperture: usize,
+    norm: GradientNorm,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let (cols, rows) = (src.cols(), src.rows());
+    if cols == 0 || rows == 0 {
+        return Ok(());
+    }
+
+    let mut dx = Image::from_size_val(src.size(), 0.0)?;
+    let mut dy = Image::from_size_val(src.size(), 0.0)?;
+    sobel_gradients(src, &mut dx, &mut dy, aperture, BorderType::Reflect101)?;
+
+    let mut magnitude = Image::<f32, 1>::from_size_val(src.size(), 0.0)?;
+    parallel::par_iter_rows_val_two(&dx, &dy, &mut magnitude, |&gx, &gy, m| {
+        *m = match norm {
+            GradientNorm::L1 => gx.abs() + gy.abs(),
+            GradientNorm::L2 => gx.hypot(gy),
+        };
+    });
+
+    // non-maximum suppression: 0 for no edge, 1 for weak and 2 for strong edges
+    let (dx, dy, magnitude) = (dx.as_slice(), dy.as_slice(), magnitude.as_slice());
+    let magnitude_at = |x: usize, y: usize, sx: isize, sy: isize| {
+        let map = |i: usize, d: isize, len: usize| {
+            BorderType::Reflect101
+                .map_index(i as isize + d, len)
+                .unwrap_or(i)
+        };
+        magnitude[map(y, sy, rows) * cols + map(x, sx, cols)]
+    };
+    let mut classes = Image::<u8, 1>::from_size_val(src.size(), 0)?;
+    parallel::par_iter_rows_indexed(&mut classes, |y, row| {
+        for (x, class) in row.iter_mut().enumerate() {
+            let i = y * cols + x;
+            let m = magnitude[i];
+            if m <= low_threshold {
+                continue;
+            }
+
+            // the neighbors along the gradient direction, quantized to 45 degrees
+            let (gx, gy) = (dx[i].abs(), dy[i].abs());
+            let (sx, sy) = if gy <= gx * std::f32::consts::FRAC_PI_8.tan() {
+                (1, 0)
+            } else if gy > gx * (3.0 * std::f32::consts::FRAC_PI_8).tan() {
+                (0, 1)
+            } else if (dx[i] < 0.0) == (dy[i] < 0.0) {
+                (1, 1)
+            } else {
+                (-1, 1)
+            };
+
+            if m > magnitude_at(x, y, -sx, -sy) && m >= magnitude_at(x, y, sx, sy) {
+                *class = if m > high_threshold { 2 } else { 1 };
+            }
+        }
+    });
+
+    // hysteresis: grow the strong edges through the weak edges
+    let classes = classes.as_slice();
+    let edges = dst.as_slice_mut();
+    edges.fill(0);
+    let mut stack = Vec::new();
+    for (i, &class) in classes.iter().enumerate() {
+        if class != 2 || edges[i] != 0 {
+            continue;
+        }
+        edges[i] = 255;
+        stack.push(i);
+        while let Some(j) = stack.pop() {
+            let (x, y) = (j % cols, j / cols);
+            for ny in y.saturating_sub(1)..=(y + 1).min(rows - 1) {
+                for nx in x.saturating_sub(1)..=(x + 1).min(cols - 1) {
+                    let k = ny * cols + nx;
+                    if classes[k] != 0 && edges[k] == 0 {
+                        edges[k] = 255;
+                        stack.push(k);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GradientNorm;
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn canny_constant_image() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 6,
+        };
+        let image = Image::<f32, 1>::from_size_val(size, 0.7)?;
+        let mut edges = Image::<u8, 1>::from_size_val(size, 1)?;
+
+        for aperture in [3, 5, 7] {
+            super::canny(&image, &mut edges, 0.1, 0.2, aperture, GradientNorm::L1)?;
+            assert!(edges.as_slice().iter().all(|&e| e == 0));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn canny_square() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 10,
+            height: 10,
+        };
+        let data = (0..100)
+            .map(|i| {
+                let (x, y) = (i % 10, i / 10);
+                if (3..7).contains(&x) && (3..7).contains(&y) {
+                    1.0
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        let image = Image::<f32, 1>::new(size, data)?;
+        let mut edges = Image::<u8, 1>::from_size_val(size, 0)?;
+
+        super::canny(&image, &mut edges, 1.0, 2.0, 3, GradientNorm::L2)?;
+
+        // a one pixel wide outline along the sides of the square
+        let is_edge = |x: usize, y: usize| edges.as_slice()[y * 10 + x] == 255;
+        for k in 4..6 {
+            assert!(is_edge(2, k) && !is_edge(3, k) && !is_edge(1, k), "row {k}");
+            assert!(is_edge(k, 2) && !is_edge(k, 3), "column {k}");
+        }
+        assert!(!is_edge(5, 5) && !is_edge(0, 0) && !is_edge(9, 9));
+        Ok(())
+    }
+
+    #[test]
+    fn canny_hysteresis() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 6,
+        };
+        // a vertical step whose contrast drops in the bottom rows
+        let data = (0..48)
+            .map(|i| {
+                let (x, y) = (i % 8, i / 8);
+                match (x < 4, y < 2) {
+                    (true, _) => 0.0,
+                    (false, true) => 1.0,
+                    (false, false) => 0.3,
+                }
+            })
+            .collect();
+        let image = Image::<f32, 1>::new(size, data)?;
+        let mut edges = Image::<u8, 1>::from_size_val(size, 0)?;
+
+        // the weak part of the edge is kept because it is connected to the strong part
+        super::canny(&image, &mut edges, 0.5, 3.0, 3, GradientNorm::L1)?;
+        let column = (3..6)
+            .map(|y| edges.as_slice()[y * 8 + 3])
+            .collect::<Vec<_>>();
+        assert_eq!(column, [255; 3]);
+        assert!(edges
+            .as_slice()
+            .chunks_exact(8)
+            .all(|row| row.contains(&255)));
+
+        // without a strong part nothing remains
+        super::canny(&image, &mut edges, 0.5, 10.0, 3, GradientNorm::L1)?;
+        assert!(edges.as_slice().iter().all(|&e| e == 0));
+        Ok(())
+    }
+}
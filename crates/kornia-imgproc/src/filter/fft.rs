@@ -0,0 +1,173 @@
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::ParallelSliceMut,
+};
+
+/// A complex number in double precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// Create a complex number from its real part.
+    pub fn from_real(re: f64) -> Self {
+        Self { re, im: 0.0 }
+    }
+
+    /// The complex conjugate.
+    pub fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl std::ops::Add for Complex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl std::ops::Sub for Complex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl std::ops::Mul for Complex {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+/// Compute the discrete Fourier transform of a sequence in place.
+///
+/// Iterative radix-2 Cooley-Tukey, the inverse transform is normalized by the length.
+///
+/// PRECONDITION: the length of `data` must be a power of two.
+pub(crate) fn fft(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    debug_assert!(n.is_power_of_two());
+
+    // bit reversal permutation
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * std::f64::consts::PI / len as f64;
+        let twiddles = (0..len / 2)
+            .map(|k| {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                Complex { re: cos, im: sin }
+            })
+            .collect::<Vec<_>>();
+        for chunk in data.chunks_exact_mut(len) {
+            let (even, odd) = chunk.split_at_mut(len / 2);
+            for ((e, o), w) in even.iter_mut().zip(odd.iter_mut()).zip(&twiddles) {
+                let t = *o * *w;
+                *o = *e - t;
+                *e = *e + t;
+            }
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        let scale = 1.0 / n as f64;
+        data.iter_mut().for_each(|c| {
+            c.re *= scale;
+            c.im *= scale;
+        });
+    }
+}
+
+/// Transpose a row-major matrix.
+fn transpose(src: &[Complex], dst: &mut [Complex], width: usize, height: usize) {
+    dst.par_chunks_exact_mut(height)
+        .enumerate()
+        .for_each(|(x, dst_row)| {
+            for (y, d) in dst_row.iter_mut().enumerate() {
+                *d = src[y * width + x];
+            }
+        });
+}
+
+/// Compute the two dimensional discrete Fourier transform of a row-major matrix in place.
+///
+/// PRECONDITION: `width` and `height` must be powers of two.
+pub(crate) fn fft2d(data: &mut [Complex], width: usize, height: usize, inverse: bool) {
+    data.par_chunks_exact_mut(width)
+        .for_each(|row| fft(row, inverse));
+
+    let mut transposed = vec![Complex::default(); data.len()];
+    transpose(data, &mut transposed, width, height);
+    transposed
+        .par_chunks_exact_mut(height)
+        .for_each(|column| fft(column, inverse));
+    transpose(&transposed, data, height, width);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fft, fft2d, Complex};
+
+    #[test]
+    fn fft_roundtrip() {
+        let signal = [1.0, 2.0, 0.0, -1.0, 3.0, 0.5, 0.0, 2.0];
+        let mut data = signal.map(Complex::from_real);
+
+        fft(&mut data, false);
+        // the first coefficient is the sum of the signal
+        assert!((data[0].re - 7.5).abs() < 1e-12 && data[0].im.abs() < 1e-12);
+        // a naive dft of the fourth coefficient
+        let expected = signal
+            .iter()
+            .enumerate()
+            .fold(Complex::default(), |acc, (i, &s)| {
+                let (sin, cos) = (-2.0 * std::f64::consts::PI * 3.0 * i as f64 / 8.0).sin_cos();
+                acc + Complex::from_real(s) * Complex { re: cos, im: sin }
+            });
+        assert!((data[3].re - expected.re).abs() < 1e-12);
+        assert!((data[3].im - expected.im).abs() < 1e-12);
+
+        fft(&mut data, true);
+        for (c, s) in data.iter().zip(signal) {
+            assert!((c.re - s).abs() < 1e-12 && c.im.abs() < 1e-12);
+        }
+
+        let mut data = (0..32)
+            .map(|i| Complex::from_real(i as f64))
+            .collect::<Vec<_>>();
+        fft2d(&mut data, 8, 4, false);
+        assert!((data[0].re - 496.0).abs() < 1e-9);
+        fft2d(&mut data, 8, 4, true);
+        for (i, c) in data.iter().enumerate() {
+            assert!((c.re - i as f64).abs() < 1e-9 && c.im.abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use kornia_image::{Image, ImageError};
+
+use super::fft::{fft2d, Complex};
+use super::kernel_indices;
+use crate::{interpolation::BorderType, parallel};
+
+/// The kernel area from which the filter is computed in the frequency domain.
+const FFT_MIN_KERNEL_AREA: usize = 11 * 11;
+
+/// Convolve an image with an arbitrary 2D kernel.
+///
+/// The filter computes the correlation of the image with the kernel, as in OpenCV: flip the kernel
+/// to compute a true convolution. The kernel is anchored at its center, `(width / 2, height / 2)`.
+/// The kernels of 11x11 pixels and more are applied in the frequency domain.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel` - The kernel with shape (kernel_height, kernel_width).
+/// * `border` - The extrapolation of the pixels outside of the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Errors
+///
+/// Returns an error if the kernel is empty or if the images have different sizes.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::filter::{filter2d, BorderType};
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 3,
+///         height: 3,
+///     },
+///     vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+/// )
+/// .unwrap();
+///
+/// let laplacian = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 3,
+///         height: 3,
+///     },
+///     vec![0.0, 1.0, 0.0, 1.0, -4.0, 1.0, 0.0, 1.0, 0.0],
+/// )
+/// .unwrap();
+///
+/// let mut dst = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// filter2d(&image, &mut dst, &laplacian, BorderType::Constant(0.0)).unwrap();
+/// assert_eq!(dst.as_slice(), &[0.0, 1.0, 0.0, 1.0, -4.0, 1.0, 0.0, 1.0, 0.0]);
+/// ```
+pub fn filter2d<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    kernel: &Image<f32, 1>,
+    border: BorderType,
+) -> Result<(), ImageError> {
+    if kernel.cols() == 0 || kernel.rows() == 0 {
+        return Err(ImageError::InvalidKernelLength(
+            kernel.cols(),
+            kernel.rows(),
+        ));
+    }
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+
+    if kernel.cols() * kernel.rows() >= FFT_MIN_KERNEL_AREA {
+        filter2d_fft(src, dst, kernel, border);
+    } else {
+        filter2d_direct(src, dst, kernel, border);
+    }
+
+    Ok(())
+}
+
+/// Correlate an image with a kernel in the spatial domain.
+fn filter2d_direct<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    kernel: &Image<f32, 1>,
+    border: BorderType,
+) {
+    let (cols, rows) = (src.cols(), src.rows());
+    let (kernel_cols, kernel_rows) = (kernel.cols(), kernel.rows());
+    let x_indices = kernel_indices(cols, kernel_cols, border);
+    let y_indices = kernel_indices(rows, kernel_rows, border);
+    let value = border.constant_value();
+    let src_data = src.as_slice();
+    let kernel_data = kernel.as_slice();
+
+    parallel::par_iter_rows_indexed(dst, |y, dst_row| {
+        let y_taps = &y_indices[y * kernel_rows..(y + 1) * kernel_rows];
+        for (x, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+            let x_taps = &x_indices[x * kernel_cols..(x + 1) * kernel_cols];
+            let mut acc = [0.0; C];
+            for (y_tap, kernel_row) in y_taps.iter().zip(kernel_data.chunks_exact(kernel_cols)) {
+                for (x_tap, k_val) in x_taps.iter().zip(kernel_row) {
+                    match (y_tap, x_tap) {
+                        (Some(r), Some(c)) => {
+                            let src_pixel = &src_data[(r * cols + c) * C..(r * cols + c + 1) * C];
+                            for (a, s) in acc.iter_mut().zip(src_pixel) {
+                                *a += s * k_val;
+                            }
+                        }
+                        _ => acc.iter_mut().for_each(|a| *a += value * k_val),
+                    }
+                }
+            }
+            dst_pixel.copy_from_slice(&acc);
+        }
+    });
+}
+
+/// Correlate an image with a kernel in the frequency domain.
+///
+/// The image is extended by the border, both the extended image and the kernel are zero padded to
+/// a power of two and the correlation is the inverse transform of `FFT(image) * conj(FFT(kernel))`.
+/// The padding is large enough for the circular correlation not to wrap on the cropped output.
+fn filter2d_fft<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    kernel: &Image<f32, 1>,
+    border: BorderType,
+) {
+    let (cols, rows) = (src.cols(), src.rows());
+    let (kernel_cols, kernel_rows) = (kernel.cols(), kernel.rows());
+    let (anchor_x, anchor_y) = ((kernel_cols / 2) as isize, (kernel_rows / 2) as isize);
+    let (padded_cols, padded_rows) = (
+        (cols + kernel_cols - 1).next_power_of_two(),
+        (rows + kernel_rows - 1).next_power_of_two(),
+    );
+    let value = border.constant_value() as f64;
+
+    let mut kernel_spectrum = vec![Complex::default(); padded_cols * padded_rows];
+    for (padded_row, kernel_row) in kernel_spectrum
+        .chunks_exact_mut(padded_cols)
+        .zip(kernel.as_slice().chunks_exact(kernel_cols))
+    {
+        for (p, &k) in padded_row.iter_mut().zip(kernel_row) {
+            *p = Complex::from_real(k as f64);
+        }
+    }
+    fft2d(&mut kernel_spectrum, padded_cols, padded_rows, false);
+
+    // the source positions of the extended image, None on the constant border
+    let x_map = (0..(cols + kernel_cols - 1) as isize)
+        .map(|i| border.map_index(i - anchor_x, cols))
+        .collect::<Vec<_>>();
+    let y_map = (0..(rows + kernel_rows - 1) as isize)
+        .map(|i| border.map_index(i - anchor_y, rows))
+        .collect::<Vec<_>>();
+
+    let src_data = src.as_slice();
+    let mut spectrum = vec![Complex::default(); padded_cols * padded_rows];
+    for ch in 0..C {
+        spectrum.fill(Complex::default());
+        for (padded_row, y_src) in spectrum.chunks_exact_mut(padded_cols).zip(&y_map) {
+            for (p, x_src) in padded_row.iter_mut().zip(&x_map) {
+                let v = match (y_src, x_src) {
+                    (Some(r), Some(c)) => src_data[(r * cols + c) * C + ch] as f64,
+                    _ => value,
+                };
+                *p = Complex::from_real(v);
+            }
+        }
+
+        fft2d(&mut spectrum, padded_cols, padded_rows, false);
+        spectrum
+            .iter_mut()
+            .zip(&kernel_spectrum)
+            .for_each(|(s, k)| *s = *s * k.conj());
+        fft2d(&mut spectrum, padded_cols, padded_rows, true);
+
+        for (dst_row, padded_row) in dst
+            .as_slice_mut()
+            .chunks_exact_mut(cols * C)
+            .zip(spectrum.chunks_exact(padded_cols))
+        {
+            for (dst_pixel, p) in dst_row.chunks_exact_mut(C).zip(padded_row) {
+                dst_pixel[ch] = p.re as f32;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn filter2d_borders() -> Result<(), ImageError> {
+        let img = Image::<_, 1>::new([4, 1].into(), vec![0.0, 1.0, 2.0, 3.0])?;
+        let mut dst = Image::<_, 1>::from_size_val(img.size(), 0f32)?;
+        // an asymmetric kernel to check that the kernel is not flipped
+        let kernel = Image::<_, 1>::new([3, 1].into(), vec![1.0, 0.0, -1.0])?;
+
+        for (border, expected) in [
+            (BorderType::Constant(10.0), [9.0, -2.0, -2.0, -8.0]),
+            (BorderType::Replicate, [-1.0, -2.0, -2.0, -1.0]),
+            (BorderType::Reflect, [-1.0, -2.0, -2.0, -1.0]),
+            (BorderType::Reflect101, [0.0, -2.0, -2.0, 0.0]),
+            (BorderType::Wrap, [2.0, -2.0, -2.0, 2.0]),
+        ] {
+            filter2d(&img, &mut dst, &kernel, border)?;
+            assert_eq!(dst.as_slice(), &expected, "{border:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter2d_laplacian() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 3,
+        };
+        // a quadratic surface x^2 + y^2 has a constant laplacian of 4
+        let img = Image::<_, 2>::new(
+            size,
+            (0..12)
+                .flat_map(|i| {
+                    let (x, y) = ((i % 4) as f32, (i / 4) as f32);
+                    [x * x + y * y, 1.0]
+                })
+                .collect(),
+        )?;
+        #[rustfmt::skip]
+        let kernel = Image::<_, 1>::new(
+            [3, 3].into(),
+            vec![
+                0.0, 1.0, 0.0,
+                1.0, -4.0, 1.0,
+                0.0, 1.0, 0.0,
+            ],
+        )?;
+        let mut dst = Image::<_, 2>::from_size_val(size, 0f32)?;
+        filter2d(&img, &mut dst, &kernel, BorderType::Replicate)?;
+
+        let pixel = |x: usize, y: usize| &dst.as_slice()[(y * 4 + x) * 2..(y * 4 + x + 1) * 2];
+        assert_eq!(pixel(1, 1), &[4.0, 0.0]);
+        assert_eq!(pixel(2, 1), &[4.0, 0.0]);
+        // the replicated border flattens the surface
+        assert_eq!(pixel(0, 0), &[2.0, 0.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter2d_fft_matches_direct() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 23,
+            height: 17,
+        };
+        let img = Image::<_, 3>::new(
+            size,
+            (0..23 * 17 * 3)
+                .map(|i| ((i * 7919) % 255) as f32 / 255.0)
+                .collect(),
+        )?;
+        let kernel = Image::<_, 1>::new(
+            [13, 12].into(),
+            (0..13 * 12).map(|i| ((i * 31) % 17) as f32 - 8.0).collect(),
+        )?;
+
+        for border in [
+            BorderType::Constant(0.5),
+            BorderType::Replicate,
+            BorderType::Reflect,
+            BorderType::Reflect101,
+            BorderType::Wrap,
+        ] {
+            let mut direct = Image::<_, 3>::from_size_val(size, 0f32)?;
+            let mut fft = Image::<_, 3>::from_size_val(size, 0f32)?;
+            filter2d_direct(&img, &mut direct, &kernel, border);
+            filter2d_fft(&img, &mut fft, &kernel, border);
+            for (d, f) in direct.as_slice().iter().zip(fft.as_slice()) {
+                assert!((d - f).abs() < 1e-3, "{border:?}: {d} != {f}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn filter2d_invalid_kernel() -> Result<(), ImageError> {
+        let img = Image::<_, 1>::from_size_val([4, 4].into(), 1f32)?;
+        let mut dst = Image::<_, 1>::from_size_val(img.size(), 0f32)?;
+        let kernel = Image::<_, 1>::new([0, 3].into(), vec![])?;
+        assert!(matches!(
+            filter2d(&img, &mut dst, &kernel, BorderType::Replicate),
+            Err(ImageError::InvalidKernelLength(0, 3))
+        ));
+
+        let mut dst = Image::<_, 1>::from_size_val([3, 4].into(), 0f32)?;
+        let kernel = Image::<_, 1>::from_size_val([3, 3].into(), 1f32)?;
+        assert!(filter2d(&img, &mut dst, &kernel, BorderType::Replicate).is_err());
+
+        Ok(())
+    }
+}
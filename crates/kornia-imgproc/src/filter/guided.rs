@@ -0,0 +1,209 @@
+use kornia_image::{Image, ImageError};
+
+use super::box_blur;
+use crate::interpolation::{BorderType, InterpolationPixel};
+
+/// Convert an image to `f32`.
+fn to_f32<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+) -> Result<Image<f32, C>, ImageError> {
+    Image::new(
+        src.size(),
+        src.as_slice().iter().map(|&v| v.to_f32()).collect(),
+    )
+}
+
+/// Blur an image with a guided filter.
+///
+/// The output is locally a linear transform of the guide image, fitted in each window to the
+/// source image, so that the edges of the guide are transferred to the output (He et al.). With
+/// the source as the guide, the filter is an edge preserving smoothing.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `guide` - The guide image with shape (H, W, 1), e.g. the grayscale source image.
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `radius` - The radius of the windows, which have a size of `2 * radius + 1`.
+/// * `eps` - The regularization of the linear coefficients, in the squared units of the pixel
+///   type. The variations of the guide smaller than `sqrt(eps)` are smoothed.
+///
+/// PRECONDITION: `src`, `guide` and `dst` must have the same size.
+///
+/// # Errors
+///
+/// Returns an error if the images have different sizes.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::filter::guided_filter;
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 4,
+///         height: 1,
+///     },
+///     vec![0.0, 0.0, 1.0, 1.0],
+/// )
+/// .unwrap();
+///
+/// let mut dst = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// guided_filter(&image, &image, &mut dst, 1, 1e-6).unwrap();
+/// assert!(dst.as_slice().iter().zip(image.as_slice()).all(|(d, s)| (d - s).abs() < 1e-3));
+/// ```
+pub fn guided_filter<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+    guide: &Image<T, 1>,
+    dst: &mut Image<T, C>,
+    radius: usize,
+    eps: f32,
+) -> Result<(), ImageError> {
+    for size in [guide.size(), dst.size()] {
+        if src.size() != size {
+            return Err(ImageError::InvalidImageSize(
+                src.cols(),
+                src.rows(),
+                size.width,
+                size.height,
+            ));
+        }
+    }
+
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+
+    let kernel_size = (2 * radius + 1, 2 * radius + 1);
+    let border = BorderType::Reflect101;
+    let guide = to_f32(guide)?;
+    let src = to_f32(src)?;
+
+    // the means of the guide, the source and their products over the windows
+    let mut guide_sq = guide.clone();
+    guide_sq.as_slice_mut().iter_mut().for_each(|g| *g *= *g);
+    let mut guide_src = src.clone();
+    for (pixel, &g) in guide_src
+        .as_slice_mut()
+        .chunks_exact_mut(C)
+        .zip(guide.as_slice())
+    {
+        pixel.iter_mut().for_each(|p| *p *= g);
+    }
+
+    let mut mean_guide = Image::<f32, 1>::from_size_val(src.size(), 0.0)?;
+    let mut mean_guide_sq = Image::<f32, 1>::from_size_val(src.size(), 0.0)?;
+    let mut mean_src = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+    let mut mean_guide_src = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+    box_blur(&guide, &mut mean_guide, kernel_size, border)?;
+    box_blur(&guide_sq, &mut mean_guide_sq, kernel_size, border)?;
+    box_blur(&src, &mut mean_src, kernel_size, border)?;
+    box_blur(&guide_src, &mut mean_guide_src, kernel_size, border)?;
+
+    // the coefficients of the linear transform q = a * guide + b in each window
+    let mut a = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+    let mut b = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
+    for ((((a_pixel, b_pixel), mean_s), mean_gs), (&mean_g, &mean_g_sq)) in a
+        .as_slice_mut()
+        .chunks_exact_mut(C)
+        .zip(b.as_slice_mut().chunks_exact_mut(C))
+        .zip(mean_src.as_slice().chunks_exact(C))
+        .zip(mean_guide_src.as_slice().chunks_exact(C))
+        .zip(mean_guide.as_slice().iter().zip(mean_guide_sq.as_slice()))
+    {
+        let var_g = mean_g_sq - mean_g * mean_g;
+        for (((a, b), &mean_s), &mean_gs) in
+            a_pixel.iter_mut().zip(b_pixel).zip(mean_s).zip(mean_gs)
+        {
+            *a = (mean_gs - mean_g * mean_s) / (var_g + eps);
+            *b = mean_s - *a * mean_g;
+        }
+    }
+
+    // average the coefficients of the windows covering each pixel, reusing the buffers of the means
+    let (mut mean_a, mut mean_b) = (mean_src, mean_guide_src);
+    box_blur(&a, &mut mean_a, kernel_size, border)?;
+    box_blur(&b, &mut mean_b, kernel_size, border)?;
+
+    for (((dst_pixel, a_pixel), b_pixel), &g) in dst
+        .as_slice_mut()
+        .chunks_exact_mut(C)
+        .zip(mean_a.as_slice().chunks_exact(C))
+        .zip(mean_b.as_slice().chunks_exact(C))
+        .zip(guide.as_slice())
+    {
+        for ((d, a), b) in dst_pixel.iter_mut().zip(a_pixel).zip(b_pixel) {
+            *d = T::from_f32(a * g + b);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn guided_filter_self() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 4,
+        };
+        // a noisy step between two flat regions
+        let img = Image::<u8, 1>::new(
+            size,
+            (0..32)
+                .map(|i| {
+                    let base = if i % 8 < 4 { 50 } else { 200 };
+                    if i % 2 == 0 {
+                        base + 4
+                    } else {
+                        base - 4
+                    }
+                })
+                .collect(),
+        )?;
+        let mut dst = Image::<u8, 1>::from_size_val(size, 0)?;
+
+        // the noise is below the regularization and the step above it
+        guided_filter(&img, &img, &mut dst, 1, 20.0 * 20.0)?;
+        for (i, &d) in dst.as_slice().iter().enumerate() {
+            let expected = if i % 8 < 4 { 50.0 } else { 200.0 };
+            assert!((d as f32 - expected).abs() <= 3.0, "pixel {i}: {d}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn guided_filter_transfers_guide() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 2,
+        };
+        // the source is a scaled guide, which is locally fitted exactly
+        let guide = Image::<f32, 1>::new(size, (0..12).map(|i| (i % 6) as f32).collect())?;
+        let src = Image::<f32, 2>::new(
+            size,
+            guide
+                .as_slice()
+                .iter()
+                .flat_map(|&g| [2.0 * g + 1.0, -g])
+                .collect(),
+        )?;
+        let mut dst = Image::<f32, 2>::from_size_val(size, 0.0)?;
+        guided_filter(&src, &guide, &mut dst, 2, 1e-6)?;
+        for (d, s) in dst.as_slice().iter().zip(src.as_slice()) {
+            assert!((d - s).abs() < 1e-3, "{d} != {s}");
+        }
+
+        let mut dst = Image::<f32, 2>::from_size_val([5, 2].into(), 0.0)?;
+        assert!(guided_filter(&src, &guide, &mut dst, 1, 1.0).is_err());
+
+        Ok(())
+    }
+}
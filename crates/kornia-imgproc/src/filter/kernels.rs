@@ -61,6 +61,40 @@ pub fn sobel_kernel_1d(kernel_size: usize) -> (Vec<f32>, Vec<f32>) {
     (kernel_x, kernel_y)
 }
 
+/// Create the separable kernels of a sobel derivative.
+///
+/// The smoothing kernel holds the binomial coefficients and the derivative kernel the binomial
+/// coefficients convolved with [-1, 0, 1], like `cv2.getDerivKernels`.
+///
+/// # Arguments
+///
+/// * `kernel_size` - The size of the kernels, an odd number greater than 1.
+///
+/// # Returns
+///
+/// The derivative and the smoothing kernels.
+pub fn sobel_derivative_kernels_1d(kernel_size: usize) -> (Vec<f32>, Vec<f32>) {
+    let binomial = |len: usize| {
+        (1..len).fold(vec![1.0f32], |row, _| {
+            let mut next = vec![1.0; row.len() + 1];
+            for i in 1..row.len() {
+                next[i] = row[i - 1] + row[i];
+            }
+            next
+        })
+    };
+
+    let smoothing = binomial(kernel_size);
+    let base = binomial(kernel_size.saturating_sub(2).max(1));
+    let mut derivative = vec![0.0; base.len() + 2];
+    for (i, b) in base.iter().enumerate() {
+        derivative[i] -= b;
+        derivative[i + 2] += b;
+    }
+
+    (derivative, smoothing)
+}
+
 /// Create list of optimized box blur kernels based on gaussian sigma
 ///
 /// <https://www.peterkovesi.com/papers/FastGaussianSmoothing.pdf>
@@ -107,6 +141,17 @@ mod tests {
         assert_eq!(kernel.1, vec![-1.0, -2.0, 0.0, 2.0, 1.0]);
     }
 
+    #[test]
+    fn test_sobel_derivative_kernels_1d() {
+        let (derivative, smoothing) = sobel_derivative_kernels_1d(3);
+        assert_eq!(derivative, vec![-1.0, 0.0, 1.0]);
+        assert_eq!(smoothing, vec![1.0, 2.0, 1.0]);
+
+        let (derivative, smoothing) = sobel_derivative_kernels_1d(7);
+        assert_eq!(derivative, vec![-1.0, -4.0, -5.0, 0.0, 5.0, 4.0, 1.0]);
+        assert_eq!(smoothing, vec![1.0, 6.0, 15.0, 20.0, 15.0, 6.0, 1.0]);
+    }
+
     #[test]
     fn test_gaussian_kernel_1d() {
         let kernel = gaussian_kernel_1d(5, 0.5);
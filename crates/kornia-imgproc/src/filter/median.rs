@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+
+use kornia_image::{Image, ImageError};
+
+use super::kernel_indices;
+use crate::{
+    interpolation::{BorderType, InterpolationPixel},
+    parallel,
+};
+
+/// A pixel type supported by [`median_blur`].
+///
+/// The default implementation selects the median of each window, `u8` images use a histogram
+/// sliding along the rows, updated with O(ky) pixels for each output pixel instead of the
+/// O(kx * ky) pixels of the window.
+pub trait MedianPixel: InterpolationPixel + PartialOrd {
+    /// Apply the median filter to an image, see [`median_blur`].
+    ///
+    /// PRECONDITION: the images have the same non-empty size and the kernel sizes are odd.
+    fn median_filter<const C: usize>(
+        src: &Image<Self, C>,
+        dst: &mut Image<Self, C>,
+        kernel_size: (usize, usize),
+        border: BorderType,
+    ) {
+        median_filter_select(src, dst, kernel_size, border);
+    }
+}
+
+impl MedianPixel for f32 {}
+
+impl MedianPixel for u16 {}
+
+impl MedianPixel for u8 {
+    fn median_filter<const C: usize>(
+        src: &Image<Self, C>,
+        dst: &mut Image<Self, C>,
+        kernel_size: (usize, usize),
+        border: BorderType,
+    ) {
+        median_filter_histogram(src, dst, kernel_size, border);
+    }
+}
+
+/// Blur an image with a median filter.
+///
+/// Each channel of each pixel is replaced by the median of the channel over the kernel window,
+/// which removes the salt and pepper noise while preserving the edges.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel_size` - The size of the kernel (kernel_x, kernel_y), odd numbers.
+/// * `border` - The extrapolation of the pixels outside of the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Errors
+///
+/// Returns an error if the kernel sizes are even or if the images have different sizes.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::filter::{median_blur, BorderType};
+///
+/// let image = Image::<u8, 1>::new(
+///     ImageSize {
+///         width: 3,
+///         height: 3,
+///     },
+///     vec![10, 10, 10, 10, 255, 10, 10, 10, 10],
+/// )
+/// .unwrap();
+///
+/// let mut dst = Image::<u8, 1>::from_size_val(image.size(), 0).unwrap();
+///
+/// median_blur(&image, &mut dst, (3, 3), BorderType::Replicate).unwrap();
+/// assert_eq!(dst.as_slice(), &[10; 9]);
+/// ```
+pub fn median_blur<T: MedianPixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    kernel_size: (usize, usize),
+    border: BorderType,
+) -> Result<(), ImageError> {
+    if kernel_size.0 % 2 == 0 || kernel_size.1 % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(
+            kernel_size.0,
+            kernel_size.1,
+        ));
+    }
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if src.cols() == 0 || src.rows() == 0 {
+        return Ok(());
+    }
+
+    T::median_filter(src, dst, kernel_size, border);
+
+    Ok(())
+}
+
+/// Apply the median filter by selecting the middle element of each window.
+fn median_filter_select<T: InterpolationPixel + PartialOrd, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    (kernel_x, kernel_y): (usize, usize),
+    border: BorderType,
+) {
+    let cols = src.cols();
+    let x_indices = kernel_indices(cols, kernel_x, border);
+    let y_indices = kernel_indices(src.rows(), kernel_y, border);
+    let value = T::from_f32(border.constant_value());
+    let src_data = src.as_slice();
+    let mid = kernel_x * kernel_y / 2;
+
+    parallel::par_iter_rows_indexed(dst, |y, dst_row| {
+        let y_taps = &y_indices[y * kernel_y..(y + 1) * kernel_y];
+        let mut window = Vec::with_capacity(kernel_x * kernel_y);
+        for (x, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+            let x_taps = &x_indices[x * kernel_x..(x + 1) * kernel_x];
+            for (ch, d) in dst_pixel.iter_mut().enumerate() {
+                window.clear();
+                for y_tap in y_taps {
+                    window.extend(x_taps.iter().map(|x_tap| match (y_tap, x_tap) {
+                        (Some(r), Some(c)) => src_data[(r * cols + c) * C + ch],
+                        _ => value,
+                    }));
+                }
+                let (_, median, _) = window.select_nth_unstable_by(mid, |a, b| {
+                    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+                });
+                *d = *median;
+            }
+        }
+    });
+}
+
+/// Apply the median filter to a `u8` image with a histogram sliding along the rows.
+///
+/// The histogram of the window is updated with the column entering and the column leaving the
+/// window, and the median is tracked with the number of values below it (Huang et al.).
+fn median_filter_histogram<const C: usize>(
+    src: &Image<u8, C>,
+    dst: &mut Image<u8, C>,
+    (kernel_x, kernel_y): (usize, usize),
+    border: BorderType,
+) {
+    let cols = src.cols();
+    let x_indices = kernel_indices(cols, kernel_x, border);
+    let y_indices = kernel_indices(src.rows(), kernel_y, border);
+    let value = u8::from_f32(border.constant_value());
+    let src_data = src.as_slice();
+    let half = (kernel_x * kernel_y / 2) as u32;
+
+    parallel::par_iter_rows_indexed(dst, |y, dst_row| {
+        let y_taps = &y_indices[y * kernel_y..(y + 1) * kernel_y];
+        let sample = |y_tap: &Option<usize>, x_tap: &Option<usize>, ch: usize| match (y_tap, x_tap)
+        {
+            (Some(r), Some(c)) => src_data[(r * cols + c) * C + ch] as usize,
+            _ => value as usize,
+        };
+
+        for ch in 0..C {
+            let mut hist = [0u32; 256];
+            let (mut median, mut below) = (0usize, 0u32);
+            for (x, dst_pixel) in dst_row.chunks_exact_mut(C).enumerate() {
+                let x_taps = &x_indices[x * kernel_x..(x + 1) * kernel_x];
+                if x == 0 {
+                    for y_tap in y_taps {
+                        for x_tap in x_taps {
+                            hist[sample(y_tap, x_tap, ch)] += 1;
+                        }
+                    }
+                } else {
+                    let leaving = &x_indices[(x - 1) * kernel_x];
+                    let entering = &x_taps[kernel_x - 1];
+                    for y_tap in y_taps {
+                        let v = sample(y_tap, leaving, ch);
+                        hist[v] -= 1;
+                        if v < median {
+                            below -= 1;
+                        }
+                        let v = sample(y_tap, entering, ch);
+                        hist[v] += 1;
+                        if v < median {
+                            below += 1;
+                        }
+                    }
+                }
+
+                // move the median until half of the window is below it
+                while below > half {
+                    median -= 1;
+                    below -= hist[median];
+                }
+                while below + hist[median] <= half {
+                    below += hist[median];
+                    median += 1;
+                }
+                dst_pixel[ch] = median as u8;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+
+    #[test]
+    fn median_blur_salt_and_pepper() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 4,
+        };
+        #[rustfmt::skip]
+        let data = vec![
+            10, 10, 10, 200, 200, 200,
+            10, 255, 10, 200, 200, 200,
+            10, 10, 10, 200, 0, 200,
+            10, 10, 10, 200, 200, 200,
+        ];
+        let img = Image::<u8, 1>::new(size, data.clone())?;
+        let mut dst = Image::<u8, 1>::from_size_val(size, 0)?;
+        median_blur(&img, &mut dst, (3, 3), BorderType::Replicate)?;
+
+        // the outliers are removed and the step is preserved
+        #[rustfmt::skip]
+        assert_eq!(
+            dst.as_slice(),
+            &[
+                10, 10, 10, 200, 200, 200,
+                10, 10, 10, 200, 200, 200,
+                10, 10, 10, 200, 200, 200,
+                10, 10, 10, 200, 200, 200,
+            ]
+        );
+
+        // the f32 path selects the same values
+        let img = Image::<f32, 1>::new(size, data.iter().map(|&v| v as f32).collect())?;
+        let mut dst_f32 = Image::<f32, 1>::from_size_val(size, 0.0)?;
+        median_blur(&img, &mut dst_f32, (3, 3), BorderType::Replicate)?;
+        assert!(dst_f32
+            .as_slice()
+            .iter()
+            .zip(dst.as_slice())
+            .all(|(&a, &b)| a == b as f32));
+
+        Ok(())
+    }
+
+    #[test]
+    fn median_blur_histogram_matches_select() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 13,
+            height: 9,
+        };
+        let img = Image::<u8, 3>::new(
+            size,
+            (0..13 * 9 * 3).map(|i| ((i * 7919) % 251) as u8).collect(),
+        )?;
+
+        for (kernel_size, border) in [
+            ((3, 3), BorderType::Constant(7.0)),
+            ((5, 3), BorderType::Replicate),
+            ((7, 7), BorderType::Reflect),
+            ((1, 5), BorderType::Reflect101),
+            ((5, 5), BorderType::Wrap),
+        ] {
+            let mut histogram = Image::<u8, 3>::from_size_val(size, 0)?;
+            let mut select = Image::<u8, 3>::from_size_val(size, 0)?;
+            median_blur(&img, &mut histogram, kernel_size, border)?;
+            median_filter_select(&img, &mut select, kernel_size, border);
+            assert_eq!(histogram.as_slice(), select.as_slice(), "{border:?}");
+        }
+
+        let mut dst = Image::<u8, 3>::from_size_val(size, 0)?;
+        assert!(median_blur(&img, &mut dst, (4, 3), BorderType::Replicate).is_err());
+
+        Ok(())
+    }
+}
@@ -2,9 +2,34 @@
 //!
 //! This module provides filter operations for image processing.
 
+pub use crate::interpolation::BorderType;
+
+/// Bilateral filter
+mod bilateral;
+pub use bilateral::*;
+
+/// Canny edge detector
+mod canny;
+pub use canny::*;
+
+/// Fast Fourier transform
+mod fft;
+
+/// Non-separable 2D filter
+mod filter2d;
+pub use filter2d::*;
+
+/// Guided filter
+mod guided;
+pub use guided::*;
+
 /// Filter kernels
 pub mod kernels;
 
+/// Median filter
+mod median;
+pub use median::*;
+
 /// Filter operations
 mod ops;
 pub use ops::*;
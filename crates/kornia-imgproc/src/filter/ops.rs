@@ -1,6 +1,7 @@
 use kornia_image::{Image, ImageError, ImageSize};
 
 use super::{fast_horizontal_filter, kernels, separable_filter};
+use crate::interpolation::BorderType;
 
 /// Blur an image using a box blur filter
 ///
@@ -9,16 +10,18 @@ use super::{fast_horizontal_filter, kernels, separable_filter};
 /// * `src` - The source image with shape (H, W, C).
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
+/// * `border` - The extrapolation of the pixels outside of the image.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 pub fn box_blur<const C: usize>(
     src: &Image<f32, C>,
     dst: &mut Image<f32, C>,
     kernel_size: (usize, usize),
+    border: BorderType,
 ) -> Result<(), ImageError> {
     let kernel_x = kernels::box_blur_kernel_1d(kernel_size.0);
     let kernel_y = kernels::box_blur_kernel_1d(kernel_size.1);
-    separable_filter(src, dst, &kernel_x, &kernel_y)?;
+    separable_filter(src, dst, &kernel_x, &kernel_y, border)?;
     Ok(())
 }
 
@@ -30,6 +33,7 @@ pub fn box_blur<const C: usize>(
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
 /// * `sigma` - The sigma of the gaussian kernel.
+/// * `border` - The extrapolation of the pixels outside of the image.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 pub fn gaussian_blur<const C: usize>(
@@ -37,10 +41,11 @@ pub fn gaussian_blur<const C: usize>(
     dst: &mut Image<f32, C>,
     kernel_size: (usize, usize),
     sigma: (f32, f32),
+    border: BorderType,
 ) -> Result<(), ImageError> {
     let kernel_x = kernels::gaussian_kernel_1d(kernel_size.0, sigma.0);
     let kernel_y = kernels::gaussian_kernel_1d(kernel_size.1, sigma.1);
-    separable_filter(src, dst, &kernel_x, &kernel_y)?;
+    separable_filter(src, dst, &kernel_x, &kernel_y, border)?;
     Ok(())
 }
 
@@ -51,22 +56,18 @@ pub fn gaussian_blur<const C: usize>(
 /// * `src` - The source image with shape (H, W, C).
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
+/// * `border` - The extrapolation of the pixels outside of the image.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 pub fn sobel<const C: usize>(
     src: &Image<f32, C>,
     dst: &mut Image<f32, C>,
     kernel_size: usize,
+    border: BorderType,
 ) -> Result<(), ImageError> {
-    // get the sobel kernels
-    let (kernel_x, kernel_y) = kernels::sobel_kernel_1d(kernel_size);
-
-    // apply the sobel filter using separable filter
     let mut gx = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
-    separable_filter(src, &mut gx, &kernel_x, &kernel_y)?;
-
     let mut gy = Image::<f32, C>::from_size_val(src.size(), 0.0)?;
-    separable_filter(src, &mut gy, &kernel_y, &kernel_x)?;
+    sobel_gradients(src, &mut gx, &mut gy, kernel_size, border)?;
 
     // compute the magnitude in parallel by rows
     dst.as_slice_mut()
@@ -80,6 +81,58 @@ pub fn sobel<const C: usize>(
     Ok(())
 }
 
+/// Compute the horizontal and vertical derivatives of an image with the sobel operator.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dx` - The destination image of the derivative along x with shape (H, W, C).
+/// * `dy` - The destination image of the derivative along y with shape (H, W, C).
+/// * `kernel_size` - The size of the kernel, an odd number greater than 1.
+/// * `border` - The extrapolation of the pixels outside of the image.
+///
+/// PRECONDITION: `src`, `dx` and `dy` must have the same shape.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::filter::{sobel_gradients, BorderType};
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 3,
+///         height: 3,
+///     },
+///     vec![0.0, 1.0, 2.0, 0.0, 1.0, 2.0, 0.0, 1.0, 2.0],
+/// )
+/// .unwrap();
+///
+/// let mut dx = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+/// let mut dy = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+///
+/// sobel_gradients(&image, &mut dx, &mut dy, 3, BorderType::Reflect101).unwrap();
+/// assert_eq!(dx.as_slice()[4], 8.0);
+/// assert_eq!(dy.as_slice()[4], 0.0);
+/// ```
+pub fn sobel_gradients<const C: usize>(
+    src: &Image<f32, C>,
+    dx: &mut Image<f32, C>,
+    dy: &mut Image<f32, C>,
+    kernel_size: usize,
+    border: BorderType,
+) -> Result<(), ImageError> {
+    if kernel_size < 3 || kernel_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(kernel_size, kernel_size));
+    }
+
+    let (derivative, smoothing) = kernels::sobel_derivative_kernels_1d(kernel_size);
+    separable_filter(src, dx, &derivative, &smoothing, border)?;
+    separable_filter(src, dy, &smoothing, &derivative, border)?;
+
+    Ok(())
+}
+
 /// Blur an image using a box blur filter multiple times to achieve a near gaussian blur
 ///
 /// # Arguments
@@ -89,6 +142,8 @@ pub fn sobel<const C: usize>(
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
 /// * `sigma` - The sigma of the gaussian kernel, xy-ordered.
 ///
+/// The pixels outside of the image replicate the border pixels.
+///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 pub fn box_blur_fast<const C: usize>(
     src: &Image<f32, C>,
@@ -153,6 +208,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sobel_gradients() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 5,
+        };
+
+        // a ramp along y
+        let img = Image::new(size, (0..25).map(|x| (x / 5) as f32).collect())?;
+        let mut dx = Image::<_, 1>::from_size_val(size, 0.0)?;
+        let mut dy = Image::<_, 1>::from_size_val(size, 0.0)?;
+
+        for kernel_size in [3, 5] {
+            sobel_gradients(&img, &mut dx, &mut dy, kernel_size, BorderType::Replicate)?;
+            assert!(dx.as_slice().iter().all(|&v| v == 0.0));
+            assert!(dy.as_slice()[12] > 0.0);
+        }
+
+        let border = BorderType::Replicate;
+        assert!(sobel_gradients(&img, &mut dx, &mut dy, 4, border).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_gaussian_blur() -> Result<(), ImageError> {
         let size = ImageSize {
@@ -168,7 +247,13 @@ mod tests {
 
         let mut dst = Image::<_, 1>::from_size_val(size, 0.0)?;
 
-        gaussian_blur(&img, &mut dst, (3, 3), (0.5, 0.5))?;
+        gaussian_blur(
+            &img,
+            &mut dst,
+            (3, 3),
+            (0.5, 0.5),
+            BorderType::Constant(0.0),
+        )?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -1,4 +1,32 @@
 use kornia_image::{Image, ImageError};
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+
+use crate::interpolation::BorderType;
+
+/// Compute the source positions read by a kernel centered on each position along an axis.
+///
+/// # Arguments
+///
+/// * `len` - The length of the image along the axis.
+/// * `kernel_len` - The length of the kernel, anchored at `kernel_len / 2`.
+/// * `border` - The extrapolation of the pixels outside of the image.
+///
+/// # Returns
+///
+/// The `kernel_len` positions of each output position, None for the constant border.
+pub(crate) fn kernel_indices(
+    len: usize,
+    kernel_len: usize,
+    border: BorderType,
+) -> Vec<Option<usize>> {
+    let anchor = (kernel_len / 2) as isize;
+    (0..len as isize)
+        .flat_map(|i| (0..kernel_len as isize).map(move |k| border.map_index(i + k - anchor, len)))
+        .collect()
+}
 
 /// Apply a separable filter to an image.
 ///
@@ -8,11 +36,13 @@ use kornia_image::{Image, ImageError};
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_x` - The horizontal kernel.
 /// * `kernel_y` - The vertical kernel.
+/// * `border` - The extrapolation of the pixels outside of the image.
 pub fn separable_filter<const C: usize>(
     src: &Image<f32, C>,
     dst: &mut Image<f32, C>,
     kernel_x: &[f32],
     kernel_y: &[f32],
+    border: BorderType,
 ) -> Result<(), ImageError> {
     if kernel_x.is_empty() || kernel_y.is_empty() {
         return Err(ImageError::InvalidKernelLength(
@@ -30,60 +60,59 @@ pub fn separable_filter<const C: usize>(
         ));
     }
 
-    let half_kernel_x = kernel_x.len() / 2;
-    let half_kernel_y = kernel_y.len() / 2;
+    let (cols, rows) = (src.cols(), src.rows());
+    if cols == 0 || rows == 0 {
+        return Ok(());
+    }
 
-    let src_data = src.as_slice();
-    let dst_data = dst.as_slice_mut();
+    let x_indices = kernel_indices(cols, kernel_x.len(), border);
+    let y_indices = kernel_indices(rows, kernel_y.len(), border);
+    let value = border.constant_value();
 
     // preallocate the temporary buffer for intermediate results
     // TODO: use a better buffer allocation strategy
-    let mut temp = vec![0.0; src_data.len()];
+    let mut temp = vec![0.0; src.as_slice().len()];
 
     // Row-wise filtering
-    for r in 0..src.rows() {
-        let row_offset = r * src.cols();
-        for c in 0..src.cols() {
-            let col_offset = (row_offset + c) * C;
-            for ch in 0..C {
-                let pix_offset = col_offset + ch;
-                let mut row_acc = 0.0;
-                for (k_idx, k_val) in kernel_x.iter().enumerate() {
-                    let x_pos = c as isize + k_idx as isize - half_kernel_x as isize;
-                    if x_pos >= 0 && x_pos < src.cols() as isize {
-                        let neighbor_idx = (row_offset + x_pos as usize) * C + ch;
-                        row_acc += unsafe { src_data.get_unchecked(neighbor_idx) } * k_val;
+    temp.par_chunks_exact_mut(cols * C)
+        .zip(src.as_slice().par_chunks_exact(cols * C))
+        .for_each(|(temp_row, src_row)| {
+            let taps = x_indices.chunks_exact(kernel_x.len());
+            for (temp_pixel, taps) in temp_row.chunks_exact_mut(C).zip(taps) {
+                for (index, k_val) in taps.iter().zip(kernel_x) {
+                    match index {
+                        Some(i) => {
+                            let src_pixel = &src_row[i * C..(i + 1) * C];
+                            for (acc, s) in temp_pixel.iter_mut().zip(src_pixel) {
+                                *acc += s * k_val;
+                            }
+                        }
+                        None => temp_pixel.iter_mut().for_each(|acc| *acc += value * k_val),
                     }
                 }
-
-                unsafe {
-                    *temp.get_unchecked_mut(pix_offset) = row_acc;
-                }
             }
-        }
-    }
-
-    // Column-wise filtering
-    for r in 0..src.rows() {
-        let row_offset = r * src.cols();
-        for c in 0..src.cols() {
-            let col_offset = (row_offset + c) * C;
-            for ch in 0..C {
-                let pix_offset = col_offset + ch;
-                let mut col_acc = 0.0;
-                for (k_idx, k_val) in kernel_y.iter().enumerate() {
-                    let y_pos = r as isize + k_idx as isize - half_kernel_y as isize;
-                    if y_pos >= 0 && y_pos < src.rows() as isize {
-                        let neighbor_idx = (y_pos as usize * src.cols() + c) * C + ch;
-                        col_acc += unsafe { temp.get_unchecked(neighbor_idx) } * k_val;
+        });
+
+    // Column-wise filtering, the rows of the constant border are filtered rows of constants
+    let row_value = value * kernel_x.iter().sum::<f32>();
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(cols * C)
+        .enumerate()
+        .for_each(|(row, dst_row)| {
+            let taps = &y_indices[row * kernel_y.len()..(row + 1) * kernel_y.len()];
+            dst_row.fill(0.0);
+            for (index, k_val) in taps.iter().zip(kernel_y) {
+                match index {
+                    Some(r) => {
+                        let temp_row = &temp[r * cols * C..(r + 1) * cols * C];
+                        for (acc, t) in dst_row.iter_mut().zip(temp_row) {
+                            *acc += t * k_val;
+                        }
                     }
-                }
-                unsafe {
-                    *dst_data.get_unchecked_mut(pix_offset) = col_acc;
+                    None => dst_row.iter_mut().for_each(|acc| *acc += row_value * k_val),
                 }
             }
-        }
-    }
+        });
 
     Ok(())
 }
@@ -179,7 +208,13 @@ mod tests {
         let mut dst = Image::<_, 1>::from_size_val(img.size(), 0f32)?;
         let kernel_x = vec![1.0, 1.0, 1.0];
         let kernel_y = vec![1.0, 1.0, 1.0];
-        separable_filter(&img, &mut dst, &kernel_x, &kernel_y)?;
+        separable_filter(
+            &img,
+            &mut dst,
+            &kernel_x,
+            &kernel_y,
+            BorderType::Constant(0.0),
+        )?;
 
         #[rustfmt::skip]
         assert_eq!(
@@ -199,6 +234,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_separable_filter_borders() -> Result<(), ImageError> {
+        let img = Image::<_, 1>::new([4, 1].into(), vec![0.0, 1.0, 2.0, 3.0])?;
+        let mut dst = Image::<_, 1>::from_size_val(img.size(), 0f32)?;
+
+        for (border, expected) in [
+            (BorderType::Constant(10.0), [11.0, 3.0, 6.0, 15.0]),
+            (BorderType::Replicate, [1.0, 3.0, 6.0, 8.0]),
+            (BorderType::Reflect, [1.0, 3.0, 6.0, 8.0]),
+            (BorderType::Reflect101, [2.0, 3.0, 6.0, 7.0]),
+            (BorderType::Wrap, [4.0, 3.0, 6.0, 5.0]),
+        ] {
+            separable_filter(&img, &mut dst, &[1.0, 1.0, 1.0], &[1.0], border)?;
+            assert_eq!(dst.as_slice(), &expected, "{border:?}");
+        }
+
+        // the corners of a constant border are constant too
+        let img = Image::<_, 1>::new([1, 1].into(), vec![1.0])?;
+        let mut dst = Image::<_, 1>::from_size_val(img.size(), 0f32)?;
+        let kernel = [1.0, 1.0, 1.0];
+        separable_filter(&img, &mut dst, &kernel, &kernel, BorderType::Constant(2.0))?;
+        assert_eq!(dst.as_slice(), &[17.0]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fast_horizontal_filter() -> Result<(), ImageError> {
         let size = ImageSize {
@@ -1,5 +1,7 @@
 use kornia_image::Image;
 
+use super::interpolate::InterpolationPixel;
+
 /// Kernel for bilinear interpolation
 ///
 /// # Arguments
@@ -12,33 +14,36 @@ use kornia_image::Image;
 /// # Returns
 ///
 /// The interpolated pixel value.
-// TODO: add support for other data types. Maybe use a trait? or template?
-pub(crate) fn bilinear_interpolation<const C: usize>(
-    image: &Image<f32, C>,
+pub(crate) fn bilinear_interpolation<T: InterpolationPixel, const C: usize>(
+    image: &Image<T, C>,
     u: f32,
     v: f32,
     c: usize,
 ) -> f32 {
     let (rows, cols) = (image.rows(), image.cols());
 
+    // the points outside of the image replicate the border pixels
+    let u = u.clamp(0.0, cols.saturating_sub(1) as f32);
+    let v = v.clamp(0.0, rows.saturating_sub(1) as f32);
+
     let iu = u.trunc() as usize;
     let iv = v.trunc() as usize;
 
     let frac_u = u.fract();
     let frac_v = v.fract();
-    let val00 = *image.get_unchecked([iv, iu, c]);
+    let val00 = image.get_unchecked([iv, iu, c]).to_f32();
     let val01 = if iu + 1 < cols {
-        *image.get_unchecked([iv, iu + 1, c])
+        image.get_unchecked([iv, iu + 1, c]).to_f32()
     } else {
         val00
     };
     let val10 = if iv + 1 < rows {
-        *image.get_unchecked([iv + 1, iu, c])
+        image.get_unchecked([iv + 1, iu, c]).to_f32()
     } else {
         val00
     };
     let val11 = if iu + 1 < cols && iv + 1 < rows {
-        *image.get_unchecked([iv + 1, iu + 1, c])
+        image.get_unchecked([iv + 1, iu + 1, c]).to_f32()
     } else {
         val00
     };
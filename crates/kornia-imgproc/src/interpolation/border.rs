@@ -0,0 +1,122 @@
+/// The extrapolation of the pixels outside of an image.
+///
+/// The examples show how the row `abcdefgh` is padded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderType {
+    /// The pixels are set to a constant value: `iiiiii|abcdefgh|iiiiiii`.
+    Constant(f32),
+    /// The border pixels are repeated: `aaaaaa|abcdefgh|hhhhhhh`.
+    Replicate,
+    /// The image is mirrored including the border pixels: `fedcba|abcdefgh|hgfedcb`.
+    Reflect,
+    /// The image is mirrored excluding the border pixels: `gfedcb|abcdefgh|gfedcba`.
+    Reflect101,
+    /// The image is repeated: `cdefgh|abcdefgh|abcdefg`.
+    Wrap,
+}
+
+impl Default for BorderType {
+    fn default() -> Self {
+        BorderType::Constant(0.0)
+    }
+}
+
+impl BorderType {
+    /// Map a coordinate along an axis to a position in the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The coordinate, possibly outside of the image.
+    /// * `len` - The length of the image along the axis.
+    ///
+    /// # Returns
+    ///
+    /// The position in the image, or None if the coordinate falls on a constant border.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_imgproc::interpolation::BorderType;
+    ///
+    /// assert_eq!(BorderType::Reflect.map_index(-1, 8), Some(0));
+    /// assert_eq!(BorderType::Reflect101.map_index(-1, 8), Some(1));
+    /// assert_eq!(BorderType::Wrap.map_index(8, 8), Some(0));
+    /// assert_eq!(BorderType::Constant(0.0).map_index(8, 8), None);
+    /// ```
+    #[inline]
+    pub fn map_index(&self, i: isize, len: usize) -> Option<usize> {
+        if i >= 0 && (i as usize) < len {
+            return Some(i as usize);
+        }
+        if len == 0 {
+            return None;
+        }
+
+        let n = len as isize;
+        let i = match self {
+            BorderType::Constant(_) => return None,
+            BorderType::Replicate => i.clamp(0, n - 1),
+            BorderType::Reflect => {
+                let i = i.rem_euclid(2 * n);
+                if i < n {
+                    i
+                } else {
+                    2 * n - 1 - i
+                }
+            }
+            BorderType::Reflect101 => {
+                if n == 1 {
+                    return Some(0);
+                }
+                let i = i.rem_euclid(2 * (n - 1));
+                if i < n {
+                    i
+                } else {
+                    2 * (n - 1) - i
+                }
+            }
+            BorderType::Wrap => i.rem_euclid(n),
+        };
+
+        Some(i as usize)
+    }
+
+    /// Returns the value of the pixels on a constant border.
+    #[inline]
+    pub(crate) fn constant_value(&self) -> f32 {
+        match self {
+            BorderType::Constant(value) => *value,
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BorderType;
+
+    #[test]
+    fn border_map_index() {
+        let padded = |border: BorderType| {
+            (-6..14)
+                .map(|i| {
+                    border
+                        .map_index(i, 8)
+                        .map_or('i', |j| b"abcdefgh"[j] as char)
+                })
+                .collect::<String>()
+        };
+
+        assert_eq!(padded(BorderType::Constant(0.0)), "iiiiiiabcdefghiiiiii");
+        assert_eq!(padded(BorderType::Replicate), "aaaaaaabcdefghhhhhhh");
+        assert_eq!(padded(BorderType::Reflect), "fedcbaabcdefghhgfedc");
+        assert_eq!(padded(BorderType::Reflect101), "gfedcbabcdefghgfedcb");
+        assert_eq!(padded(BorderType::Wrap), "cdefghabcdefghabcdef");
+
+        // the mirrored borders are repeated for coordinates far away from the image
+        assert_eq!(BorderType::Reflect.map_index(17, 8), Some(1));
+        assert_eq!(BorderType::Reflect101.map_index(-15, 8), Some(1));
+        assert_eq!(BorderType::Reflect101.map_index(3, 1), Some(0));
+        assert_eq!(BorderType::Replicate.map_index(0, 0), None);
+    }
+}
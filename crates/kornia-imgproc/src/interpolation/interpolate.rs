@@ -1,4 +1,6 @@
 use super::bilinear::bilinear_interpolation;
+use super::border::BorderType;
+use super::kernels::{cubic, lanczos};
 use super::nearest::nearest_neighbor_interpolation;
 use kornia_image::Image;
 
@@ -9,10 +11,94 @@ pub enum InterpolationMode {
     Bilinear,
     /// Nearest neighbor interpolation
     Nearest,
+    /// Bicubic interpolation with the Catmull-Rom kernel
+    Bicubic,
+    /// Lanczos interpolation with three lobes
+    Lanczos,
+    /// Area interpolation, averaging the pixels covered by the output pixel
+    Area,
+}
+
+/// A pixel type whose values can be interpolated.
+///
+/// The interpolation is computed in `f32` precision and converted back to the pixel type,
+/// integer types are rounded to the nearest value and saturated to their range.
+pub trait InterpolationPixel: Copy + Send + Sync {
+    /// Convert the pixel value to `f32`.
+    fn to_f32(self) -> f32;
+
+    /// Convert an interpolated `f32` value to the pixel type.
+    fn from_f32(value: f32) -> Self;
+}
+
+impl InterpolationPixel for f32 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+}
+
+impl InterpolationPixel for u8 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        // the cast saturates and maps NaN to zero
+        value.round() as u8
+    }
+}
+
+impl InterpolationPixel for u16 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        // the cast saturates and maps NaN to zero
+        value.round() as u16
+    }
+}
+
+#[cfg(feature = "half")]
+impl InterpolationPixel for half::f16 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        half::f16::to_f32(self)
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        half::f16::from_f32(value)
+    }
+}
+
+#[cfg(feature = "half")]
+impl InterpolationPixel for half::bf16 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        half::bf16::to_f32(self)
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        half::bf16::from_f32(value)
+    }
 }
 
 /// Kernel for interpolating a pixel value
 ///
+/// The pixels outside of the image replicate the border pixels.
+///
 /// # Arguments
 ///
 /// * `image` - The input image container with shape (height, width, C).
@@ -21,19 +107,269 @@ pub enum InterpolationMode {
 /// * `c` - The channel of the pixel to interpolate.
 /// * `interpolation` - The interpolation mode to use.
 ///
+/// NOTE: the Area mode is a resampling kernel and falls back to bilinear interpolation when
+/// sampling a single pixel.
+///
 /// # Returns
 ///
 /// The interpolated pixel value.
-pub fn interpolate_pixel<const C: usize>(
-    //image: &ArrayView3<T>,
-    image: &Image<f32, C>,
+pub fn interpolate_pixel<T: InterpolationPixel, const C: usize>(
+    image: &Image<T, C>,
     u: f32,
     v: f32,
     c: usize,
     interpolation: InterpolationMode,
-) -> f32 {
+) -> T {
     match interpolation {
-        InterpolationMode::Bilinear => bilinear_interpolation(image, u, v, c),
+        InterpolationMode::Bilinear | InterpolationMode::Area => {
+            T::from_f32(bilinear_interpolation(image, u, v, c))
+        }
         InterpolationMode::Nearest => nearest_neighbor_interpolation(image, u, v, c),
+        InterpolationMode::Bicubic | InterpolationMode::Lanczos => {
+            interpolate_pixel_with_border(image, u, v, c, interpolation, BorderType::Replicate)
+        }
+    }
+}
+
+/// Kernel for interpolating a pixel value with the given border handling
+///
+/// The bilinear and area modes with a constant border interpolate the points within the image,
+/// i.e. in `[0, width) x [0, height)`, replicating the border pixels, and set the points outside of
+/// the image to the constant value. The points with non-finite coordinates are outside of the
+/// image and are set to the constant value of the border, or zero.
+///
+/// # Arguments
+///
+/// * `image` - The input image container with shape (height, width, C).
+/// * `u` - The x coordinate of the pixel to interpolate.
+/// * `v` - The y coordinate of the pixel to interpolate.
+/// * `c` - The channel of the pixel to interpolate.
+/// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside of the image.
+///
+/// NOTE: the Area mode is a resampling kernel and falls back to bilinear interpolation when
+/// sampling a single pixel.
+///
+/// # Returns
+///
+/// The interpolated pixel value.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::interpolation::{interpolate_pixel_with_border, BorderType, InterpolationMode};
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 2,
+///         height: 1,
+///     },
+///     vec![1.0, 3.0],
+/// )
+/// .unwrap();
+///
+/// let value = interpolate_pixel_with_border(
+///     &image,
+///     1.5,
+///     0.0,
+///     0,
+///     InterpolationMode::Bilinear,
+///     BorderType::Wrap,
+/// );
+/// assert_eq!(value, 2.0);
+/// ```
+pub fn interpolate_pixel_with_border<T: InterpolationPixel, const C: usize>(
+    image: &Image<T, C>,
+    u: f32,
+    v: f32,
+    c: usize,
+    interpolation: InterpolationMode,
+    border: BorderType,
+) -> T {
+    let mut value = [T::from_f32(0.0)];
+    interpolate_channels(image, u, v, c, interpolation, border, &mut value);
+    value[0]
+}
+
+/// The coordinates are clamped to this magnitude, far outside of any image, so that the indices of
+/// the samples do not overflow.
+const MAX_COORDINATE: f32 = (1 << 24) as f32;
+
+/// The samples along an axis with their weights, None for the samples on a constant border.
+type Taps<const K: usize> = [(Option<usize>, f32); K];
+
+/// Interpolate consecutive channels of a pixel with the given border handling.
+///
+/// The positions and the weights of the samples are computed once for all the channels, see
+/// [`interpolate_pixel_with_border`].
+///
+/// # Arguments
+///
+/// * `image` - The input image container with shape (height, width, C).
+/// * `u` - The x coordinate of the pixel to interpolate.
+/// * `v` - The y coordinate of the pixel to interpolate.
+/// * `first_channel` - The first channel to interpolate.
+/// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside of the image.
+/// * `dst` - The interpolated values of the channels from `first_channel`.
+pub(crate) fn interpolate_channels<T: InterpolationPixel, const C: usize>(
+    image: &Image<T, C>,
+    u: f32,
+    v: f32,
+    first_channel: usize,
+    interpolation: InterpolationMode,
+    border: BorderType,
+    dst: &mut [T],
+) {
+    let (rows, cols) = (image.rows(), image.cols());
+    let constant = border.constant_value();
+
+    // the points at infinity or undefined are outside of the image
+    if !u.is_finite() || !v.is_finite() {
+        dst.fill(T::from_f32(constant));
+        return;
+    }
+    let (u, v) = (
+        u.clamp(-MAX_COORDINATE, MAX_COORDINATE),
+        v.clamp(-MAX_COORDINATE, MAX_COORDINATE),
+    );
+
+    match interpolation {
+        InterpolationMode::Nearest => {
+            let taps = |t: f32, len: usize| [(border.map_index(t.round() as isize, len), 1.0)];
+            let (wx, wy) = (taps(u, cols), taps(v, rows));
+            interpolate_taps(image, &wx, &wy, constant, first_channel, dst);
+        }
+        InterpolationMode::Bilinear | InterpolationMode::Area => {
+            let border = match border {
+                // the points within the image replicate the border pixels
+                BorderType::Constant(_) => {
+                    if !(0.0..cols as f32).contains(&u) || !(0.0..rows as f32).contains(&v) {
+                        dst.fill(T::from_f32(constant));
+                        return;
+                    }
+                    BorderType::Replicate
+                }
+                border => border,
+            };
+            let taps = |t: f32, len: usize| {
+                let t0 = t.floor();
+                let (i0, frac) = (t0 as isize, t - t0);
+                [
+                    (border.map_index(i0, len), 1.0 - frac),
+                    (border.map_index(i0 + 1, len), frac),
+                ]
+            };
+            let (wx, wy) = (taps(u, cols), taps(v, rows));
+            interpolate_taps(image, &wx, &wy, constant, first_channel, dst);
+        }
+        InterpolationMode::Bicubic => {
+            let wx = kernel_taps::<4>(u, cols, cubic, border);
+            let wy = kernel_taps::<4>(v, rows, cubic, border);
+            interpolate_taps(image, &wx, &wy, constant, first_channel, dst);
+        }
+        InterpolationMode::Lanczos => {
+            let wx = kernel_taps::<6>(u, cols, lanczos, border);
+            let wy = kernel_taps::<6>(v, rows, lanczos, border);
+            interpolate_taps(image, &wx, &wy, constant, first_channel, dst);
+        }
+    }
+}
+
+/// Compute the K samples around a point along an axis with the weights of a kernel.
+///
+/// The weights are normalized to sum one.
+#[inline]
+fn kernel_taps<const K: usize>(
+    t: f32,
+    len: usize,
+    kernel: fn(f32) -> f32,
+    border: BorderType,
+) -> Taps<K> {
+    // the first sample is K / 2 - 1 pixels before the one at the left (top) of the point
+    let t0 = t.floor() as isize - (K as isize / 2 - 1);
+    let mut w: [f32; K] = std::array::from_fn(|k| kernel(t - (t0 + k as isize) as f32));
+    let sum = w.iter().sum::<f32>();
+    w.iter_mut().for_each(|w| *w /= sum);
+    std::array::from_fn(|k| (border.map_index(t0 + k as isize, len), w[k]))
+}
+
+/// Interpolate consecutive channels of a pixel from the samples along each axis.
+#[inline]
+fn interpolate_taps<T: InterpolationPixel, const C: usize, const K: usize>(
+    image: &Image<T, C>,
+    wx: &Taps<K>,
+    wy: &Taps<K>,
+    constant: f32,
+    first_channel: usize,
+    dst: &mut [T],
+) {
+    for (i, out) in dst.iter_mut().enumerate() {
+        let c = first_channel + i;
+        let value = wy
+            .iter()
+            .map(|&(y, wy)| {
+                let row = wx
+                    .iter()
+                    .map(|&(x, wx)| match (x, y) {
+                        (Some(x), Some(y)) => wx * image.get_unchecked([y, x, c]).to_f32(),
+                        _ => wx * constant,
+                    })
+                    .sum::<f32>();
+                wy * row
+            })
+            .sum::<f32>();
+        *out = T::from_f32(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interpolate_pixel_with_border, BorderType, InterpolationMode};
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    #[test]
+    fn interpolate_non_finite() -> Result<(), ImageError> {
+        let image = Image::<u8, 1>::new(
+            ImageSize {
+                width: 3,
+                height: 3,
+            },
+            vec![1; 9],
+        )?;
+
+        for interpolation in [
+            InterpolationMode::Nearest,
+            InterpolationMode::Bilinear,
+            InterpolationMode::Bicubic,
+            InterpolationMode::Lanczos,
+            InterpolationMode::Area,
+        ] {
+            for (u, v) in [
+                (f32::INFINITY, 2.0),
+                (1.0, f32::NEG_INFINITY),
+                (f32::NAN, f32::NAN),
+            ] {
+                let value =
+                    |border| interpolate_pixel_with_border(&image, u, v, 0, interpolation, border);
+                assert_eq!(value(BorderType::Replicate), 0, "{interpolation:?}");
+                assert_eq!(value(BorderType::Reflect101), 0, "{interpolation:?}");
+                assert_eq!(value(BorderType::Constant(9.0)), 9, "{interpolation:?}");
+            }
+
+            // the far away points do not overflow the indices
+            let value = interpolate_pixel_with_border(
+                &image,
+                1e30,
+                -1e30,
+                0,
+                interpolation,
+                BorderType::Wrap,
+            );
+            assert_eq!(value, 1, "{interpolation:?}");
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,42 @@
+use std::f32::consts::PI;
+
+/// The radius of the cubic convolution kernel.
+pub(crate) const CUBIC_SUPPORT: f32 = 2.0;
+
+/// The radius of the Lanczos kernel.
+pub(crate) const LANCZOS_SUPPORT: f32 = 3.0;
+
+/// Cubic convolution kernel with a = -0.5 (Catmull-Rom), as used by Pillow.
+#[inline]
+pub(crate) fn cubic(x: f32) -> f32 {
+    const A: f32 = -0.5;
+    let x = x.abs();
+    if x < 1.0 {
+        ((A + 2.0) * x - (A + 3.0)) * x * x + 1.0
+    } else if x < 2.0 {
+        ((A * x - 5.0 * A) * x + 8.0 * A) * x - 4.0 * A
+    } else {
+        0.0
+    }
+}
+
+/// Normalized sinc function.
+#[inline]
+fn sinc(x: f32) -> f32 {
+    if x == 0.0 {
+        1.0
+    } else {
+        let x = x * PI;
+        x.sin() / x
+    }
+}
+
+/// Lanczos kernel with three lobes.
+#[inline]
+pub(crate) fn lanczos(x: f32) -> f32 {
+    if x.abs() < LANCZOS_SUPPORT {
+        sinc(x) * sinc(x / LANCZOS_SUPPORT)
+    } else {
+        0.0
+    }
+}
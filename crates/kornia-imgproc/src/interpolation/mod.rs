@@ -1,13 +1,16 @@
 mod bilinear;
+mod border;
 
 /// Utility functions to generate meshgrid and remap images
 pub mod grid;
 
 pub(crate) mod interpolate;
+pub(crate) mod kernels;
 mod nearest;
 mod remap;
 
+pub use border::BorderType;
 pub use interpolate::InterpolationMode;
 pub use remap::remap;
 
-pub use interpolate::interpolate_pixel;
+pub use interpolate::{interpolate_pixel, interpolate_pixel_with_border, InterpolationPixel};
@@ -12,12 +12,12 @@ use kornia_image::Image;
 /// # Returns
 ///
 /// The interpolated pixel value.
-pub(crate) fn nearest_neighbor_interpolation<const C: usize>(
-    image: &Image<f32, C>,
+pub(crate) fn nearest_neighbor_interpolation<T: Copy, const C: usize>(
+    image: &Image<T, C>,
     u: f32,
     v: f32,
     c: usize,
-) -> f32 {
+) -> T {
     let (rows, cols) = (image.rows(), image.cols());
 
     let iu = u.round() as usize;
@@ -1,7 +1,7 @@
 use crate::parallel;
 
-use super::interpolate::interpolate_pixel;
-use super::InterpolationMode;
+use super::interpolate::{interpolate_channels, InterpolationPixel};
+use super::{BorderType, InterpolationMode};
 use kornia_image::{Image, ImageError};
 use kornia_tensor::{CpuAllocator, Tensor2};
 
@@ -14,17 +14,19 @@ use kornia_tensor::{CpuAllocator, Tensor2};
 /// * `map_x` - The x coordinates of the pixels to interpolate.
 /// * `map_y` - The y coordinates of the pixels to interpolate.
 /// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside of the input image.
 ///
 /// # Errors
 ///
 /// * The mapx and mapy must have the same size.
 /// * The output image must have the same size as the mapx and mapy.
-pub fn remap<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+pub fn remap<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
     map_x: &Tensor2<f32, CpuAllocator>,
     map_y: &Tensor2<f32, CpuAllocator>,
     interpolation: InterpolationMode,
+    border: BorderType,
 ) -> Result<(), ImageError> {
     if map_x.shape != map_y.shape {
         return Err(ImageError::InvalidImageSize(
@@ -46,10 +48,8 @@ pub fn remap<const C: usize>(
 
     // parallelize the remap operation by rows
     parallel::par_iter_rows_resample(dst, map_x, map_y, |&x, &y, dst_pixel| {
-        // interpolate the pixel value
-        dst_pixel.iter_mut().enumerate().for_each(|(c, pixel)| {
-            *pixel = interpolate_pixel(src, x, y, c, interpolation);
-        });
+        // interpolate the pixel value of all the channels
+        interpolate_channels(src, x, y, 0, interpolation, border, dst_pixel);
     });
 
     Ok(())
@@ -91,6 +91,7 @@ mod tests {
             &map_x,
             &map_y,
             super::InterpolationMode::Bilinear,
+            super::BorderType::Replicate,
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
@@ -107,4 +108,138 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn remap_borders() -> Result<(), ImageError> {
+        use super::BorderType;
+
+        let image = Image::<u8, 1>::new(
+            ImageSize {
+                width: 4,
+                height: 1,
+            },
+            vec![10, 20, 30, 40],
+        )?;
+
+        let map_x = Tensor2::from_shape_vec([1, 4], vec![-2.0, -1.0, 4.0, 6.0], CpuAllocator)?;
+        let map_y = Tensor2::from_shape_vec([1, 4], vec![0.0; 4], CpuAllocator)?;
+
+        for (border, expected) in [
+            (BorderType::Constant(7.0), [7, 7, 7, 7]),
+            (BorderType::Replicate, [10, 10, 40, 40]),
+            (BorderType::Reflect, [20, 10, 40, 20]),
+            (BorderType::Reflect101, [30, 20, 30, 10]),
+            (BorderType::Wrap, [30, 40, 10, 30]),
+        ] {
+            let mut image_transformed = Image::<u8, 1>::from_size_val([4, 1].into(), 0)?;
+            super::remap(
+                &image,
+                &mut image_transformed,
+                &map_x,
+                &map_y,
+                super::InterpolationMode::Nearest,
+                border,
+            )?;
+            assert_eq!(image_transformed.as_slice(), expected, "{border:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn remap_higher_order() -> Result<(), ImageError> {
+        // a linear ramp along x
+        let image = Image::<f32, 1>::new(
+            ImageSize {
+                width: 8,
+                height: 8,
+            },
+            (0..64).map(|i| (i % 8) as f32).collect(),
+        )?;
+
+        let xs = vec![2.0, 3.0, 3.25, 4.5];
+        let map_x = Tensor2::from_shape_vec([1, 4], xs.clone(), CpuAllocator)?;
+        let map_y = Tensor2::from_shape_vec([1, 4], vec![3.5; 4], CpuAllocator)?;
+
+        let mut image_transformed = Image::<f32, 1>::from_size_val([4, 1].into(), 0.0)?;
+        super::remap(
+            &image,
+            &mut image_transformed,
+            &map_x,
+            &map_y,
+            super::InterpolationMode::Bicubic,
+            super::BorderType::Reflect101,
+        )?;
+        // the cubic kernel reproduces linear functions
+        for (a, b) in image_transformed.as_slice().iter().zip(&xs) {
+            assert!((a - b).abs() < 1e-5, "{a} != {b}");
+        }
+
+        super::remap(
+            &image,
+            &mut image_transformed,
+            &map_x,
+            &map_y,
+            super::InterpolationMode::Lanczos,
+            super::BorderType::Reflect101,
+        )?;
+        // the Lanczos kernel interpolates the pixels and is close to linear in between
+        assert!((image_transformed.as_slice()[0] - 2.0).abs() < 1e-5);
+        assert!((image_transformed.as_slice()[1] - 3.0).abs() < 1e-5);
+        for (a, b) in image_transformed.as_slice().iter().zip(&xs) {
+            assert!((a - b).abs() < 2e-2, "{a} != {b}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn remap_non_finite() -> Result<(), ImageError> {
+        use super::{BorderType, InterpolationMode};
+
+        let image = Image::<f32, 1>::new(
+            ImageSize {
+                width: 4,
+                height: 1,
+            },
+            vec![10.0, 20.0, 30.0, 40.0],
+        )?;
+
+        let map_x = Tensor2::from_shape_vec(
+            [1, 3],
+            vec![f32::INFINITY, f32::NEG_INFINITY, f32::NAN],
+            CpuAllocator,
+        )?;
+        let map_y = Tensor2::from_shape_vec([1, 3], vec![0.0; 3], CpuAllocator)?;
+
+        for interpolation in [
+            InterpolationMode::Nearest,
+            InterpolationMode::Bilinear,
+            InterpolationMode::Bicubic,
+            InterpolationMode::Lanczos,
+        ] {
+            for (border, expected) in [
+                (BorderType::Constant(7.0), 7.0),
+                (BorderType::Replicate, 0.0),
+                (BorderType::Wrap, 0.0),
+            ] {
+                let mut image_transformed = Image::<f32, 1>::from_size_val([3, 1].into(), 1.0)?;
+                super::remap(
+                    &image,
+                    &mut image_transformed,
+                    &map_x,
+                    &map_y,
+                    interpolation,
+                    border,
+                )?;
+                assert_eq!(
+                    image_transformed.as_slice(),
+                    [expected; 3],
+                    "{interpolation:?} {border:?}"
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -6,6 +6,12 @@ pub mod calibration;
 /// color transformations module.
 pub mod color;
 
+/// connected components labelling module.
+pub mod connected_components;
+
+/// contour finding and analysis module.
+pub mod contours;
+
 /// image basic operations module.
 pub mod core;
 
@@ -42,9 +48,18 @@ pub mod parallel;
 /// image processing metrics module.
 pub mod metrics;
 
+/// morphological operations module.
+pub mod morphology;
+
 /// operations to normalize images.
 pub mod normalize;
 
+/// sparse optical flow module.
+pub mod optical_flow;
+
+/// image pyramids module.
+pub mod pyramid;
+
 /// utility functions for resizing images.
 pub mod resize;
 
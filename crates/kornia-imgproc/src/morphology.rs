@@ -0,0 +1,605 @@
+use std::ops::Sub;
+
+use num_traits::{Bounded, Zero};
+
+use kornia_image::{Image, ImageError};
+
+use crate::parallel;
+
+/// The shape of a structuring element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KernelShape {
+    /// A filled rectangle.
+    Rect,
+    /// A cross made of the center row and the center column.
+    Cross,
+    /// A filled ellipse inscribed in the rectangle.
+    Ellipse,
+}
+
+/// A structuring element, the neighborhood used by the morphological operations.
+///
+/// The element is anchored at its center.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructuringElement {
+    width: usize,
+    height: usize,
+    mask: Vec<bool>,
+}
+
+impl StructuringElement {
+    /// Create a structuring element with a predefined shape.
+    ///
+    /// The ellipse matches `cv2.getStructuringElement(cv2.MORPH_ELLIPSE, ...)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the element.
+    /// * `size` - The width and height of the element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the width or the height is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_imgproc::morphology::{KernelShape, StructuringElement};
+    ///
+    /// let kernel = StructuringElement::new(KernelShape::Cross, (3, 3)).unwrap();
+    /// assert_eq!(
+    ///     kernel.mask(),
+    ///     &[false, true, false, true, true, true, false, true, false]
+    /// );
+    /// ```
+    pub fn new(shape: KernelShape, size: (usize, usize)) -> Result<Self, ImageError> {
+        let (width, height) = size;
+        if width == 0 || height == 0 {
+            return Err(ImageError::InvalidKernelLength(width, height));
+        }
+
+        let (cx, cy) = (width / 2, height / 2);
+        let mut mask = vec![false; width * height];
+        for (y, row) in mask.chunks_exact_mut(width).enumerate() {
+            let (start, end) = match shape {
+                KernelShape::Rect => (0, width),
+                KernelShape::Cross if y == cy => (0, width),
+                KernelShape::Cross => (cx, cx + 1),
+                KernelShape::Ellipse => {
+                    let dy = y as f32 - cy as f32;
+                    // a single row is a flat ellipse
+                    let dx = if cy == 0 {
+                        cx
+                    } else {
+                        let r = cy as f32;
+                        (cx as f32 * ((r * r - dy * dy) / (r * r)).sqrt()).round() as usize
+                    };
+                    (cx - dx.min(cx), (cx + dx + 1).min(width))
+                }
+            };
+            row[start..end].fill(true);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            mask,
+        })
+    }
+
+    /// Create a structuring element from a custom mask.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The width and height of the element.
+    /// * `mask` - The row-major mask of the element, true for the pixels of the neighborhood.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of the mask does not match the size.
+    pub fn from_mask(size: (usize, usize), mask: Vec<bool>) -> Result<Self, ImageError> {
+        let (width, height) = size;
+        if width == 0 || height == 0 || mask.len() != width * height {
+            return Err(ImageError::InvalidKernelLength(mask.len(), width * height));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            mask,
+        })
+    }
+
+    /// The width and height of the element.
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// The row-major mask of the element.
+    pub fn mask(&self) -> &[bool] {
+        &self.mask
+    }
+
+    /// The offsets to the center of the pixels of the element.
+    fn offsets(&self) -> Vec<(isize, isize)> {
+        let (cx, cy) = ((self.width / 2) as isize, (self.height / 2) as isize);
+        self.mask
+            .iter()
+            .enumerate()
+            .filter(|(_, &m)| m)
+            .map(|(i, _)| {
+                (
+                    (i % self.width) as isize - cx,
+                    (i / self.width) as isize - cy,
+                )
+            })
+            .collect()
+    }
+
+    /// Split a rectangular element in a horizontal and a vertical element.
+    fn separable_offsets(&self) -> Option<[Vec<(isize, isize)>; 2]> {
+        if !self.mask.iter().all(|&m| m) {
+            return None;
+        }
+        let (cx, cy) = ((self.width / 2) as isize, (self.height / 2) as isize);
+        let horizontal = (0..self.width as isize).map(|x| (x - cx, 0)).collect();
+        let vertical = (0..self.height as isize).map(|y| (0, y - cy)).collect();
+        Some([horizontal, vertical])
+    }
+}
+
+/// A morphological operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MorphologyOp {
+    /// The minimum over the neighborhood.
+    Erode,
+    /// The maximum over the neighborhood.
+    Dilate,
+    /// An erosion followed by a dilation, removes small bright regions.
+    Open,
+    /// A dilation followed by an erosion, fills small dark regions.
+    Close,
+    /// The difference between the dilation and the erosion, highlights the edges.
+    Gradient,
+    /// The difference between the image and its opening.
+    TopHat,
+    /// The difference between the closing and the image.
+    BlackHat,
+}
+
+/// Compute the minimum or the maximum over the neighborhood of each pixel.
+///
+/// The pixels outside of the image are ignored.
+fn morph_pass<T, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    offsets: &[(isize, isize)],
+    dilate: bool,
+) where
+    T: Copy + Send + Sync + PartialOrd + Bounded,
+{
+    let (cols, rows) = (src.cols() as isize, src.rows() as isize);
+    let init = if dilate {
+        T::min_value()
+    } else {
+        T::max_value()
+    };
+    let src_data = src.as_slice();
+
+    parallel::par_iter_rows_indexed(dst, |y, dst_row| {
+        dst_row.fill(init);
+        for &(dx, dy) in offsets {
+            let sy = y as isize + dy;
+            if sy < 0 || sy >= rows {
+                continue;
+            }
+            // the range of the row for which the neighbor is inside the image
+            let start = (-dx).clamp(0, cols) as usize;
+            let end = (cols - dx).clamp(0, cols) as usize;
+            if start >= end {
+                continue;
+            }
+            let src_start = (sy * cols + start as isize + dx) as usize * C;
+            let src_row = &src_data[src_start..src_start + (end - start) * C];
+            let dst_row = &mut dst_row[start * C..end * C];
+            for (d, &s) in dst_row.iter_mut().zip(src_row) {
+                if (dilate && s > *d) || (!dilate && s < *d) {
+                    *d = s;
+                }
+            }
+        }
+    });
+}
+
+/// Apply an erosion or a dilation a number of times.
+///
+/// The reflected element, i.e. with the negated offsets, is used if `reflect` is true.
+fn erode_or_dilate<T, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    kernel: &StructuringElement,
+    iterations: usize,
+    dilate: bool,
+    reflect: bool,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + PartialOrd + Bounded,
+{
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    dst.as_slice_mut().copy_from_slice(src.as_slice());
+    if src.cols() == 0 || src.rows() == 0 || iterations == 0 {
+        return Ok(());
+    }
+
+    // rectangles are processed as a row followed by a column
+    let mut passes = match kernel.separable_offsets() {
+        Some(passes) => passes.to_vec(),
+        None => vec![kernel.offsets()],
+    };
+    if reflect {
+        for (dx, dy) in passes.iter_mut().flatten() {
+            (*dx, *dy) = (-*dx, -*dy);
+        }
+    }
+
+    let mut tmp = dst.clone();
+    for _ in 0..iterations {
+        for offsets in passes.iter() {
+            morph_pass(dst, &mut tmp, offsets, dilate);
+            std::mem::swap(dst, &mut tmp);
+        }
+    }
+
+    Ok(())
+}
+
+/// Erode an image with a structuring element.
+///
+/// Each pixel is set to the minimum of its neighborhood, the pixels outside of the image are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `dst` - The output image.
+/// * `kernel` - The structuring element.
+/// * `iterations` - The number of times the erosion is applied.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::morphology::{erode, KernelShape, StructuringElement};
+///
+/// let image = Image::<u8, 1>::new(
+///     ImageSize {
+///         width: 5,
+///         height: 1,
+///     },
+///     vec![0, 255, 255, 255, 0],
+/// )
+/// .unwrap();
+///
+/// let mut eroded = Image::<u8, 1>::from_size_val(image.size(), 0).unwrap();
+/// let kernel = StructuringElement::new(KernelShape::Rect, (3, 1)).unwrap();
+///
+/// erode(&image, &mut eroded, &kernel, 1).unwrap();
+/// assert_eq!(eroded.as_slice(), &[0, 0, 255, 0, 0]);
+/// ```
+pub fn erode<T, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    kernel: &StructuringElement,
+    iterations: usize,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + PartialOrd + Bounded,
+{
+    erode_or_dilate(src, dst, kernel, iterations, false, false)
+}
+
+/// Dilate an image with a structuring element.
+///
+/// Each pixel is set to the maximum of its neighborhood, the pixels outside of the image are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `dst` - The output image.
+/// * `kernel` - The structuring element.
+/// * `iterations` - The number of times the dilation is applied.
+///
+/// Precondition: the input and output images must have the same size.
+pub fn dilate<T, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    kernel: &StructuringElement,
+    iterations: usize,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + PartialOrd + Bounded,
+{
+    erode_or_dilate(src, dst, kernel, iterations, true, false)
+}
+
+/// Apply a morphological operation to an image.
+///
+/// The opening and the closing apply all the erosions and then all the dilations, or the
+/// opposite, like `cv2.morphologyEx`. The second step uses the reflected element, so that the
+/// opening is never above the image and the closing is never below it, also for the elements
+/// that are not symmetric around their center, e.g. with an even size.
+///
+/// The differences of the gradient, the top hat and the black hat saturate at zero.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `dst` - The output image.
+/// * `op` - The morphological operation.
+/// * `kernel` - The structuring element.
+/// * `iterations` - The number of times the erosions and dilations are applied.
+///
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::morphology::{morphology, KernelShape, MorphologyOp, StructuringElement};
+///
+/// let image = Image::<f32, 1>::new(
+///     ImageSize {
+///         width: 5,
+///         height: 1,
+///     },
+///     vec![0.0, 0.0, 1.0, 1.0, 1.0],
+/// )
+/// .unwrap();
+///
+/// let mut edges = Image::<f32, 1>::from_size_val(image.size(), 0.0).unwrap();
+/// let kernel = StructuringElement::new(KernelShape::Rect, (3, 1)).unwrap();
+///
+/// morphology(&image, &mut edges, MorphologyOp::Gradient, &kernel, 1).unwrap();
+/// assert_eq!(edges.as_slice(), &[0.0, 1.0, 1.0, 0.0, 0.0]);
+/// ```
+pub fn morphology<T, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    op: MorphologyOp,
+    kernel: &StructuringElement,
+    iterations: usize,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + PartialOrd + Bounded + Zero + Sub<Output = T>,
+{
+    match op {
+        MorphologyOp::Erode => erode(src, dst, kernel, iterations),
+        MorphologyOp::Dilate => dilate(src, dst, kernel, iterations),
+        MorphologyOp::Open | MorphologyOp::TopHat => {
+            let mut eroded = src.clone();
+            erode(src, &mut eroded, kernel, iterations)?;
+            erode_or_dilate(&eroded, dst, kernel, iterations, true, true)?;
+            if op == MorphologyOp::TopHat {
+                let opened = dst.clone();
+                parallel::par_iter_rows_val_two(src, &opened, dst, |&s, &o, d| {
+                    *d = saturating_diff(s, o)
+                });
+            }
+            Ok(())
+        }
+        MorphologyOp::Close | MorphologyOp::BlackHat => {
+            let mut dilated = src.clone();
+            dilate(src, &mut dilated, kernel, iterations)?;
+            erode_or_dilate(&dilated, dst, kernel, iterations, false, true)?;
+            if op == MorphologyOp::BlackHat {
+                let closed = dst.clone();
+                parallel::par_iter_rows_val_two(&closed, src, dst, |&c, &s, d| {
+                    *d = saturating_diff(c, s)
+                });
+            }
+            Ok(())
+        }
+        MorphologyOp::Gradient => {
+            let mut eroded = src.clone();
+            erode(src, &mut eroded, kernel, iterations)?;
+            dilate(src, dst, kernel, iterations)?;
+            // an element without pixels dilates to the minimum and erodes to the maximum
+            let dilated = dst.clone();
+            parallel::par_iter_rows_val_two(&dilated, &eroded, dst, |&a, &b, d| {
+                *d = saturating_diff(a, b)
+            });
+            Ok(())
+        }
+    }
+}
+
+/// The difference `a - b`, or zero if `a` is not greater than `b`.
+#[inline]
+fn saturating_diff<T: PartialOrd + Zero + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        T::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KernelShape, MorphologyOp, StructuringElement};
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    fn mask_to_string(kernel: &StructuringElement) -> Vec<String> {
+        kernel
+            .mask()
+            .chunks_exact(kernel.size().0)
+            .map(|row| row.iter().map(|&m| if m { '1' } else { '0' }).collect())
+            .collect()
+    }
+
+    #[test]
+    fn structuring_element_shapes() -> Result<(), ImageError> {
+        let ellipse = StructuringElement::new(KernelShape::Ellipse, (5, 5))?;
+        assert_eq!(
+            mask_to_string(&ellipse),
+            ["00100", "11111", "11111", "11111", "00100"]
+        );
+
+        let ellipse = StructuringElement::new(KernelShape::Ellipse, (7, 3))?;
+        assert_eq!(mask_to_string(&ellipse), ["0001000", "1111111", "0001000"]);
+
+        let cross = StructuringElement::new(KernelShape::Cross, (5, 3))?;
+        assert_eq!(mask_to_string(&cross), ["00100", "11111", "00100"]);
+
+        assert!(StructuringElement::new(KernelShape::Rect, (0, 3)).is_err());
+        assert!(StructuringElement::from_mask((2, 2), vec![true; 3]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn erode_dilate_u8() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 5,
+        };
+        let mut data = vec![0u8; 25];
+        data[12] = 200;
+        let image = Image::<u8, 1>::new(size, data)?;
+
+        let cross = StructuringElement::new(KernelShape::Cross, (3, 3))?;
+        let mut dilated = Image::<u8, 1>::from_size_val(size, 0)?;
+        super::dilate(&image, &mut dilated, &cross, 1)?;
+        #[rustfmt::skip]
+        assert_eq!(
+            dilated.as_slice(),
+            &[
+                0, 0, 0, 0, 0,
+                0, 0, 200, 0, 0,
+                0, 200, 200, 200, 0,
+                0, 0, 200, 0, 0,
+                0, 0, 0, 0, 0,
+            ]
+        );
+
+        // two iterations with a 3x3 rectangle are a 5x5 rectangle
+        let rect = StructuringElement::new(KernelShape::Rect, (3, 3))?;
+        super::dilate(&image, &mut dilated, &rect, 2)?;
+        assert_eq!(dilated.as_slice(), &[200; 25]);
+
+        // the pixels outside of the image do not erode the borders
+        let mut eroded = Image::<u8, 1>::from_size_val(size, 0)?;
+        super::erode(&dilated, &mut eroded, &rect, 1)?;
+        assert_eq!(eroded.as_slice(), &[200; 25]);
+
+        let custom = StructuringElement::from_mask((3, 1), vec![true, false, false])?;
+        super::erode(&image, &mut eroded, &custom, 0)?;
+        assert_eq!(eroded.as_slice(), image.as_slice());
+        super::dilate(&image, &mut eroded, &custom, 1)?;
+        assert_eq!(eroded.as_slice()[13], 200);
+        Ok(())
+    }
+
+    #[test]
+    fn morphology_ops_f32() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 7,
+            height: 1,
+        };
+        // bright specks and dark holes narrower than the kernel
+        let image = Image::<f32, 2>::new(
+            size,
+            [0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]
+                .iter()
+                .flat_map(|&v| [v, 0.5 * v])
+                .collect(),
+        )?;
+        let kernel = StructuringElement::new(KernelShape::Rect, (3, 1))?;
+        let run = |op| -> Result<Vec<f32>, ImageError> {
+            let mut dst = Image::<f32, 2>::from_size_val(size, 0.0)?;
+            super::morphology(&image, &mut dst, op, &kernel, 1)?;
+            Ok(dst.as_slice().iter().step_by(2).copied().collect())
+        };
+
+        assert_eq!(
+            run(MorphologyOp::Open)?,
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            run(MorphologyOp::Close)?,
+            [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            run(MorphologyOp::TopHat)?,
+            [0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]
+        );
+        assert_eq!(
+            run(MorphologyOp::BlackHat)?,
+            [1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0]
+        );
+        assert_eq!(
+            run(MorphologyOp::Gradient)?,
+            [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn morphology_ops_asymmetric_u8() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 9,
+            height: 7,
+        };
+        let image = Image::<u8, 1>::new(
+            size,
+            (0..size.width * size.height)
+                .map(|i| (i * 97 % 251) as u8)
+                .collect(),
+        )?;
+
+        let kernels = [
+            StructuringElement::new(KernelShape::Rect, (4, 4))?,
+            StructuringElement::new(KernelShape::Ellipse, (4, 2))?,
+            StructuringElement::from_mask((3, 2), vec![true, true, false, false, false, true])?,
+        ];
+        for kernel in kernels.iter() {
+            let run = |op| -> Result<Image<u8, 1>, ImageError> {
+                let mut dst = Image::<u8, 1>::from_size_val(size, 0)?;
+                super::morphology(&image, &mut dst, op, kernel, 1)?;
+                Ok(dst)
+            };
+
+            let opened = run(MorphologyOp::Open)?;
+            let closed = run(MorphologyOp::Close)?;
+            let top_hat = run(MorphologyOp::TopHat)?;
+            let black_hat = run(MorphologyOp::BlackHat)?;
+            for (i, &s) in image.as_slice().iter().enumerate() {
+                let (o, c) = (opened.as_slice()[i], closed.as_slice()[i]);
+                assert!(o <= s && s <= c, "{kernel:?}: {o} {s} {c}");
+                assert_eq!(top_hat.as_slice()[i], s - o, "{kernel:?}");
+                assert_eq!(black_hat.as_slice()[i], c - s, "{kernel:?}");
+            }
+
+            // the opening and the closing are idempotent
+            let mut reopened = Image::<u8, 1>::from_size_val(size, 0)?;
+            super::morphology(&opened, &mut reopened, MorphologyOp::Open, kernel, 1)?;
+            assert_eq!(reopened.as_slice(), opened.as_slice(), "{kernel:?}");
+        }
+
+        // an element without pixels does not underflow the gradient
+        let empty = StructuringElement::from_mask((3, 3), vec![false; 9])?;
+        let mut dst = Image::<u8, 1>::from_size_val(size, 1)?;
+        super::morphology(&image, &mut dst, MorphologyOp::Gradient, &empty, 1)?;
+        assert_eq!(dst.as_slice(), &[0; 63]);
+        Ok(())
+    }
+}
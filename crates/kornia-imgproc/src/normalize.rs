@@ -320,4 +320,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn normalize_mean_std_f16() -> Result<(), ImageError> {
+        use half::f16;
+
+        let image_u8 = Image::<u8, 3>::new(
+            ImageSize {
+                width: 1,
+                height: 2,
+            },
+            vec![0, 51, 102, 153, 204, 255],
+        )?;
+
+        // u8 -> f16 in [0, 1] and normalize, as expected by fp16 models
+        let image = image_u8.cast_and_scale(f16::from_f32(1.0 / 255.0))?;
+        let mut image_normalized = Image::from_size_val(image.size(), f16::ZERO)?;
+        super::normalize_mean_std(
+            &image,
+            &mut image_normalized,
+            &[f16::from_f32(0.5); 3],
+            &[f16::from_f32(0.5); 3],
+        )?;
+
+        let expected = [-1.0, -0.6, -0.2, 0.2, 0.6, 1.0];
+        for (x, y) in image_normalized.as_slice().iter().zip(expected) {
+            assert!((x.to_f32() - y).abs() < 1e-2);
+        }
+
+        Ok(())
+    }
 }
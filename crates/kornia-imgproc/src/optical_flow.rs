@@ -0,0 +1,370 @@
+use kornia_image::{Image, ImageError};
+use rayon::prelude::*;
+
+use crate::{
+    filter::{sobel_gradients, BorderType},
+    interpolation::{interpolate_pixel_with_border, InterpolationMode},
+    pyramid::GaussianPyramid,
+};
+
+/// The configuration of the Lucas-Kanade tracker.
+#[derive(Clone, Debug)]
+pub struct LucasKanadeConfig {
+    /// The size of the square window around the points, an odd number.
+    pub window_size: usize,
+    /// The number of levels of the image pyramids, 1 to track on the input images only.
+    pub num_levels: usize,
+    /// The maximum number of iterations per level.
+    pub max_iterations: usize,
+    /// The displacement update in pixels below which the iterations stop.
+    pub epsilon: f32,
+    /// The minimum eigenvalue of the spatial gradient matrix normalized by the window area, below
+    /// which the window is too uniform to be tracked.
+    pub min_eigenvalue: f32,
+}
+
+impl Default for LucasKanadeConfig {
+    /// The default configuration for images with intensities in [0, 1].
+    fn default() -> Self {
+        Self {
+            window_size: 21,
+            num_levels: 3,
+            max_iterations: 30,
+            epsilon: 0.01,
+            min_eigenvalue: 1e-4,
+        }
+    }
+}
+
+/// A point tracked by [`track_lucas_kanade`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackedPoint {
+    /// The x and y coordinates of the point in the next image.
+    pub position: [f32; 2],
+    /// Whether the point was tracked, false if its window is too uniform or it left the image.
+    pub status: bool,
+    /// The mean absolute difference between the windows of the point in the two images.
+    pub error: f32,
+}
+
+/// A level of the pyramid of the previous image with its derivatives.
+struct GradientLevel<'a> {
+    image: &'a Image<f32, 1>,
+    dx: Image<f32, 1>,
+    dy: Image<f32, 1>,
+}
+
+/// Sample an image at a subpixel position, replicating the border pixels outside of the image.
+#[inline]
+fn sample(image: &Image<f32, 1>, x: f32, y: f32) -> f32 {
+    interpolate_pixel_with_border(
+        image,
+        x,
+        y,
+        0,
+        InterpolationMode::Bilinear,
+        BorderType::Replicate,
+    )
+}
+
+/// Whether a position lies within an image.
+#[inline]
+fn is_inside(image: &Image<f32, 1>, [x, y]: [f32; 2]) -> bool {
+    x >= 0.0 && y >= 0.0 && x <= (image.cols() - 1) as f32 && y <= (image.rows() - 1) as f32
+}
+
+/// Track a point from the previous image to the next image, from the coarsest level.
+fn track_point(
+    prev: &[GradientLevel],
+    next: &[Image<f32, 1>],
+    point: [f32; 2],
+    config: &LucasKanadeConfig,
+) -> TrackedPoint {
+    let radius = (config.window_size / 2) as isize;
+    let area = (config.window_size * config.window_size) as f32;
+    let lost = TrackedPoint {
+        position: point,
+        status: false,
+        error: 0.0,
+    };
+
+    // the displacement guessed from the coarser levels, in the pixels of the current level
+    let mut guess = [0.0f32; 2];
+    for (level_idx, (level, next_image)) in prev.iter().zip(next).enumerate().rev() {
+        let scale = (1 << level_idx) as f32;
+        let [px, py] = [point[0] / scale, point[1] / scale];
+
+        // the window of the point in the previous image with its derivatives
+        let mut window = Vec::with_capacity(config.window_size * config.window_size);
+        let (mut gxx, mut gyy, mut gxy) = (0.0, 0.0, 0.0);
+        for wy in -radius..=radius {
+            for wx in -radius..=radius {
+                let (x, y) = (px + wx as f32, py + wy as f32);
+                let ix = sample(&level.dx, x, y);
+                let iy = sample(&level.dy, x, y);
+                gxx += ix * ix;
+                gyy += iy * iy;
+                gxy += ix * iy;
+                window.push((x, y, sample(level.image, x, y), ix, iy));
+            }
+        }
+
+        let det = gxx * gyy - gxy * gxy;
+        let half_diff = 0.5 * (gxx - gyy);
+        let min_eigenvalue = 0.5 * (gxx + gyy) - (half_diff * half_diff + gxy * gxy).sqrt();
+        if min_eigenvalue / area < config.min_eigenvalue || det.abs() < f32::EPSILON {
+            return lost;
+        }
+
+        // Newton-Raphson iterations on the image mismatch
+        let mut flow = [0.0f32; 2];
+        for _ in 0..config.max_iterations {
+            let [dx, dy] = [guess[0] + flow[0], guess[1] + flow[1]];
+            if !is_inside(next_image, [px + dx, py + dy]) {
+                return lost;
+            }
+
+            let (mut bx, mut by) = (0.0, 0.0);
+            for &(x, y, value, ix, iy) in &window {
+                let diff = value - sample(next_image, x + dx, y + dy);
+                bx += diff * ix;
+                by += diff * iy;
+            }
+            let eta = [(gyy * bx - gxy * by) / det, (gxx * by - gxy * bx) / det];
+            flow = [flow[0] + eta[0], flow[1] + eta[1]];
+            if eta[0].hypot(eta[1]) < config.epsilon {
+                break;
+            }
+        }
+
+        guess = [guess[0] + flow[0], guess[1] + flow[1]];
+        if level_idx > 0 {
+            guess = [2.0 * guess[0], 2.0 * guess[1]];
+        }
+    }
+
+    let position = [point[0] + guess[0], point[1] + guess[1]];
+    let (level, next_image) = (&prev[0], &next[0]);
+    if !is_inside(next_image, position) {
+        return lost;
+    }
+
+    let error = (-radius..=radius)
+        .flat_map(|wy| (-radius..=radius).map(move |wx| (wx as f32, wy as f32)))
+        .map(|(wx, wy)| {
+            let a = sample(level.image, point[0] + wx, point[1] + wy);
+            let b = sample(next_image, position[0] + wx, position[1] + wy);
+            (a - b).abs()
+        })
+        .sum::<f32>()
+        / area;
+
+    TrackedPoint {
+        position,
+        status: true,
+        error,
+    }
+}
+
+/// Track points from an image to the next one with the pyramidal Lucas-Kanade method.
+///
+/// The displacement of each point is estimated from the coarsest to the finest level of Gaussian
+/// pyramids of the images, by iteratively aligning the window around the point in the previous
+/// image with the next image (Bouguet). The image derivatives are computed with the sobel
+/// operator and the pixels outside of the images replicate the border pixels.
+///
+/// # Arguments
+///
+/// * `prev` - The previous grayscale image with shape (H, W).
+/// * `next` - The next grayscale image with shape (H, W).
+/// * `points` - The x and y coordinates of the points in the previous image.
+/// * `config` - The configuration of the tracker.
+///
+/// # Returns
+///
+/// The tracked points, in the order of the input points.
+///
+/// # Errors
+///
+/// Returns an error if the images have different sizes or if the window size is even.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::Image;
+/// use kornia_imgproc::optical_flow::{track_lucas_kanade, LucasKanadeConfig};
+///
+/// // a smooth pattern moving by one pixel to the right
+/// let pattern = |dx: f32| {
+///     Image::<f32, 1>::new(
+///         [48, 48].into(),
+///         (0..48 * 48)
+///             .map(|i| {
+///                 let (x, y) = ((i % 48) as f32 - dx, (i / 48) as f32);
+///                 0.5 + 0.25 * ((x / 4.0).sin() + (y / 5.0).cos())
+///             })
+///             .collect(),
+///     )
+///     .unwrap()
+/// };
+///
+/// let tracked = track_lucas_kanade(
+///     &pattern(0.0),
+///     &pattern(1.0),
+///     &[[24.0, 24.0]],
+///     &LucasKanadeConfig::default(),
+/// )
+/// .unwrap();
+///
+/// assert!(tracked[0].status);
+/// assert!((tracked[0].position[0] - 25.0).abs() < 0.05);
+/// assert!((tracked[0].position[1] - 24.0).abs() < 0.05);
+/// ```
+pub fn track_lucas_kanade(
+    prev: &Image<f32, 1>,
+    next: &Image<f32, 1>,
+    points: &[[f32; 2]],
+    config: &LucasKanadeConfig,
+) -> Result<Vec<TrackedPoint>, ImageError> {
+    if prev.size() != next.size() {
+        return Err(ImageError::InvalidImageSize(
+            prev.cols(),
+            prev.rows(),
+            next.cols(),
+            next.rows(),
+        ));
+    }
+
+    if config.window_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(
+            config.window_size,
+            config.window_size,
+        ));
+    }
+
+    if prev.cols() == 0 || prev.rows() == 0 {
+        return Ok(points
+            .iter()
+            .map(|&position| TrackedPoint {
+                position,
+                status: false,
+                error: 0.0,
+            })
+            .collect());
+    }
+
+    let num_levels = config.num_levels.max(1);
+    let prev_pyramid = GaussianPyramid::new(prev, num_levels, 2.0)?;
+    let next_pyramid = GaussianPyramid::new(next, num_levels, 2.0)?;
+
+    // the sobel operator scales the derivatives by 8
+    let prev_levels = prev_pyramid
+        .levels()
+        .iter()
+        .map(|image| {
+            let mut dx = Image::from_size_val(image.size(), 0.0)?;
+            let mut dy = Image::from_size_val(image.size(), 0.0)?;
+            sobel_gradients(image, &mut dx, &mut dy, 3, BorderType::Replicate)?;
+            for d in dx.as_slice_mut().iter_mut().chain(dy.as_slice_mut()) {
+                *d /= 8.0;
+            }
+            Ok(GradientLevel { image, dx, dy })
+        })
+        .collect::<Result<Vec<_>, ImageError>>()?;
+
+    Ok(points
+        .par_iter()
+        .map(|&point| track_point(&prev_levels, next_pyramid.levels(), point, config))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+
+    /// A smooth textured image translated by a displacement.
+    fn texture(size: usize, shift: [f32; 2]) -> Image<f32, 1> {
+        Image::new(
+            ImageSize {
+                width: size,
+                height: size,
+            },
+            (0..size * size)
+                .map(|i| {
+                    let x = (i % size) as f32 - shift[0];
+                    let y = (i / size) as f32 - shift[1];
+                    0.5 + 0.2 * (x / 5.0).sin() * (y / 7.0).cos() + 0.1 * ((x + y) / 3.0).sin()
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn lucas_kanade_subpixel_shift() -> Result<(), ImageError> {
+        let prev = texture(64, [0.0, 0.0]);
+        let next = texture(64, [1.5, -0.7]);
+        let points = [[20.0, 20.0], [32.5, 30.0], [40.0, 44.0]];
+
+        let config = LucasKanadeConfig {
+            num_levels: 1,
+            ..Default::default()
+        };
+        let tracked = track_lucas_kanade(&prev, &next, &points, &config)?;
+        for (t, p) in tracked.iter().zip(points) {
+            assert!(t.status);
+            assert!((t.position[0] - p[0] - 1.5).abs() < 0.05, "{t:?}");
+            assert!((t.position[1] - p[1] + 0.7).abs() < 0.05, "{t:?}");
+            assert!(t.error < 0.01);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn lucas_kanade_pyramid_large_shift() -> Result<(), ImageError> {
+        let prev = texture(96, [0.0, 0.0]);
+        let next = texture(96, [6.0, 4.0]);
+        let points = [[40.0, 40.0], [48.0, 56.0]];
+
+        let tracked = track_lucas_kanade(&prev, &next, &points, &LucasKanadeConfig::default())?;
+        for (t, p) in tracked.iter().zip(points) {
+            assert!(t.status);
+            assert!((t.position[0] - p[0] - 6.0).abs() < 0.1, "{t:?}");
+            assert!((t.position[1] - p[1] - 4.0).abs() < 0.1, "{t:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn lucas_kanade_lost_points() -> Result<(), ImageError> {
+        // a uniform region cannot be tracked
+        let flat = Image::<f32, 1>::from_size_val([32, 32].into(), 0.5)?;
+        let tracked =
+            track_lucas_kanade(&flat, &flat, &[[16.0, 16.0]], &LucasKanadeConfig::default())?;
+        assert!(!tracked[0].status);
+
+        // a point leaving the image
+        let prev = texture(64, [0.0, 0.0]);
+        let next = texture(64, [3.0, 0.0]);
+        let config = LucasKanadeConfig {
+            num_levels: 2,
+            ..Default::default()
+        };
+        let tracked = track_lucas_kanade(&prev, &next, &[[62.0, 30.0], [30.0, 30.0]], &config)?;
+        assert!(!tracked[0].status);
+        assert!(tracked[1].status);
+
+        let small = Image::<f32, 1>::from_size_val([16, 16].into(), 0.5)?;
+        assert!(track_lucas_kanade(&prev, &small, &[], &config).is_err());
+        let config = LucasKanadeConfig {
+            window_size: 4,
+            ..Default::default()
+        };
+        assert!(track_lucas_kanade(&prev, &next, &[], &config).is_err());
+
+        Ok(())
+    }
+}
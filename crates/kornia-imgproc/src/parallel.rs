@@ -80,11 +80,11 @@ pub fn par_iter_rows_val_two<T1, const C1: usize, T2, const C2: usize, T3, const
 }
 
 /// Apply a function to each pixel for grid sampling in parallel.
-pub fn par_iter_rows_resample<const C: usize>(
-    dst: &mut Image<f32, C>,
+pub fn par_iter_rows_resample<T: Send + Sync, const C: usize>(
+    dst: &mut Image<T, C>,
     map_x: &Tensor2<f32, CpuAllocator>,
     map_y: &Tensor2<f32, CpuAllocator>,
-    f: impl Fn(&f32, &f32, &mut [f32]) + Send + Sync,
+    f: impl Fn(&f32, &f32, &mut [T]) + Send + Sync,
 ) {
     let cols = dst.cols();
     let dst_slice = dst.as_slice_mut();
@@ -104,3 +104,20 @@ pub fn par_iter_rows_resample<const C: usize>(
                 });
         });
 }
+
+/// Apply a function to each row of the image in parallel.
+///
+/// # Arguments
+///
+/// * `dst` - The output image.
+/// * `f` - The function to apply to each row, with the index of the row.
+pub fn par_iter_rows_indexed<T: Send + Sync, const C: usize>(
+    dst: &mut Image<T, C>,
+    f: impl Fn(usize, &mut [T]) + Send + Sync,
+) {
+    let cols = dst.cols();
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(C * cols)
+        .enumerate()
+        .for_each(|(row, dst_chunk)| f(row, dst_chunk));
+}
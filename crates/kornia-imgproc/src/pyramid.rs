@@ -0,0 +1,626 @@
+use crate::interpolation::BorderType;
+use kornia_image::{Image, ImageError, ImageSize};
+use rayon::{
+    iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+
+/// The binomial approximation of the Gaussian kernel used by [`pyr_down`] and [`pyr_up`].
+const PYR_KERNEL: [f32; 5] = [1.0, 4.0, 6.0, 4.0, 1.0];
+
+/// The source samples contributing to an output sample along an axis, with their weights.
+type Taps = Vec<(usize, f32)>;
+
+/// Map a coordinate to the image mirroring it at the borders.
+#[inline]
+fn reflect(i: isize, len: usize) -> usize {
+    BorderType::Reflect101.map_index(i, len).unwrap_or(0)
+}
+
+/// The taps of the blur and subsampling by 2 of [`pyr_down`].
+fn pyr_down_taps(dst_len: usize, src_len: usize) -> Vec<Taps> {
+    (0..dst_len)
+        .map(|x| {
+            PYR_KERNEL
+                .iter()
+                .enumerate()
+                .map(|(k, w)| (reflect(2 * x as isize + k as isize - 2, src_len), w / 16.0))
+                .collect()
+        })
+        .collect()
+}
+
+/// The taps of the upsampling by 2 of [`pyr_up`].
+///
+/// The source is interleaved with zeros and blurred with twice the kernel of [`pyr_down`], so the
+/// even samples take the 3 odd taps of the kernel and the odd samples its 2 even taps.
+fn pyr_up_taps(dst_len: usize, src_len: usize) -> Vec<Taps> {
+    (0..dst_len)
+        .map(|x| {
+            let m = (x / 2) as isize;
+            if x % 2 == 0 {
+                vec![
+                    (reflect(m - 1, src_len), 1.0 / 8.0),
+                    (reflect(m, src_len), 6.0 / 8.0),
+                    (reflect(m + 1, src_len), 1.0 / 8.0),
+                ]
+            } else {
+                vec![(reflect(m, src_len), 0.5), (reflect(m + 1, src_len), 0.5)]
+            }
+        })
+        .collect()
+}
+
+/// The taps of a Gaussian blur and downsampling by an arbitrary factor.
+///
+/// The standard deviation of the Gaussian is `scale_factor / 3`, so the kernel covers the
+/// footprint of the output pixel in the source.
+fn gaussian_taps(dst_len: usize, src_len: usize, scale_factor: f32) -> Vec<Taps> {
+    let ratio = src_len as f32 / dst_len as f32;
+    let sigma = 2.0 * scale_factor / 6.0;
+    let radius = (3.0 * sigma).ceil() as isize;
+    (0..dst_len)
+        .map(|x| {
+            // align the pixel centers of both images
+            let center = (x as f32 + 0.5) * ratio - 0.5;
+            let start = center.floor() as isize - radius;
+            let end = center.ceil() as isize + radius;
+            let mut taps = (start..=end)
+                .map(|i| {
+                    let d = (i as f32 - center) / sigma;
+                    (reflect(i, src_len), (-0.5 * d * d).exp())
+                })
+                .collect::<Taps>();
+            let sum = taps.iter().map(|(_, w)| w).sum::<f32>();
+            taps.iter_mut().for_each(|(_, w)| *w /= sum);
+            taps
+        })
+        .collect()
+}
+
+/// The taps of a linear upsampling by an arbitrary factor.
+fn linear_taps(dst_len: usize, src_len: usize) -> Vec<Taps> {
+    let ratio = src_len as f32 / dst_len as f32;
+    (0..dst_len)
+        .map(|x| {
+            let pos = ((x as f32 + 0.5) * ratio - 0.5).clamp(0.0, (src_len - 1) as f32);
+            let i = pos.floor() as usize;
+            let frac = pos - i as f32;
+            vec![(i, 1.0 - frac), ((i + 1).min(src_len - 1), frac)]
+        })
+        .collect()
+}
+
+/// Resample an image with a horizontal and a vertical pass.
+fn resample_separable<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    taps_x: &[Taps],
+    taps_y: &[Taps],
+) {
+    let src_stride = src.cols() * C;
+    let dst_stride = dst.cols() * C;
+    if src_stride == 0 || dst_stride == 0 {
+        return;
+    }
+
+    // horizontal pass on all the rows of the source
+    let mut tmp = vec![0.0; src.rows() * dst_stride];
+    tmp.par_chunks_exact_mut(dst_stride)
+        .zip(src.as_slice().par_chunks_exact(src_stride))
+        .for_each(|(tmp_row, src_row)| {
+            for (tmp_pixel, taps) in tmp_row.chunks_exact_mut(C).zip(taps_x) {
+                for &(i, w) in taps {
+                    let src_pixel = &src_row[i * C..(i + 1) * C];
+                    for (t, s) in tmp_pixel.iter_mut().zip(src_pixel) {
+                        *t += w * s;
+                    }
+                }
+            }
+        });
+
+    // vertical pass
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(dst_stride)
+        .zip(taps_y.par_iter())
+        .for_each(|(dst_row, taps)| {
+            dst_row.fill(0.0);
+            for &(r, w) in taps {
+                let tmp_row = &tmp[r * dst_stride..(r + 1) * dst_stride];
+                for (d, t) in dst_row.iter_mut().zip(tmp_row) {
+                    *d += w * t;
+                }
+            }
+        });
+}
+
+/// Blur and downsample an image by a factor of 2.
+///
+/// The image is blurred with the 5x5 kernel [1, 4, 6, 4, 1]^T [1, 4, 6, 4, 1] / 256 and the even
+/// rows and columns are kept, like `cv2.pyrDown`. The borders are mirrored excluding the border
+/// pixels.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `dst` - The output image.
+///
+/// PRECONDITION: the output image must have half the size of the input image, rounded up.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::pyramid::pyr_down;
+///
+/// let image = Image::<f32, 1>::from_size_val(
+///     ImageSize {
+///         width: 5,
+///         height: 4,
+///     },
+///     1.0,
+/// )
+/// .unwrap();
+///
+/// let mut down = Image::<f32, 1>::from_size_val([3, 2].into(), 0.0).unwrap();
+///
+/// pyr_down(&image, &mut down).unwrap();
+/// assert_eq!(down.as_slice(), &[1.0; 6]);
+/// ```
+pub fn pyr_down<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+) -> Result<(), ImageError> {
+    if dst.cols() != src.cols().div_ceil(2) || dst.rows() != src.rows().div_ceil(2) {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let taps_x = pyr_down_taps(dst.cols(), src.cols());
+    let taps_y = pyr_down_taps(dst.rows(), src.rows());
+    resample_separable(src, dst, &taps_x, &taps_y);
+
+    Ok(())
+}
+
+/// Upsample an image by a factor of 2 and blur it.
+///
+/// The image is interleaved with zeros and blurred with 4 times the kernel of [`pyr_down`], like
+/// `cv2.pyrUp`. The output can be one pixel smaller than twice the input along each axis, so an
+/// image of odd size can be recovered from its [`pyr_down`] output.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `dst` - The output image.
+///
+/// PRECONDITION: half the size of the output image, rounded up, must be the size of the input.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_imgproc::pyramid::pyr_up;
+///
+/// let image = Image::<f32, 1>::from_size_val(
+///     ImageSize {
+///         width: 3,
+///         height: 2,
+///     },
+///     1.0,
+/// )
+/// .unwrap();
+///
+/// let mut up = Image::<f32, 1>::from_size_val([5, 4].into(), 0.0).unwrap();
+///
+/// pyr_up(&image, &mut up).unwrap();
+/// assert_eq!(up.as_slice(), &[1.0; 20]);
+/// ```
+pub fn pyr_up<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+) -> Result<(), ImageError> {
+    if dst.cols().div_ceil(2) != src.cols() || dst.rows().div_ceil(2) != src.rows() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let taps_x = pyr_up_taps(dst.cols(), src.cols());
+    let taps_y = pyr_up_taps(dst.rows(), src.rows());
+    resample_separable(src, dst, &taps_x, &taps_y);
+
+    Ok(())
+}
+
+/// The length of an axis reduced by the scale factor of a pyramid, rounded up.
+fn reduced_len(len: usize, scale_factor: f32) -> usize {
+    (len as f32 / scale_factor).ceil() as usize
+}
+
+/// The size of the next level of a pyramid, or None if the image cannot be reduced further.
+fn reduced_size(size: ImageSize, scale_factor: f32) -> Option<ImageSize> {
+    if size.width <= 1 && size.height <= 1 {
+        return None;
+    }
+    let reduce = |len: usize| reduced_len(len, scale_factor).max(1);
+    Some(ImageSize {
+        width: reduce(size.width),
+        height: reduce(size.height),
+    })
+}
+
+/// Blur and downsample an image by the scale factor of a pyramid.
+fn reduce<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    scale_factor: f32,
+) -> Result<(), ImageError> {
+    if scale_factor == 2.0 {
+        return pyr_down(src, dst);
+    }
+    if dst.cols() != reduced_len(src.cols(), scale_factor)
+        || dst.rows() != reduced_len(src.rows(), scale_factor)
+    {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let taps_x = gaussian_taps(dst.cols(), src.cols(), scale_factor);
+    let taps_y = gaussian_taps(dst.rows(), src.rows(), scale_factor);
+    resample_separable(src, dst, &taps_x, &taps_y);
+    Ok(())
+}
+
+/// Upsample a level of a pyramid to the size of the previous level.
+fn expand<const C: usize>(
+    src: &Image<f32, C>,
+    dst: &mut Image<f32, C>,
+    scale_factor: f32,
+) -> Result<(), ImageError> {
+    if scale_factor == 2.0 {
+        return pyr_up(src, dst);
+    }
+    if reduced_len(dst.cols(), scale_factor) != src.cols()
+        || reduced_len(dst.rows(), scale_factor) != src.rows()
+    {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let taps_x = linear_taps(dst.cols(), src.cols());
+    let taps_y = linear_taps(dst.rows(), src.rows());
+    resample_separable(src, dst, &taps_x, &taps_y);
+    Ok(())
+}
+
+/// A Gaussian pyramid: the image followed by successively blurred and downsampled copies.
+///
+/// With a scale factor of 2 the levels are computed with [`pyr_down`]. Other factors blur the
+/// image with a Gaussian of standard deviation `scale_factor / 3` before resampling it.
+pub struct GaussianPyramid<const C: usize> {
+    levels: Vec<Image<f32, C>>,
+    scale_factor: f32,
+}
+
+impl<const C: usize> GaussianPyramid<C> {
+    /// Build the Gaussian pyramid of an image.
+    ///
+    /// The construction stops early if the image is reduced to a single pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The input image, which becomes the first level of the pyramid.
+    /// * `num_levels` - The maximum number of levels, including the input image.
+    /// * `scale_factor` - The ratio between the sizes of two consecutive levels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scale factor is not a finite number greater than 1, or if the
+    /// image is empty along one axis only.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_image::Image;
+    /// use kornia_imgproc::pyramid::GaussianPyramid;
+    ///
+    /// let image = Image::<f32, 3>::from_size_val([64, 48].into(), 0.5).unwrap();
+    ///
+    /// let pyramid = GaussianPyramid::new(&image, 3, 2.0).unwrap();
+    /// assert_eq!(pyramid.num_levels(), 3);
+    /// assert_eq!(pyramid.levels()[2].size(), [16, 12].into());
+    /// ```
+    pub fn new(
+        image: &Image<f32, C>,
+        num_levels: usize,
+        scale_factor: f32,
+    ) -> Result<Self, ImageError> {
+        if !scale_factor.is_finite() || scale_factor <= 1.0 {
+            return Err(ImageError::InvalidScaleFactor(scale_factor));
+        }
+
+        let mut levels = vec![image.clone()];
+        while levels.len() < num_levels {
+            let Some(last) = levels.last() else {
+                break;
+            };
+            let Some(size) = reduced_size(last.size(), scale_factor) else {
+                break;
+            };
+            let mut level = Image::from_size_val(size, 0.0)?;
+            reduce(last, &mut level, scale_factor)?;
+            levels.push(level);
+        }
+
+        Ok(Self {
+            levels,
+            scale_factor,
+        })
+    }
+
+    /// The levels of the pyramid, from the input image to the coarsest level.
+    pub fn levels(&self) -> &[Image<f32, C>] {
+        &self.levels
+    }
+
+    /// The number of levels of the pyramid.
+    pub fn num_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// The ratio between the sizes of two consecutive levels.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+}
+
+/// A Laplacian pyramid: the band-pass details of each level of a [`GaussianPyramid`].
+///
+/// Each level is the difference between a Gaussian level and the upsampled next level, the last
+/// level is the coarsest Gaussian level. The image is recovered with [`Self::reconstruct`].
+pub struct LaplacianPyramid<const C: usize> {
+    levels: Vec<Image<f32, C>>,
+    scale_factor: f32,
+}
+
+impl<const C: usize> LaplacianPyramid<C> {
+    /// Build the Laplacian pyramid of an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The input image.
+    /// * `num_levels` - The maximum number of levels, including the coarse residual.
+    /// * `scale_factor` - The ratio between the sizes of two consecutive levels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scale factor is not a finite number greater than 1, or if the
+    /// image is empty along one axis only.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_image::Image;
+    /// use kornia_imgproc::pyramid::LaplacianPyramid;
+    ///
+    /// let image = Image::<f32, 1>::new([4, 1].into(), vec![0.0, 1.0, 0.5, 0.25]).unwrap();
+    ///
+    /// let pyramid = LaplacianPyramid::new(&image, 3, 2.0).unwrap();
+    /// let reconstructed = pyramid.reconstruct().unwrap();
+    ///
+    /// for (a, b) in reconstructed.as_slice().iter().zip(image.as_slice()) {
+    ///     assert!((a - b).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn new(
+        image: &Image<f32, C>,
+        num_levels: usize,
+        scale_factor: f32,
+    ) -> Result<Self, ImageError> {
+        Self::from_gaussian(&GaussianPyramid::new(image, num_levels, scale_factor)?)
+    }
+
+    /// Build the Laplacian pyramid from the levels of a Gaussian pyramid.
+    ///
+    /// # Arguments
+    ///
+    /// * `gaussian` - The Gaussian pyramid of the image.
+    pub fn from_gaussian(gaussian: &GaussianPyramid<C>) -> Result<Self, ImageError> {
+        let scale_factor = gaussian.scale_factor();
+        let mut levels = Vec::with_capacity(gaussian.num_levels());
+
+        for pair in gaussian.levels().windows(2) {
+            let (fine, coarse) = (&pair[0], &pair[1]);
+            let mut detail = Image::from_size_val(fine.size(), 0.0)?;
+            expand(coarse, &mut detail, scale_factor)?;
+            detail
+                .as_slice_mut()
+                .iter_mut()
+                .zip(fine.as_slice())
+                .for_each(|(d, f)| *d = f - *d);
+            levels.push(detail);
+        }
+        if let Some(residual) = gaussian.levels().last() {
+            levels.push(residual.clone());
+        }
+
+        Ok(Self {
+            levels,
+            scale_factor,
+        })
+    }
+
+    /// The levels of the pyramid, from the finest details to the coarse residual.
+    pub fn levels(&self) -> &[Image<f32, C>] {
+        &self.levels
+    }
+
+    /// The number of levels of the pyramid.
+    pub fn num_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// The ratio between the sizes of two consecutive levels.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Reconstruct the image from the levels of the pyramid.
+    ///
+    /// The coarse residual is upsampled and the details of each level are added back, which
+    /// recovers the input image up to the floating point rounding.
+    ///
+    /// # Returns
+    ///
+    /// The image with the size of the finest level.
+    pub fn reconstruct(&self) -> Result<Image<f32, C>, ImageError> {
+        let Some((residual, details)) = self.levels.split_last() else {
+            return Err(ImageError::ImageDataNotInitialized);
+        };
+
+        let mut image = residual.clone();
+        for detail in details.iter().rev() {
+            let mut up = Image::from_size_val(detail.size(), 0.0)?;
+            expand(&image, &mut up, self.scale_factor)?;
+            up.as_slice_mut()
+                .iter_mut()
+                .zip(detail.as_slice())
+                .for_each(|(u, d)| *u += d);
+            image = up;
+        }
+
+        Ok(image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GaussianPyramid, LaplacianPyramid};
+    use kornia_image::{Image, ImageError, ImageSize};
+
+    fn ramp(width: usize, height: usize) -> Result<Image<f32, 2>, ImageError> {
+        let data = (0..width * height)
+            .flat_map(|i| {
+                let (x, y) = ((i % width) as f32, (i / width) as f32);
+                [x * 0.1 + (y * 0.7).sin(), (x * y * 0.05).cos()]
+            })
+            .collect();
+        Image::new(ImageSize { width, height }, data)
+    }
+
+    #[test]
+    fn pyr_down_up() -> Result<(), ImageError> {
+        let image = Image::<f32, 1>::new(
+            ImageSize {
+                width: 4,
+                height: 1,
+            },
+            vec![0.0, 16.0, 0.0, 0.0],
+        )?;
+
+        let mut down = Image::<f32, 1>::from_size_val([2, 1].into(), 0.0)?;
+        super::pyr_down(&image, &mut down)?;
+        // the left border mirrors the 16 at -1
+        assert_eq!(down.as_slice(), &[8.0, 4.0]);
+
+        let mut up = Image::<f32, 1>::from_size_val([4, 1].into(), 0.0)?;
+        super::pyr_up(&down, &mut up)?;
+        assert_eq!(up.as_slice(), &[7.0, 6.0, 5.0, 6.0]);
+
+        let mut wrong = Image::<f32, 1>::from_size_val([3, 1].into(), 0.0)?;
+        assert!(matches!(
+            super::pyr_down(&image, &mut wrong),
+            Err(ImageError::InvalidImageSize(4, 1, 3, 1))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn gaussian_pyramid_sizes() -> Result<(), ImageError> {
+        let image = ramp(13, 6)?;
+
+        let pyramid = GaussianPyramid::new(&image, 10, 2.0)?;
+        let sizes = pyramid
+            .levels()
+            .iter()
+            .map(|l| (l.cols(), l.rows()))
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [(13, 6), (7, 3), (4, 2), (2, 1), (1, 1)]);
+
+        let pyramid = GaussianPyramid::new(&image, 3, 1.5)?;
+        let sizes = pyramid
+            .levels()
+            .iter()
+            .map(|l| (l.cols(), l.rows()))
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [(13, 6), (9, 4), (6, 3)]);
+
+        for scale_factor in [1.0, f32::INFINITY, f32::NAN] {
+            assert!(matches!(
+                GaussianPyramid::new(&image, 3, scale_factor),
+                Err(ImageError::InvalidScaleFactor(_))
+            ));
+        }
+
+        // an empty axis cannot be reduced to a single pixel
+        for scale_factor in [2.0, 1.5] {
+            let empty = Image::<f32, 1>::from_size_val([3, 0].into(), 0.0)?;
+            assert!(matches!(
+                GaussianPyramid::new(&empty, 3, scale_factor),
+                Err(ImageError::InvalidImageSize(..))
+            ));
+            assert!(matches!(
+                LaplacianPyramid::new(&empty, 3, scale_factor),
+                Err(ImageError::InvalidImageSize(..))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn gaussian_pyramid_constant() -> Result<(), ImageError> {
+        let image = Image::<f32, 3>::from_size_val([21, 17].into(), 0.25)?;
+        for scale_factor in [2.0, 1.5, 3.0] {
+            let pyramid = GaussianPyramid::new(&image, 4, scale_factor)?;
+            for level in pyramid.levels() {
+                for v in level.as_slice() {
+                    assert!((v - 0.25).abs() < 1e-6, "{scale_factor}: {v}");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn laplacian_pyramid_reconstruct() -> Result<(), ImageError> {
+        let image = ramp(31, 18)?;
+        for scale_factor in [2.0, 1.5] {
+            let pyramid = LaplacianPyramid::new(&image, 4, scale_factor)?;
+            assert_eq!(pyramid.num_levels(), 4);
+            assert_eq!(pyramid.levels()[0].size(), image.size());
+
+            let reconstructed = pyramid.reconstruct()?;
+            assert_eq!(reconstructed.size(), image.size());
+            for (a, b) in reconstructed.as_slice().iter().zip(image.as_slice()) {
+                assert!((a - b).abs() < 1e-5, "{scale_factor}: {a} != {b}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,35 @@
 use crate::{
-    interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode},
+    interpolation::{
+        grid::meshgrid_from_fn,
+        interpolate_pixel,
+        kernels::{cubic, lanczos, CUBIC_SUPPORT, LANCZOS_SUPPORT},
+        InterpolationMode, InterpolationPixel,
+    },
     parallel,
 };
 use fast_image_resize::{self as fr};
 use kornia_image::{Image, ImageError};
+use rayon::{
+    iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
 
 /// Resize an image to a new size.
 ///
 /// The function resizes an image to a new size using the specified interpolation mode.
-/// It supports any number of channels and data types.
+/// It supports any number of channels and the pixel types implementing [`InterpolationPixel`],
+/// i.e. `u8`, `u16`, `f32`, and `f16` and `bf16` with the `half` feature.
+///
+/// The integer pixel types are rounded and saturated.
+///
+/// NOTE: the modes do not share the same sampling convention.
+///
+/// * The nearest and bilinear modes align the corners of the images: the output pixel `x` samples
+///   the input at `x * (src_width - 1) / (dst_width - 1)`, so the first and last pixels are kept.
+///   A single output pixel samples the first input pixel.
+/// * The bicubic, Lanczos and area modes align the pixel centers: the output pixel `x` is centered
+///   on the input at `(x + 0.5) * src_width / dst_width - 0.5`, like OpenCV. They are separable
+///   resampling filters, which are widened when downsampling to avoid aliasing.
 ///
 /// # Arguments
 ///
@@ -20,6 +41,10 @@ use kornia_image::{Image, ImageError};
 ///
 /// The resized image with the new size.
 ///
+/// # Errors
+///
+/// Returns an error if the input image is empty and the output image is not.
+///
 /// # Example
 ///
 /// ```
@@ -54,13 +79,11 @@ use kornia_image::{Image, ImageError};
 /// assert_eq!(image_resized.size().width, 2);
 /// assert_eq!(image_resized.size().height, 3);
 /// ```
-pub fn resize_native<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+pub fn resize_native<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
     interpolation: InterpolationMode,
-) -> Result<(), ImageError>
-where
-{
+) -> Result<(), ImageError> {
     // check if the input and output images have the same size
     // and copy the input image to the output image if they have the same size
     if src.size() == dst.size() {
@@ -68,11 +91,47 @@ where
         return Ok(());
     }
 
+    // an empty output has nothing to sample, an empty input cannot be sampled
+    if dst.cols() == 0 || dst.rows() == 0 {
+        return Ok(());
+    }
+    if src.cols() == 0 || src.rows() == 0 {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    match interpolation {
+        InterpolationMode::Nearest | InterpolationMode::Bilinear => {}
+        InterpolationMode::Bicubic => {
+            return resize_separable(src, dst, |src_len, dst_len| {
+                kernel_taps(src_len, dst_len, cubic, CUBIC_SUPPORT)
+            });
+        }
+        InterpolationMode::Lanczos => {
+            return resize_separable(src, dst, |src_len, dst_len| {
+                kernel_taps(src_len, dst_len, lanczos, LANCZOS_SUPPORT)
+            });
+        }
+        InterpolationMode::Area => return resize_separable(src, dst, area_taps),
+    }
+
     // create a grid of x and y coordinates for the output image
     // and interpolate the values from the input image.
+    // a single output pixel samples the first input pixel
+    let step = |src_len: usize, dst_len: usize| {
+        if dst_len > 1 {
+            (src_len - 1) as f32 / (dst_len - 1) as f32
+        } else {
+            0.0
+        }
+    };
     let (dst_rows, dst_cols) = (dst.rows(), dst.cols());
-    let step_x = (src.cols() - 1) as f32 / (dst.cols() - 1) as f32;
-    let step_y = (src.rows() - 1) as f32 / (dst.rows() - 1) as f32;
+    let step_x = step(src.cols(), dst_cols);
+    let step_y = step(src.rows(), dst_rows);
     let (map_x, map_y) = meshgrid_from_fn(dst_cols, dst_rows, |x, y| {
         Ok((x as f32 * step_x, y as f32 * step_y))
     })?;
@@ -88,14 +147,168 @@ where
     Ok(())
 }
 
+/// The input pixels contributing to an output pixel along one axis, with their weights.
+struct Taps {
+    start: usize,
+    weights: Vec<f32>,
+}
+
+/// Normalize the weights so that they sum to one.
+fn normalized(start: usize, mut weights: Vec<f32>) -> Taps {
+    let sum = weights.iter().sum::<f32>();
+    if sum != 0.0 {
+        weights.iter_mut().for_each(|w| *w /= sum);
+    }
+    Taps { start, weights }
+}
+
+/// Compute the taps of a convolution kernel, truncated at the borders of the input.
+fn kernel_taps(src_len: usize, dst_len: usize, kernel: fn(f32) -> f32, support: f32) -> Vec<Taps> {
+    let scale = src_len as f32 / dst_len as f32;
+    // widen the kernel when downsampling to filter out the high frequencies
+    let filter_scale = scale.max(1.0);
+    let radius = support * filter_scale;
+
+    (0..dst_len)
+        .map(|i| {
+            let center = (i as f32 + 0.5) * scale;
+            let start = (center - radius).floor().max(0.0) as usize;
+            let end = ((center + radius).ceil() as usize).min(src_len);
+            let weights = (start..end)
+                .map(|j| kernel((j as f32 + 0.5 - center) / filter_scale))
+                .collect();
+            normalized(start, weights)
+        })
+        .collect()
+}
+
+/// Compute the taps weighting the input pixels by their overlap with the output pixel.
+fn area_taps(src_len: usize, dst_len: usize) -> Vec<Taps> {
+    let scale = src_len as f32 / dst_len as f32;
+
+    (0..dst_len)
+        .map(|i| {
+            let (x0, x1) = (i as f32 * scale, (i + 1) as f32 * scale);
+            let start = (x0.floor() as usize).min(src_len - 1);
+            let end = (x1.ceil() as usize).clamp(start + 1, src_len);
+            let weights = (start..end)
+                .map(|j| (x1.min((j + 1) as f32) - x0.max(j as f32)).max(0.0))
+                .collect();
+            normalized(start, weights)
+        })
+        .collect()
+}
+
+/// Resize an image with a separable filter, first along the rows and then along the columns.
+fn resize_separable<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
+    taps: impl Fn(usize, usize) -> Vec<Taps>,
+) -> Result<(), ImageError> {
+    let (src_cols, src_rows) = (src.cols(), src.rows());
+    let (dst_cols, dst_rows) = (dst.cols(), dst.rows());
+    let (taps_x, taps_y) = (taps(src_cols, dst_cols), taps(src_rows, dst_rows));
+
+    // horizontal pass into an intermediate image with the output width
+    let mut tmp = vec![0f32; src_rows * dst_cols * C];
+    tmp.par_chunks_exact_mut(dst_cols * C)
+        .zip(src.as_slice().par_chunks_exact(src_cols * C))
+        .for_each(|(tmp_row, src_row)| {
+            for (tmp_pixel, taps) in tmp_row.chunks_exact_mut(C).zip(&taps_x) {
+                let src_pixels = src_row[taps.start * C..].chunks_exact(C);
+                for (w, src_pixel) in taps.weights.iter().zip(src_pixels) {
+                    for (t, s) in tmp_pixel.iter_mut().zip(src_pixel) {
+                        *t += w * s.to_f32();
+                    }
+                }
+            }
+        });
+
+    // vertical pass into the output image
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(dst_cols * C)
+        .zip(taps_y.par_iter())
+        .for_each(|(dst_row, taps)| {
+            let mut acc = vec![0f32; dst_cols * C];
+            let tmp_rows = tmp[taps.start * dst_cols * C..].chunks_exact(dst_cols * C);
+            for (w, tmp_row) in taps.weights.iter().zip(tmp_rows) {
+                for (a, t) in acc.iter_mut().zip(tmp_row) {
+                    *a += w * t;
+                }
+            }
+            for (d, a) in dst_row.iter_mut().zip(acc) {
+                *d = T::from_f32(a);
+            }
+        });
+
+    Ok(())
+}
+
+/// A pixel type supported by [`resize_fast`].
+pub trait FastResizePixel: Copy {
+    /// Returns the [fast_image_resize] pixel type of an image with the given number of channels.
+    fn pixel_type(channels: usize) -> Option<fr::PixelType>;
+}
+
+impl FastResizePixel for u8 {
+    fn pixel_type(channels: usize) -> Option<fr::PixelType> {
+        match channels {
+            1 => Some(fr::PixelType::U8),
+            2 => Some(fr::PixelType::U8x2),
+            3 => Some(fr::PixelType::U8x3),
+            4 => Some(fr::PixelType::U8x4),
+            _ => None,
+        }
+    }
+}
+
+impl FastResizePixel for u16 {
+    fn pixel_type(channels: usize) -> Option<fr::PixelType> {
+        match channels {
+            1 => Some(fr::PixelType::U16),
+            2 => Some(fr::PixelType::U16x2),
+            3 => Some(fr::PixelType::U16x3),
+            4 => Some(fr::PixelType::U16x4),
+            _ => None,
+        }
+    }
+}
+
+impl FastResizePixel for f32 {
+    fn pixel_type(channels: usize) -> Option<fr::PixelType> {
+        match channels {
+            1 => Some(fr::PixelType::F32),
+            2 => Some(fr::PixelType::F32x2),
+            3 => Some(fr::PixelType::F32x3),
+            4 => Some(fr::PixelType::F32x4),
+            _ => None,
+        }
+    }
+}
+
+/// Reinterpret a slice of pixel values as bytes.
+fn as_bytes<T: FastResizePixel>(data: &[T]) -> &[u8] {
+    // SAFETY: the pixel types are plain numbers without padding
+    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data)) }
+}
+
+/// Reinterpret a mutable slice of pixel values as bytes.
+fn as_bytes_mut<T: FastResizePixel>(data: &mut [T]) -> &mut [u8] {
+    // SAFETY: the pixel types are plain numbers without padding and any byte pattern is valid
+    unsafe {
+        std::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, std::mem::size_of_val(data))
+    }
+}
+
 /// Resize an image to a new size using the [fast_image_resize](https://crates.io/crates/fast_image_resize) crate.
 ///
 /// The function resizes an image to a new size using the specified interpolation mode.
-/// It supports only 3-channel images and u8 data type.
+/// It supports `u8`, `u16` and `f32` images with 1 to 4 channels. The channels are resized
+/// independently, i.e. the fourth channel is not treated as alpha.
 ///
 /// # Arguments
 ///
-/// * `image` - The input image container with 3 channels.
+/// * `image` - The input image container.
 /// * `new_size` - The new size of the image.
 /// * `interpolation` - The interpolation mode to use.
 ///
@@ -140,20 +353,14 @@ where
 ///
 /// # Errors
 ///
-/// The function returns an error if the image cannot be resized.
-pub fn resize_fast(
-    src: &Image<u8, 3>,
-    dst: &mut Image<u8, 3>,
+/// The function returns an error if the image cannot be resized or the number of channels is
+/// not supported.
+pub fn resize_fast<T: FastResizePixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
     interpolation: InterpolationMode,
 ) -> Result<(), ImageError> {
-    if dst.size() != dst.size() {
-        return Err(ImageError::InvalidImageSize(
-            src.size().width,
-            src.size().height,
-            dst.size().width,
-            dst.size().height,
-        ));
-    }
+    let pixel_type = T::pixel_type(C).ok_or(ImageError::IncompatiblePixelTypes)?;
 
     // prepare the input image for the fast_image_resize crate
     let (src_cols, src_rows) = (src.cols(), src.rows());
@@ -162,10 +369,10 @@ pub fn resize_fast(
     let src_image = fr::images::ImageRef::new(
         src_cols as u32,
         src_rows as u32,
-        src.as_slice(),
-        fr::PixelType::U8x3,
+        as_bytes(src.as_slice()),
+        pixel_type,
     )
-    .map_err(|_| ImageError::InvalidChannelShape(src_data_len, src_cols * src_rows * 3))?;
+    .map_err(|_| ImageError::InvalidChannelShape(src_data_len, src_cols * src_rows * C))?;
 
     // prepare the output image for the fast_image_resize crate
     let (dst_cols, dst_rows) = (dst.cols(), dst.rows());
@@ -174,16 +381,20 @@ pub fn resize_fast(
     let mut dst_image = fr::images::Image::from_slice_u8(
         dst_cols as u32,
         dst_rows as u32,
-        dst.as_slice_mut(),
-        fr::PixelType::U8x3,
+        as_bytes_mut(dst.as_slice_mut()),
+        pixel_type,
     )
-    .map_err(|_| ImageError::InvalidChannelShape(dst_data_len, dst_cols * dst_rows * 3))?;
-
-    let mut options = fr::ResizeOptions::new();
-    options.algorithm = match interpolation {
-        InterpolationMode::Bilinear => fr::ResizeAlg::Convolution(fr::FilterType::Bilinear),
-        InterpolationMode::Nearest => fr::ResizeAlg::Nearest,
-    };
+    .map_err(|_| ImageError::InvalidChannelShape(dst_data_len, dst_cols * dst_rows * C))?;
+
+    let options = fr::ResizeOptions::new()
+        .resize_alg(match interpolation {
+            InterpolationMode::Bilinear => fr::ResizeAlg::Convolution(fr::FilterType::Bilinear),
+            InterpolationMode::Nearest => fr::ResizeAlg::Nearest,
+            InterpolationMode::Bicubic => fr::ResizeAlg::Convolution(fr::FilterType::CatmullRom),
+            InterpolationMode::Lanczos => fr::ResizeAlg::Convolution(fr::FilterType::Lanczos3),
+            InterpolationMode::Area => fr::ResizeAlg::Convolution(fr::FilterType::Box),
+        })
+        .use_alpha(false);
 
     let mut resizer = fr::Resizer::new();
     resizer
@@ -198,6 +409,38 @@ mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
     use kornia_tensor::TensorError;
 
+    #[cfg(feature = "half")]
+    #[test]
+    fn resize_smoke_f16() -> Result<(), ImageError> {
+        use half::f16;
+
+        let image = Image::<_, 1>::new(
+            ImageSize {
+                width: 3,
+                height: 3,
+            },
+            (0..9).map(|x| f16::from_f32(x as f32)).collect(),
+        )?;
+        let mut image_resized = Image::<_, 1>::from_size_val(
+            ImageSize {
+                width: 2,
+                height: 2,
+            },
+            f16::ZERO,
+        )?;
+
+        super::resize_native(
+            &image,
+            &mut image_resized,
+            super::InterpolationMode::Bilinear,
+        )?;
+
+        let expected = [0.0, 2.0, 6.0, 8.0].map(f16::from_f32);
+        assert_eq!(image_resized.as_slice(), expected);
+
+        Ok(())
+    }
+
     #[test]
     fn resize_smoke_ch3() -> Result<(), ImageError> {
         let image = Image::<_, 3>::new(
@@ -313,4 +556,191 @@ mod tests {
         assert_eq!(image_resized.size().height, 3);
         Ok(())
     }
+
+    #[test]
+    fn resize_bicubic_saturates() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+        let new_size = ImageSize {
+            width: 4,
+            height: 1,
+        };
+
+        let image = Image::<f32, 1>::new(size, vec![0.0, 255.0])?;
+        let mut image_resized = Image::<f32, 1>::from_size_val(new_size, 0.0)?;
+        super::resize_native(
+            &image,
+            &mut image_resized,
+            super::InterpolationMode::Bicubic,
+        )?;
+
+        // the Catmull-Rom kernel overshoots around the edge
+        let expected = [-22.5, 52.821_43, 202.178_57, 277.5];
+        for (a, b) in image_resized.as_slice().iter().zip(expected) {
+            assert!((a - b).abs() < 1e-3, "{a} != {b}");
+        }
+
+        let image = Image::<u8, 1>::new(size, vec![0, 255])?;
+        let mut image_resized = Image::<u8, 1>::from_size_val(new_size, 0)?;
+        super::resize_native(
+            &image,
+            &mut image_resized,
+            super::InterpolationMode::Bicubic,
+        )?;
+        assert_eq!(image_resized.as_slice(), [0, 53, 202, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_area() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 4,
+        };
+        let new_size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+
+        let image = Image::<f32, 1>::new(size, (0..16).map(|x| x as f32).collect())?;
+        let mut image_resized = Image::<f32, 1>::from_size_val(new_size, 0.0)?;
+        super::resize_native(&image, &mut image_resized, super::InterpolationMode::Area)?;
+        assert_eq!(image_resized.as_slice(), [2.5, 4.5, 10.5, 12.5]);
+
+        let image = Image::<u16, 2>::new(size, (0..32).map(|x| (x % 2) * 1000 + x / 2).collect())?;
+        let mut image_resized = Image::<u16, 2>::from_size_val(new_size, 0)?;
+        super::resize_native(&image, &mut image_resized, super::InterpolationMode::Area)?;
+        assert_eq!(
+            image_resized.as_slice(),
+            [3, 1003, 5, 1005, 11, 1011, 13, 1013]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_sampling_conventions() -> Result<(), ImageError> {
+        let image = Image::<f32, 1>::new([4, 2].into(), [0.0, 1.0, 2.0, 3.0].repeat(2))?;
+        let mut image_resized = Image::<f32, 1>::from_size_val([2, 2].into(), 0.0)?;
+
+        // the corners are aligned and kept
+        super::resize_native(
+            &image,
+            &mut image_resized,
+            super::InterpolationMode::Bilinear,
+        )?;
+        assert_eq!(image_resized.as_slice(), [0.0, 3.0, 0.0, 3.0]);
+
+        // the pixel centers are aligned, 0.5 and 2.5 in the input
+        super::resize_native(&image, &mut image_resized, super::InterpolationMode::Area)?;
+        assert_eq!(image_resized.as_slice(), [0.5, 2.5, 0.5, 2.5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_degenerate_sizes() -> Result<(), ImageError> {
+        use super::InterpolationMode;
+
+        let modes = [
+            InterpolationMode::Nearest,
+            InterpolationMode::Bilinear,
+            InterpolationMode::Bicubic,
+            InterpolationMode::Lanczos,
+            InterpolationMode::Area,
+        ];
+        let image = Image::<f32, 1>::new([4, 2].into(), [0.0, 1.0, 2.0, 3.0].repeat(2))?;
+        let empty = Image::<f32, 1>::from_size_val([0, 2].into(), 0.0)?;
+        for mode in modes {
+            // an empty input cannot be sampled
+            let mut image_resized = Image::<f32, 1>::from_size_val([2, 2].into(), 0.0)?;
+            let res = super::resize_native(&empty, &mut image_resized, mode);
+            assert!(
+                matches!(res, Err(ImageError::InvalidImageSize(0, 2, 2, 2))),
+                "{mode:?}"
+            );
+
+            // an empty output is left untouched
+            let mut image_resized = Image::<f32, 1>::from_size_val([2, 0].into(), 0.0)?;
+            super::resize_native(&image, &mut image_resized, mode)?;
+
+            // a single column is finite
+            let mut image_resized = Image::<f32, 1>::from_size_val([1, 2].into(), -1.0)?;
+            super::resize_native(&image, &mut image_resized, mode)?;
+            assert!(
+                image_resized
+                    .as_slice()
+                    .iter()
+                    .all(|x| x.is_finite() && *x >= 0.0),
+                "{mode:?}"
+            );
+        }
+
+        // the corner aligned modes sample the first column
+        let mut image_resized = Image::<f32, 1>::from_size_val([1, 2].into(), -1.0)?;
+        super::resize_native(&image, &mut image_resized, InterpolationMode::Bilinear)?;
+        assert_eq!(image_resized.as_slice(), [0.0, 0.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_lanczos_constant() -> Result<(), ImageError> {
+        let image = Image::<u16, 4>::from_size_val([6, 5].into(), 1000)?;
+        for new_size in [[4, 3], [9, 7]] {
+            let mut image_resized = Image::<u16, 4>::from_size_val(new_size.into(), 0)?;
+            super::resize_native(
+                &image,
+                &mut image_resized,
+                super::InterpolationMode::Lanczos,
+            )?;
+            assert!(image_resized.as_slice().iter().all(|&x| x == 1000));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn resize_fast_pixel_types() -> Result<(), ImageError> {
+        let new_size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+
+        let image = Image::<u16, 1>::from_size_val([6, 4].into(), 4000)?;
+        let mut image_resized = Image::<u16, 1>::from_size_val(new_size, 0)?;
+        super::resize_fast(&image, &mut image_resized, super::InterpolationMode::Area)?;
+        assert!(image_resized.as_slice().iter().all(|&x| x == 4000));
+
+        // the fourth channel is not treated as alpha
+        let image = Image::<f32, 4>::new([6, 4].into(), [0.25, 0.5, 0.75, 0.0].repeat(24))?;
+        let mut image_resized = Image::<f32, 4>::from_size_val(new_size, 1.0)?;
+        super::resize_fast(
+            &image,
+            &mut image_resized,
+            super::InterpolationMode::Lanczos,
+        )?;
+        for (a, b) in image_resized
+            .as_slice()
+            .iter()
+            .zip([0.25, 0.5, 0.75, 0.0].repeat(6))
+        {
+            assert!((a - b).abs() < 1e-6, "{a} != {b}");
+        }
+
+        let image = Image::<u8, 5>::from_size_val([6, 4].into(), 0)?;
+        let mut image_resized = Image::<u8, 5>::from_size_val(new_size, 0)?;
+        assert!(matches!(
+            super::resize_fast(
+                &image,
+                &mut image_resized,
+                super::InterpolationMode::Nearest
+            ),
+            Err(ImageError::IncompatiblePixelTypes)
+        ));
+
+        Ok(())
+    }
 }
@@ -2,7 +2,10 @@ use std::f32::consts::PI;
 
 use kornia_image::{Image, ImageError};
 
-use crate::interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode};
+use crate::interpolation::{
+    grid::meshgrid_from_fn, interpolate::interpolate_channels, BorderType, InterpolationMode,
+    InterpolationPixel,
+};
 use crate::parallel;
 
 /// Inverts a 2x3 affine transformation matrix.
@@ -92,6 +95,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 /// * `dst` - The output image with shape (height, width, channels).
 /// * `m` - The 2x3 affine transformation matrix.
 /// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside of the input image.
 ///
 /// # Returns
 ///
@@ -101,7 +105,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 ///
 /// ```
 /// use kornia_image::{Image, ImageSize};
-/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::interpolation::{BorderType, InterpolationMode};
 /// use kornia_imgproc::warp::warp_affine;
 ///
 /// let src = Image::<_, 3>::from_size_val(
@@ -120,16 +124,17 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 ///
 /// let mut dst = Image::<_, 3>::from_size_val(new_size, 0.0).unwrap();
 ///
-/// warp_affine(&src, &mut dst, &m, InterpolationMode::Nearest).unwrap();
+/// warp_affine(&src, &mut dst, &m, InterpolationMode::Nearest, BorderType::Constant(0.0)).unwrap();
 ///
 /// assert_eq!(dst.size().width, 4);
 /// assert_eq!(dst.size().height, 5);
 /// ```
-pub fn warp_affine<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+pub fn warp_affine<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
     m: &[f32; 6],
     interpolation: InterpolationMode,
+    border: BorderType,
 ) -> Result<(), ImageError> {
     // invert affine transform matrix to find corresponding positions in src from dst
     let m_inv = invert_affine_transform(m);
@@ -143,14 +148,8 @@ pub fn warp_affine<const C: usize>(
 
     // apply affine transformation
     parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| {
-        // check if the position is within the bounds of the src image
-        if x >= 0.0f32 && x < src.cols() as f32 && y >= 0.0f32 && y < src.rows() as f32 {
-            // interpolate the pixel value for each channel
-            dst_pixel
-                .iter_mut()
-                .enumerate()
-                .for_each(|(k, pixel)| *pixel = interpolate_pixel(src, x, y, k, interpolation));
-        }
+        // interpolate the pixel value of all the channels
+        interpolate_channels(src, x, y, 0, interpolation, border, dst_pixel);
     });
 
     Ok(())
@@ -182,6 +181,7 @@ mod tests {
             &mut image_transformed,
             &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
             super::InterpolationMode::Bilinear,
+            super::BorderType::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 3);
@@ -214,6 +214,7 @@ mod tests {
             &mut image_transformed,
             &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
             super::InterpolationMode::Nearest,
+            super::BorderType::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
@@ -246,6 +247,7 @@ mod tests {
             &mut image_transformed,
             &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
             super::InterpolationMode::Nearest,
+            super::BorderType::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.as_slice(), image.as_slice());
@@ -277,6 +279,7 @@ mod tests {
             &mut image_transformed,
             &super::get_rotation_matrix2d((0.5, 0.5), 90.0, 1.0),
             super::InterpolationMode::Nearest,
+            super::BorderType::Constant(0.0),
         )?;
 
         assert_eq!(
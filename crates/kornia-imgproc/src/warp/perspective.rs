@@ -1,5 +1,8 @@
 use crate::{
-    interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode},
+    interpolation::{
+        grid::meshgrid_from_fn, interpolate::interpolate_channels, BorderType, InterpolationMode,
+        InterpolationPixel,
+    },
     parallel,
 };
 
@@ -60,6 +63,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 /// * `dst` - The output image with shape (height, width, channels).
 /// * `m` - The 3x3 perspective transformation matrix src -> dst.
 /// * `interpolation` - The interpolation mode to use.
+/// * `border` - The extrapolation of the pixels outside of the input image.
 ///
 /// # Returns
 ///
@@ -69,7 +73,7 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 ///
 /// ```
 /// use kornia_image::{Image, ImageSize};
-/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::interpolation::{BorderType, InterpolationMode};
 /// use kornia_imgproc::warp::warp_perspective;
 ///
 /// let src = Image::<f32, 1>::new(
@@ -90,16 +94,17 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 ///   0.0
 /// ).unwrap();
 ///
-/// warp_perspective(&src, &mut dst, &m, InterpolationMode::Bilinear).unwrap();
+/// warp_perspective(&src, &mut dst, &m, InterpolationMode::Bilinear, BorderType::Constant(0.0)).unwrap();
 ///
 /// assert_eq!(dst.size().width, 2);
 /// assert_eq!(dst.size().height, 3);
 /// ```
-pub fn warp_perspective<const C: usize>(
-    src: &Image<f32, C>,
-    dst: &mut Image<f32, C>,
+pub fn warp_perspective<T: InterpolationPixel, const C: usize>(
+    src: &Image<T, C>,
+    dst: &mut Image<T, C>,
     m: &[f32; 9],
     interpolation: InterpolationMode,
+    border: BorderType,
 ) -> Result<(), ImageError> {
     // inverse perspective matrix
     // TODO: allow later to skip the inverse calculation if user provides it
@@ -114,12 +119,8 @@ pub fn warp_perspective<const C: usize>(
 
     // apply affine transformation
     parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| {
-        if x >= 0.0f32 && x < src.cols() as f32 && y >= 0.0f32 && y < src.rows() as f32 {
-            dst_pixel
-                .iter_mut()
-                .enumerate()
-                .for_each(|(k, pixel)| *pixel = interpolate_pixel(src, x, y, k, interpolation));
-        }
+        // interpolate the pixel value of all the channels
+        interpolate_channels(src, x, y, 0, interpolation, border, dst_pixel);
     });
 
     Ok(())
@@ -172,6 +173,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderType::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 3);
@@ -208,6 +210,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderType::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
@@ -249,6 +252,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderType::Constant(0.0),
         )?;
 
         let mut image_resized = Image::<_, 1>::from_size_val(new_size, 0.0)?;
@@ -302,6 +306,7 @@ mod tests {
             &mut image_transformed,
             &m,
             super::InterpolationMode::Bilinear,
+            super::BorderType::Constant(0.0),
         )?;
 
         assert_eq!(image_transformed.num_channels(), 1);
@@ -312,4 +317,73 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_warp_perspective_shift_replicate() -> Result<(), ImageError> {
+        let image = Image::<_, 1>::new(
+            ImageSize {
+                width: 4,
+                height: 4,
+            },
+            (0..16).map(|x| x as f32).collect(),
+        )?;
+
+        // shift left by 1 pixel
+        let m = [1.0, 0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+        let image_expected = vec![
+            1.0f32, 2.0, 3.0, 3.0, 5.0, 6.0, 7.0, 7.0, 9.0, 10.0, 11.0, 11.0, 13.0, 14.0, 15.0,
+            15.0,
+        ];
+
+        let mut image_transformed = Image::<_, 1>::from_size_val(image.size(), 0.0)?;
+
+        super::warp_perspective(
+            &image,
+            &mut image_transformed,
+            &m,
+            super::InterpolationMode::Bilinear,
+            super::BorderType::Replicate,
+        )?;
+
+        assert_eq!(image_transformed.as_slice(), image_expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_warp_perspective_horizon() -> Result<(), ImageError> {
+        let image = Image::<_, 1>::new(
+            ImageSize {
+                width: 4,
+                height: 4,
+            },
+            (0..16).map(|x| x as f32).collect(),
+        )?;
+
+        // an involution whose projective row vanishes on the column x = 1
+        let m = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, -1.0];
+
+        for interpolation in [
+            super::InterpolationMode::Nearest,
+            super::InterpolationMode::Bilinear,
+            super::InterpolationMode::Bicubic,
+        ] {
+            let mut image_transformed = Image::<_, 1>::from_size_val(image.size(), 1.0)?;
+
+            super::warp_perspective(
+                &image,
+                &mut image_transformed,
+                &m,
+                interpolation,
+                super::BorderType::Constant(5.0),
+            )?;
+
+            for row in image_transformed.as_slice().chunks_exact(4) {
+                assert_eq!(row[1], 5.0, "{interpolation:?}");
+            }
+        }
+
+        Ok(())
+    }
 }
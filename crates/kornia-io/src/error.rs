@@ -26,6 +26,14 @@ pub enum IoError {
     #[error("Failed to decode the image")]
     ImageDecodeError(#[from] image::ImageError),
 
+    /// Error with the NumPy array files.
+    #[error("Error with the NumPy array file")]
+    NpyError(#[from] crate::npy::NpyError),
+
+    /// Error with the safetensors files.
+    #[error("Error with the safetensors file")]
+    SafeTensorsError(#[from] crate::safetensors::SafeTensorsError),
+
     /// Error to decode the PNG image.
     #[error("Failed to decode the image")]
     PngDecodeError(String),
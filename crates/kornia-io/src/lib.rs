@@ -14,9 +14,15 @@ pub mod functional;
 #[cfg(feature = "turbojpeg")]
 pub mod jpegturbo;
 
+/// NumPy `.npy` and `.npz` reading and writing for tensors and images.
+pub mod npy;
+
 /// PNG image encoding and decoding.
 pub mod png;
 
+/// Safetensors reading and writing for named tensor collections.
+pub mod safetensors;
+
 /// GStreamer video module for real-time video processing.
 #[cfg(feature = "gstreamer")]
 pub mod stream;
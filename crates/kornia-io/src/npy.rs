@@ -0,0 +1,1064 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Seek, Write},
+    path::Path,
+};
+
+use kornia_image::{Image, ImageSize};
+use kornia_tensor::{
+    checked_numel, CpuAllocator, DType, DTypeElement, DynTensor, Tensor, TensorAllocator,
+    TensorError,
+};
+
+use crate::error::IoError;
+
+/// The magic string at the beginning of the NumPy files.
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The alignment in bytes of the data after the header.
+const NPY_HEADER_ALIGNMENT: usize = 64;
+
+/// Error types for the NumPy module.
+#[derive(thiserror::Error, Debug)]
+pub enum NpyError {
+    /// The file does not start with the NumPy magic string.
+    #[error("Invalid NumPy magic string")]
+    InvalidMagic,
+
+    /// The version of the format is not supported.
+    #[error("Unsupported NumPy format version {0}.{1}")]
+    UnsupportedVersion(u8, u8),
+
+    /// The header of the array could not be parsed.
+    #[error("Invalid NumPy header: {0}")]
+    InvalidHeader(String),
+
+    /// The data type of the array does not match the requested element type.
+    #[error("Data type mismatch: expected {0}, found {1}")]
+    DtypeMismatch(String, String),
+
+    /// The data type of the array is not supported.
+    #[error("Unsupported data type {0}")]
+    UnsupportedDtype(String),
+
+    /// The shape of the array does not match the requested tensor or image.
+    #[error("Shape mismatch: cannot read an array of shape {0:?} as {1}")]
+    ShapeMismatch(Vec<usize>, String),
+
+    /// The array is stored in Fortran order, only C order is supported.
+    #[error("Fortran ordered arrays are not supported")]
+    FortranOrder,
+
+    /// The array is not found in the archive.
+    #[error("Array {0} not found in the archive")]
+    ArrayNotFound(String),
+
+    /// Error with the npz archive.
+    #[error("Error with the npz archive")]
+    ZipError(#[from] zip::result::ZipError),
+
+    /// Error to create the tensor.
+    #[error("Failed to create the tensor")]
+    TensorError(#[from] TensorError),
+}
+
+/// An element type that can be stored in NumPy arrays.
+pub trait NpyElement: Copy {
+    /// The NumPy type descriptor without byte order, e.g. `f4`.
+    const DTYPE: &'static str;
+
+    /// Read an element from its little-endian bytes.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Read an element from its big-endian bytes.
+    fn from_be_slice(bytes: &[u8]) -> Self;
+
+    /// Append the little-endian bytes of the element to a buffer.
+    fn extend_le_bytes(&self, buf: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($t:ty, $dtype:expr) => {
+        impl NpyElement for $t {
+            const DTYPE: &'static str = $dtype;
+
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let mut arr = [0u8; std::mem::size_of::<$t>()];
+                arr.copy_from_slice(bytes);
+                <$t>::from_le_bytes(arr)
+            }
+
+            fn from_be_slice(bytes: &[u8]) -> Self {
+                let mut arr = [0u8; std::mem::size_of::<$t>()];
+                arr.copy_from_slice(bytes);
+                <$t>::from_be_bytes(arr)
+            }
+
+            fn extend_le_bytes(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_npy_element!(u8, "u1");
+impl_npy_element!(i8, "i1");
+impl_npy_element!(u16, "u2");
+impl_npy_element!(i16, "i2");
+impl_npy_element!(u32, "u4");
+impl_npy_element!(i32, "i4");
+impl_npy_element!(u64, "u8");
+impl_npy_element!(i64, "i8");
+impl_npy_element!(f32, "f4");
+impl_npy_element!(f64, "f8");
+#[cfg(feature = "half")]
+impl_npy_element!(half::f16, "f2");
+
+/// The parsed header of a NumPy array.
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// Extract the raw value of a key from the header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let pattern = format!("'{key}':");
+    let start = header
+        .find(&pattern)
+        .ok_or_else(|| NpyError::InvalidHeader(format!("missing key {key}")))?;
+    Ok(header[start + pattern.len()..].trim_start())
+}
+
+fn parse_header(header: &str) -> Result<NpyHeader, NpyError> {
+    let descr = header_value(header, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|s| s.split('\'').next())
+        .ok_or_else(|| NpyError::InvalidHeader("invalid descr".to_string()))?;
+
+    let fortran_order = header_value(header, "fortran_order")?.starts_with("True");
+
+    let shape = header_value(header, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+        .ok_or_else(|| NpyError::InvalidHeader("invalid shape".to_string()))?;
+    let shape = shape
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<usize>()
+                .map_err(|_| NpyError::InvalidHeader(format!("invalid dimension {s}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(NpyHeader {
+        descr: descr.to_string(),
+        fortran_order,
+        shape,
+    })
+}
+
+/// Map a NumPy type descriptor without byte order to a runtime data type.
+fn dtype_from_descr(descr: &str) -> Option<DType> {
+    Some(match descr {
+        "u1" => DType::U8,
+        "i1" => DType::I8,
+        "u2" => DType::U16,
+        "i2" => DType::I16,
+        "u4" => DType::U32,
+        "i4" => DType::I32,
+        "u8" => DType::U64,
+        "i8" => DType::I64,
+        "f2" => DType::F16,
+        "f4" => DType::F32,
+        "f8" => DType::F64,
+        _ => return None,
+    })
+}
+
+/// Map a runtime data type to a NumPy type descriptor without byte order.
+fn descr_from_dtype(dtype: DType) -> Option<&'static str> {
+    Some(match dtype {
+        DType::U8 => "u1",
+        DType::I8 => "i1",
+        DType::U16 => "u2",
+        DType::I16 => "i2",
+        DType::U32 => "u4",
+        DType::I32 => "i4",
+        DType::U64 => "u8",
+        DType::I64 => "i8",
+        DType::F16 => "f2",
+        DType::F32 => "f4",
+        DType::F64 => "f8",
+        // numpy has no native bfloat16 type
+        DType::BF16 => return None,
+    })
+}
+
+/// Encode the header of an array in the NumPy format, with room for the data.
+fn encode_npy_header(shape: &[usize], descr: &str, elem_size: usize) -> Vec<u8> {
+    let shape_str = match shape {
+        [d] => format!("({d},)"),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let byte_order = if elem_size == 1 { '|' } else { '<' };
+    let mut header = format!(
+        "{{'descr': '{byte_order}{descr}', 'fortran_order': False, 'shape': {shape_str}, }}"
+    );
+
+    // version 1.0 stores the header length in 2 bytes, version 2.0 in 4 bytes
+    let len_prefix = if header.len() + 1 + 10 > u16::MAX as usize {
+        12
+    } else {
+        10
+    };
+    let total =
+        (len_prefix + header.len() + 1).div_ceil(NPY_HEADER_ALIGNMENT) * NPY_HEADER_ALIGNMENT;
+    header.push_str(&" ".repeat(total - len_prefix - header.len() - 1));
+    header.push('\n');
+
+    // the capacity is only a hint, the data of a valid tensor cannot overflow
+    let data_len = npy_data_len(shape, elem_size).unwrap_or(0);
+    let mut buf = Vec::with_capacity(total.saturating_add(data_len));
+    buf.extend_from_slice(NPY_MAGIC);
+    if len_prefix == 10 {
+        buf.extend_from_slice(&[1, 0]);
+        buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    } else {
+        buf.extend_from_slice(&[2, 0]);
+        buf.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    }
+    buf.extend_from_slice(header.as_bytes());
+    buf
+}
+
+/// Compute the number of bytes of the data of an array, checking for overflow.
+fn npy_data_len(shape: &[usize], elem_size: usize) -> Result<usize, NpyError> {
+    checked_numel(shape)
+        .ok()
+        .and_then(|numel| numel.checked_mul(elem_size))
+        .ok_or_else(|| {
+            NpyError::InvalidHeader(format!("the size of the shape {shape:?} overflows"))
+        })
+}
+
+/// Encode the raw parts of an array in the NumPy format.
+fn encode_npy_impl<T: NpyElement>(shape: &[usize], data: &[T]) -> Vec<u8> {
+    let mut buf = encode_npy_header(shape, T::DTYPE, std::mem::size_of::<T>());
+    for x in data {
+        x.extend_le_bytes(&mut buf);
+    }
+    buf
+}
+
+/// An array in the NumPy format whose data is not decoded yet.
+struct NpyArray<'a> {
+    /// The type descriptor without byte order.
+    dtype: String,
+    shape: Vec<usize>,
+    big_endian: bool,
+    data: &'a [u8],
+}
+
+/// Parse the header of an array in the NumPy format.
+fn parse_npy(bytes: &[u8]) -> Result<NpyArray<'_>, NpyError> {
+    if bytes.len() < 10 || &bytes[..6] != NPY_MAGIC {
+        return Err(NpyError::InvalidMagic);
+    }
+    let (header_len, header_start) = match (bytes[6], bytes[7]) {
+        (1, 0) => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        (2, 0) | (3, 0) if bytes.len() >= 12 => (
+            u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+            12,
+        ),
+        (major, minor) => return Err(NpyError::UnsupportedVersion(major, minor)),
+    };
+    let header_bytes = bytes
+        .get(header_start..header_start + header_len)
+        .and_then(|h| std::str::from_utf8(h).ok())
+        .ok_or_else(|| NpyError::InvalidHeader("truncated header".to_string()))?;
+    let header = parse_header(header_bytes)?;
+
+    if header.fortran_order {
+        return Err(NpyError::FortranOrder);
+    }
+
+    let (byte_order, dtype) = header.descr.split_at(1.min(header.descr.len()));
+    if !matches!(byte_order, "<" | ">" | "|" | "=") {
+        return Err(NpyError::InvalidHeader(format!(
+            "invalid byte order {}",
+            header.descr
+        )));
+    }
+    let big_endian = match byte_order {
+        ">" => true,
+        "=" => cfg!(target_endian = "big"),
+        _ => false,
+    };
+
+    Ok(NpyArray {
+        dtype: dtype.to_string(),
+        shape: header.shape,
+        big_endian,
+        data: &bytes[header_start + header_len..],
+    })
+}
+
+/// Decode the data of a parsed array with the given element type.
+fn decode_npy_data<T: NpyElement>(array: NpyArray<'_>) -> Result<(Vec<usize>, Vec<T>), NpyError> {
+    if array.dtype != T::DTYPE {
+        return Err(NpyError::DtypeMismatch(T::DTYPE.to_string(), array.dtype));
+    }
+
+    let elem_size = std::mem::size_of::<T>();
+    let data_len = npy_data_len(&array.shape, elem_size)?;
+    if array.data.len() < data_len {
+        return Err(NpyError::InvalidHeader(format!(
+            "expected {} bytes of data, found {}",
+            data_len,
+            array.data.len()
+        )));
+    }
+
+    let chunks = array.data[..data_len].chunks_exact(elem_size);
+    let data = if array.big_endian {
+        chunks.map(T::from_be_slice).collect()
+    } else {
+        chunks.map(T::from_le_slice).collect()
+    };
+
+    Ok((array.shape, data))
+}
+
+/// Decode an array in the NumPy format into its shape and data.
+fn decode_npy_impl<T: NpyElement>(bytes: &[u8]) -> Result<(Vec<usize>, Vec<T>), NpyError> {
+    decode_npy_data(parse_npy(bytes)?)
+}
+
+/// Decode an array in the NumPy format into a dynamic tensor.
+fn decode_npy_dyn_impl(bytes: &[u8]) -> Result<DynTensor, NpyError> {
+    fn decode<T: NpyElement + DTypeElement>(array: NpyArray<'_>) -> Result<DynTensor, NpyError> {
+        let (shape, data) = decode_npy_data::<T>(array)?;
+        Ok(DynTensor::from_shape_vec(shape, data)?)
+    }
+
+    let array = parse_npy(bytes)?;
+    match dtype_from_descr(&array.dtype) {
+        Some(DType::U8) => decode::<u8>(array),
+        Some(DType::I8) => decode::<i8>(array),
+        Some(DType::U16) => decode::<u16>(array),
+        Some(DType::I16) => decode::<i16>(array),
+        Some(DType::U32) => decode::<u32>(array),
+        Some(DType::I32) => decode::<i32>(array),
+        Some(DType::U64) => decode::<u64>(array),
+        Some(DType::I64) => decode::<i64>(array),
+        Some(DType::F32) => decode::<f32>(array),
+        Some(DType::F64) => decode::<f64>(array),
+        #[cfg(feature = "half")]
+        Some(DType::F16) => decode::<half::f16>(array),
+        _ => Err(NpyError::UnsupportedDtype(array.dtype)),
+    }
+}
+
+/// Encode a tensor in the NumPy `.npy` format.
+///
+/// The data is written in C order and little-endian byte order.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to encode.
+///
+/// # Returns
+///
+/// The bytes of the `.npy` file.
+pub fn encode_npy<T, const N: usize, A>(tensor: &Tensor<T, N, A>) -> Vec<u8>
+where
+    T: NpyElement,
+    A: TensorAllocator + 'static,
+{
+    encode_npy_impl(&tensor.shape, tensor.as_slice())
+}
+
+/// Decode a tensor from the bytes of a NumPy `.npy` file.
+///
+/// Both byte orders are supported, the data is converted to the native byte order.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes of the `.npy` file.
+///
+/// # Returns
+///
+/// The decoded tensor.
+///
+/// # Errors
+///
+/// If the data type or the number of dimensions of the array do not match the tensor, or if
+/// the array is stored in Fortran order, an error is returned.
+pub fn decode_npy<T, const N: usize>(bytes: &[u8]) -> Result<Tensor<T, N, CpuAllocator>, IoError>
+where
+    T: NpyElement,
+{
+    let (shape, data) = decode_npy_impl::<T>(bytes)?;
+    let shape: [usize; N] = shape
+        .as_slice()
+        .try_into()
+        .map_err(|_| NpyError::ShapeMismatch(shape.clone(), format!("a tensor of rank {N}")))?;
+    let tensor = Tensor::from_shape_vec(shape, data, CpuAllocator).map_err(NpyError::from)?;
+    Ok(tensor)
+}
+
+/// Encode a dynamic tensor in the NumPy `.npy` format.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to encode.
+///
+/// # Returns
+///
+/// The bytes of the `.npy` file.
+///
+/// # Errors
+///
+/// If the data type has no NumPy equivalent, e.g. `bf16`, an error is returned.
+pub fn encode_npy_dyn(tensor: &DynTensor) -> Result<Vec<u8>, IoError> {
+    let dtype = tensor.dtype();
+    let descr =
+        descr_from_dtype(dtype).ok_or_else(|| NpyError::UnsupportedDtype(dtype.to_string()))?;
+    let mut buf = encode_npy_header(tensor.shape(), descr, dtype.size_of());
+    if cfg!(target_endian = "little") {
+        buf.extend_from_slice(tensor.as_bytes());
+    } else {
+        tensor
+            .as_bytes()
+            .chunks_exact(dtype.size_of())
+            .for_each(|x| buf.extend(x.iter().rev()));
+    }
+    Ok(buf)
+}
+
+/// Decode a dynamic tensor from the bytes of a NumPy `.npy` file.
+///
+/// The data type and the rank are taken from the header of the file.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes of the `.npy` file.
+///
+/// # Returns
+///
+/// The decoded tensor.
+pub fn decode_npy_dyn(bytes: &[u8]) -> Result<DynTensor, IoError> {
+    Ok(decode_npy_dyn_impl(bytes)?)
+}
+
+/// Convert a tensor decoded from a NumPy array into an image.
+///
+/// Arrays with shape `(H, W, C)` are accepted, and `(H, W)` for single channel images.
+fn tensor_to_image<T: NpyElement, const C: usize>(
+    shape: Vec<usize>,
+    data: Vec<T>,
+) -> Result<Image<T, C>, IoError> {
+    let size = match shape[..] {
+        [height, width, channels] if channels == C => ImageSize { width, height },
+        [height, width] if C == 1 => ImageSize { width, height },
+        _ => {
+            return Err(
+                NpyError::ShapeMismatch(shape, format!("an image with {C} channels")).into(),
+            )
+        }
+    };
+    Ok(Image::new(size, data)?)
+}
+
+/// Write a tensor to a NumPy `.npy` file.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the `.npy` file.
+/// * `tensor` - The tensor to write.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_io::npy::{read_npy, write_npy};
+/// use kornia_tensor::{CpuAllocator, Tensor};
+///
+/// let t = Tensor::<f32, 2, _>::from_shape_val([2, 3], 1.0, CpuAllocator);
+/// write_npy("/tmp/tensor.npy", &t).unwrap();
+/// let t2 = read_npy::<f32, 2>("/tmp/tensor.npy").unwrap();
+/// assert_eq!(t.as_slice(), t2.as_slice());
+/// ```
+pub fn write_npy<T, const N: usize, A>(
+    file_path: impl AsRef<Path>,
+    tensor: &Tensor<T, N, A>,
+) -> Result<(), IoError>
+where
+    T: NpyElement,
+    A: TensorAllocator + 'static,
+{
+    let mut file = BufWriter::new(File::create(file_path)?);
+    file.write_all(&encode_npy(tensor))?;
+    file.flush()?;
+    Ok(())
+}
+
+/// Read a tensor from a NumPy `.npy` file.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the `.npy` file.
+///
+/// # Returns
+///
+/// The tensor stored in the file.
+pub fn read_npy<T, const N: usize>(
+    file_path: impl AsRef<Path>,
+) -> Result<Tensor<T, N, CpuAllocator>, IoError>
+where
+    T: NpyElement,
+{
+    decode_npy(&read_file(file_path)?)
+}
+
+/// Write a dynamic tensor to a NumPy `.npy` file.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the `.npy` file.
+/// * `tensor` - The tensor to write.
+pub fn write_npy_dyn(file_path: impl AsRef<Path>, tensor: &DynTensor) -> Result<(), IoError> {
+    let mut file = BufWriter::new(File::create(file_path)?);
+    file.write_all(&encode_npy_dyn(tensor)?)?;
+    file.flush()?;
+    Ok(())
+}
+
+/// Read a NumPy `.npy` file of any data type and rank into a dynamic tensor.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the `.npy` file.
+///
+/// # Returns
+///
+/// The tensor stored in the file.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_io::npy::read_npy_dyn;
+/// use kornia_tensor::{DType, Tensor, CpuAllocator};
+///
+/// let t = read_npy_dyn("/tmp/tensor.npy").unwrap();
+/// if t.dtype() == DType::F32 && t.rank() == 2 {
+///     let t: Tensor<f32, 2, CpuAllocator> = t.try_into().unwrap();
+/// }
+/// ```
+pub fn read_npy_dyn(file_path: impl AsRef<Path>) -> Result<DynTensor, IoError> {
+    decode_npy_dyn(&read_file(file_path)?)
+}
+
+/// Write an image to a NumPy `.npy` file as an array with shape `(H, W, C)`.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the `.npy` file.
+/// * `image` - The image to write.
+pub fn write_image_npy<T, const C: usize>(
+    file_path: impl AsRef<Path>,
+    image: &Image<T, C>,
+) -> Result<(), IoError>
+where
+    T: NpyElement,
+{
+    write_npy(file_path, &image.0)
+}
+
+/// Read an image from a NumPy `.npy` file.
+///
+/// The array must have shape `(H, W, C)`, or `(H, W)` for single channel images.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the `.npy` file.
+///
+/// # Returns
+///
+/// The image stored in the file.
+pub fn read_image_npy<T, const C: usize>(
+    file_path: impl AsRef<Path>,
+) -> Result<Image<T, C>, IoError>
+where
+    T: NpyElement,
+{
+    let (shape, data) = decode_npy_impl(&read_file(file_path)?)?;
+    tensor_to_image(shape, data)
+}
+
+fn read_file(file_path: impl AsRef<Path>) -> Result<Vec<u8>, IoError> {
+    let file_path = file_path.as_ref();
+    if !file_path.exists() {
+        return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+    }
+    let mut bytes = Vec::new();
+    BufReader::new(File::open(file_path)?).read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// A writer of NumPy `.npz` archives of named arrays.
+///
+/// The archives are compatible with `numpy.load`, and with `numpy.savez_compressed` when
+/// created with [`NpzWriter::compressed`].
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_io::npy::{NpzReader, NpzWriter};
+/// use kornia_tensor::{CpuAllocator, Tensor};
+///
+/// let points = Tensor::<f64, 2, _>::from_shape_val([10, 3], 0.0, CpuAllocator);
+/// let image = Image::<u8, 3>::from_size_val(ImageSize { width: 4, height: 2 }, 0).unwrap();
+///
+/// let mut writer = NpzWriter::new("/tmp/arrays.npz").unwrap();
+/// writer.add_tensor("points", &points).unwrap();
+/// writer.add_image("image", &image).unwrap();
+/// writer.finish().unwrap();
+///
+/// let mut reader = NpzReader::new("/tmp/arrays.npz").unwrap();
+/// let points = reader.read_tensor::<f64, 2>("points").unwrap();
+/// let image = reader.read_image::<u8, 3>("image").unwrap();
+/// ```
+pub struct NpzWriter<W: Write + Seek> {
+    zip: zip::ZipWriter<W>,
+    options: zip::write::SimpleFileOptions,
+}
+
+impl NpzWriter<BufWriter<File>> {
+    /// Create a new archive without compression, like `numpy.savez`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the `.npz` file.
+    pub fn new(file_path: impl AsRef<Path>) -> Result<Self, IoError> {
+        Ok(Self::from_writer(
+            BufWriter::new(File::create(file_path)?),
+            false,
+        ))
+    }
+
+    /// Create a new archive with deflate compression, like `numpy.savez_compressed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the `.npz` file.
+    pub fn compressed(file_path: impl AsRef<Path>) -> Result<Self, IoError> {
+        Ok(Self::from_writer(
+            BufWriter::new(File::create(file_path)?),
+            true,
+        ))
+    }
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    /// Create a new archive writing to the given writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination of the archive.
+    /// * `compress` - Whether to compress the arrays with deflate.
+    pub fn from_writer(writer: W, compress: bool) -> Self {
+        let method = if compress {
+            zip::CompressionMethod::Deflated
+        } else {
+            zip::CompressionMethod::Stored
+        };
+        Self {
+            zip: zip::ZipWriter::new(writer),
+            options: zip::write::SimpleFileOptions::default()
+                .compression_method(method)
+                .large_file(false),
+        }
+    }
+
+    /// Add a tensor to the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array in the archive.
+    /// * `tensor` - The tensor to add.
+    pub fn add_tensor<T, const N: usize, A>(
+        &mut self,
+        name: &str,
+        tensor: &Tensor<T, N, A>,
+    ) -> Result<(), IoError>
+    where
+        T: NpyElement,
+        A: TensorAllocator + 'static,
+    {
+        let bytes = encode_npy(tensor);
+        self.zip
+            .start_file(format!("{name}.npy"), self.options)
+            .map_err(NpyError::from)?;
+        self.zip.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Add a dynamic tensor to the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array in the archive.
+    /// * `tensor` - The tensor to add.
+    pub fn add_dyn_tensor(&mut self, name: &str, tensor: &DynTensor) -> Result<(), IoError> {
+        let bytes = encode_npy_dyn(tensor)?;
+        self.zip
+            .start_file(format!("{name}.npy"), self.options)
+            .map_err(NpyError::from)?;
+        self.zip.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Add an image to the archive as an array with shape `(H, W, C)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array in the archive.
+    /// * `image` - The image to add.
+    pub fn add_image<T, const C: usize>(
+        &mut self,
+        name: &str,
+        image: &Image<T, C>,
+    ) -> Result<(), IoError>
+    where
+        T: NpyElement,
+    {
+        self.add_tensor(name, &image.0)
+    }
+
+    /// Finish writing the archive and return the inner writer.
+    pub fn finish(self) -> Result<W, IoError> {
+        let mut writer = self.zip.finish().map_err(NpyError::from)?;
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+/// A reader of NumPy `.npz` archives of named arrays.
+///
+/// See [`NpzWriter`] for an example.
+pub struct NpzReader<R: Read + Seek> {
+    zip: zip::ZipArchive<R>,
+}
+
+impl NpzReader<BufReader<File>> {
+    /// Open an archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the `.npz` file.
+    pub fn new(file_path: impl AsRef<Path>) -> Result<Self, IoError> {
+        let file_path = file_path.as_ref();
+        if !file_path.exists() {
+            return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+        }
+        Self::from_reader(BufReader::new(File::open(file_path)?))
+    }
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    /// Open an archive from the given reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the archive.
+    pub fn from_reader(reader: R) -> Result<Self, IoError> {
+        Ok(Self {
+            zip: zip::ZipArchive::new(reader).map_err(NpyError::from)?,
+        })
+    }
+
+    /// Returns the names of the arrays in the archive.
+    pub fn names(&self) -> Vec<String> {
+        self.zip
+            .file_names()
+            .map(|name| name.strip_suffix(".npy").unwrap_or(name).to_string())
+            .collect()
+    }
+
+    fn read_bytes(&mut self, name: &str) -> Result<Vec<u8>, IoError> {
+        let mut file = match self.zip.by_name(&format!("{name}.npy")) {
+            Ok(file) => file,
+            Err(zip::result::ZipError::FileNotFound) => {
+                return Err(NpyError::ArrayNotFound(name.to_string()).into())
+            }
+            Err(e) => return Err(NpyError::from(e).into()),
+        };
+        let mut bytes = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Read a tensor from the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array in the archive.
+    pub fn read_tensor<T, const N: usize>(
+        &mut self,
+        name: &str,
+    ) -> Result<Tensor<T, N, CpuAllocator>, IoError>
+    where
+        T: NpyElement,
+    {
+        decode_npy(&self.read_bytes(name)?)
+    }
+
+    /// Read an array of any data type and rank from the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array in the archive.
+    pub fn read_dyn_tensor(&mut self, name: &str) -> Result<DynTensor, IoError> {
+        decode_npy_dyn(&self.read_bytes(name)?)
+    }
+
+    /// Read an image from the archive.
+    ///
+    /// The array must have shape `(H, W, C)`, or `(H, W)` for single channel images.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the array in the archive.
+    pub fn read_image<T, const C: usize>(&mut self, name: &str) -> Result<Image<T, C>, IoError>
+    where
+        T: NpyElement,
+    {
+        let (shape, data) = decode_npy_impl(&self.read_bytes(name)?)?;
+        tensor_to_image(shape, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_npy_roundtrip() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("tensor.npy");
+
+        let t = Tensor::<i16, 3, _>::from_shape_fn([2, 3, 4], CpuAllocator, |[i, j, k]| {
+            (i * 12 + j * 4 + k) as i16 - 10
+        });
+        write_npy(&file_path, &t)?;
+        let t2 = read_npy::<i16, 3>(&file_path)?;
+        assert_eq!(t2.shape, t.shape);
+        assert_eq!(t2.as_slice(), t.as_slice());
+
+        // the data is aligned as numpy does
+        let bytes = std::fs::read(&file_path)?;
+        let header_len = bytes.len() - 2 * 24;
+        assert_eq!(header_len % 64, 0);
+        assert_eq!(bytes[header_len - 1], b'\n');
+
+        let res = read_npy::<f32, 3>(&file_path);
+        assert!(matches!(
+            res,
+            Err(IoError::NpyError(NpyError::DtypeMismatch(_, _)))
+        ));
+        let res = read_npy::<i16, 2>(&file_path);
+        assert!(matches!(
+            res,
+            Err(IoError::NpyError(NpyError::ShapeMismatch(_, _)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_header() -> Result<(), IoError> {
+        let t = Tensor::<u8, 1, _>::from_shape_vec([3], vec![1, 2, 3], CpuAllocator)
+            .map_err(NpyError::from)?;
+        let bytes = encode_npy(&t);
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        let header = String::from_utf8_lossy(&bytes[10..]);
+        assert!(header.starts_with("{'descr': '|u1', 'fortran_order': False, 'shape': (3,), }"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_shape_overflow() -> Result<(), IoError> {
+        let header =
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (9223372036854775808, 2), }";
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&[0; 16]);
+
+        let res = decode_npy::<f32, 2>(&bytes);
+        assert!(matches!(
+            res,
+            Err(IoError::NpyError(NpyError::InvalidHeader(_)))
+        ));
+        let res = decode_npy_dyn(&bytes);
+        assert!(matches!(
+            res,
+            Err(IoError::NpyError(NpyError::InvalidHeader(_)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_big_endian() -> Result<(), IoError> {
+        // array written by numpy with `np.array([1.5, -2.0], dtype='>f4')`
+        let header = "{'descr': '>f4', 'fortran_order': False, 'shape': (2,), }";
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&1.5f32.to_be_bytes());
+        bytes.extend_from_slice(&(-2.0f32).to_be_bytes());
+
+        let t = decode_npy::<f32, 1>(&bytes)?;
+        assert_eq!(t.as_slice(), [1.5, -2.0]);
+
+        let fortran = bytes.clone();
+        let fortran = String::from_utf8_lossy(&fortran).replace("False", "True ");
+        let res = decode_npy::<f32, 1>(fortran.as_bytes());
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_npy_f16() -> Result<(), IoError> {
+        use half::f16;
+
+        let t = Tensor::<f16, 2, _>::from_shape_fn([2, 2], CpuAllocator, |[i, j]| {
+            f16::from_f32(i as f32 - j as f32 * 0.25)
+        });
+        let bytes = encode_npy(&t);
+        assert!(String::from_utf8_lossy(&bytes).contains("'descr': '<f2'"));
+        assert_eq!(decode_npy::<f16, 2>(&bytes)?.as_slice(), t.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn test_npz_roundtrip() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+
+        let points = Tensor::<f64, 2, _>::from_shape_fn([4, 3], CpuAllocator, |[i, j]| {
+            i as f64 + j as f64 * 0.5
+        });
+        let image = Image::<u16, 1>::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            vec![0, 1, 2, 3, 4, 65535],
+        )?;
+
+        for compress in [false, true] {
+            let file_path = tmp_dir.path().join(format!("arrays_{compress}.npz"));
+            let mut writer = if compress {
+                NpzWriter::compressed(&file_path)?
+            } else {
+                NpzWriter::new(&file_path)?
+            };
+            writer.add_tensor("points", &points)?;
+            writer.add_image("image", &image)?;
+            writer.finish()?;
+
+            let mut reader = NpzReader::new(&file_path)?;
+            let mut names = reader.names();
+            names.sort();
+            assert_eq!(names, ["image", "points"]);
+
+            let points2 = reader.read_tensor::<f64, 2>("points")?;
+            assert_eq!(points2.as_slice(), points.as_slice());
+            let image2 = reader.read_image::<u16, 1>("image")?;
+            assert_eq!(image2.size(), image.size());
+            assert_eq!(image2.as_slice(), image.as_slice());
+
+            let res = reader.read_tensor::<f64, 2>("missing");
+            assert!(matches!(
+                res,
+                Err(IoError::NpyError(NpyError::ArrayNotFound(_)))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_npy_gray() -> Result<(), IoError> {
+        // (H, W) arrays are read as single channel images
+        let t =
+            Tensor::<f32, 2, _>::from_shape_vec([2, 2], vec![0.0, 0.25, 0.5, 1.0], CpuAllocator)
+                .map_err(NpyError::from)?;
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("gray.npy");
+        write_npy(&file_path, &t)?;
+
+        let image = read_image_npy::<f32, 1>(&file_path)?;
+        assert_eq!(
+            image.size(),
+            ImageSize {
+                width: 2,
+                height: 2
+            }
+        );
+        assert_eq!(image.as_slice(), t.as_slice());
+
+        let res = read_image_npy::<f32, 3>(&file_path);
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_dyn() -> Result<(), IoError> {
+        let t = Tensor::<i32, 3, _>::from_shape_fn([2, 1, 3], CpuAllocator, |[i, _, k]| {
+            i as i32 * 10 - k as i32
+        });
+        let bytes = encode_npy(&t);
+
+        let d = decode_npy_dyn(&bytes)?;
+        assert_eq!(d.dtype(), DType::I32);
+        assert_eq!(d.shape(), &[2, 1, 3]);
+        assert_eq!(d.as_slice::<i32>().map_err(NpyError::from)?, t.as_slice());
+        assert_eq!(encode_npy_dyn(&d)?, bytes);
+
+        let t2 = d
+            .clone()
+            .try_into_tensor::<i32, 3>()
+            .map_err(NpyError::from)?;
+        assert_eq!(t2.as_slice(), t.as_slice());
+        assert!(d.try_into_tensor::<f32, 3>().is_err());
+
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("arrays.npz");
+        let mut writer = NpzWriter::new(&file_path)?;
+        writer.add_dyn_tensor("t", &DynTensor::from(t))?;
+        writer.finish()?;
+        let mut reader = NpzReader::new(&file_path)?;
+        assert_eq!(reader.read_dyn_tensor("t")?.shape(), &[2, 1, 3]);
+
+        let mut unsupported = bytes.clone();
+        let pos = unsupported.windows(2).position(|w| w == b"i4").unwrap_or(0);
+        unsupported[pos..pos + 2].copy_from_slice(b"c8");
+        assert!(matches!(
+            decode_npy_dyn(&unsupported),
+            Err(IoError::NpyError(NpyError::UnsupportedDtype(_)))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,739 @@
+use std::{borrow::Cow, collections::HashMap, fs::File, path::Path, ptr::NonNull, sync::Arc};
+
+use ::safetensors::{
+    tensor::{Metadata, TensorInfo},
+    Dtype, SafeTensorError, SafeTensors, View,
+};
+use kornia_tensor::{
+    CpuAllocator, DType, DTypeElement, DynTensor, Tensor, TensorAllocator, TensorError,
+};
+
+use crate::error::IoError;
+
+/// Error types for the safetensors module.
+#[derive(thiserror::Error, Debug)]
+pub enum SafeTensorsError {
+    /// The file is not a valid safetensors file.
+    #[error("Invalid safetensors file")]
+    FormatError(#[from] SafeTensorError),
+
+    /// The tensor is not found in the file.
+    #[error("Tensor {0} not found in the file")]
+    TensorNotFound(String),
+
+    /// The data type of the tensor does not match the requested element type.
+    #[error("Data type mismatch: expected {0:?}, found {1:?}")]
+    DtypeMismatch(Dtype, Dtype),
+
+    /// The data type of the tensor is not supported.
+    #[error("Unsupported data type {0:?}")]
+    UnsupportedDtype(Dtype),
+
+    /// The rank of the tensor does not match the requested rank.
+    #[error("Rank mismatch: cannot read a tensor of shape {0:?} with rank {1}")]
+    RankMismatch(Vec<usize>, usize),
+
+    /// Error to create the tensor.
+    #[error("Failed to create the tensor")]
+    TensorError(#[from] TensorError),
+}
+
+/// An element type that can be stored in safetensors files.
+pub trait SafeTensorsElement: Copy {
+    /// The safetensors data type of the element.
+    const SAFETENSORS_DTYPE: Dtype;
+
+    /// Read an element from its little-endian bytes.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Append the little-endian bytes of the element to a buffer.
+    fn extend_le_bytes(&self, buf: &mut Vec<u8>);
+}
+
+macro_rules! impl_safetensors_element {
+    ($t:ty, $dtype:expr) => {
+        impl SafeTensorsElement for $t {
+            const SAFETENSORS_DTYPE: Dtype = $dtype;
+
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let mut arr = [0u8; std::mem::size_of::<$t>()];
+                arr.copy_from_slice(bytes);
+                <$t>::from_le_bytes(arr)
+            }
+
+            fn extend_le_bytes(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_safetensors_element!(u8, Dtype::U8);
+impl_safetensors_element!(i8, Dtype::I8);
+impl_safetensors_element!(u16, Dtype::U16);
+impl_safetensors_element!(i16, Dtype::I16);
+impl_safetensors_element!(u32, Dtype::U32);
+impl_safetensors_element!(i32, Dtype::I32);
+impl_safetensors_element!(u64, Dtype::U64);
+impl_safetensors_element!(i64, Dtype::I64);
+impl_safetensors_element!(f32, Dtype::F32);
+impl_safetensors_element!(f64, Dtype::F64);
+#[cfg(feature = "half")]
+impl_safetensors_element!(half::f16, Dtype::F16);
+#[cfg(feature = "half")]
+impl_safetensors_element!(half::bf16, Dtype::BF16);
+
+/// Map a safetensors data type to a runtime data type.
+fn dtype_from_safetensors(dtype: Dtype) -> Option<DType> {
+    Some(match dtype {
+        Dtype::U8 => DType::U8,
+        Dtype::I8 => DType::I8,
+        Dtype::U16 => DType::U16,
+        Dtype::I16 => DType::I16,
+        Dtype::U32 => DType::U32,
+        Dtype::I32 => DType::I32,
+        Dtype::U64 => DType::U64,
+        Dtype::I64 => DType::I64,
+        Dtype::F16 => DType::F16,
+        Dtype::BF16 => DType::BF16,
+        Dtype::F32 => DType::F32,
+        Dtype::F64 => DType::F64,
+        _ => return None,
+    })
+}
+
+/// Map a runtime data type to a safetensors data type.
+fn safetensors_from_dtype(dtype: DType) -> Dtype {
+    match dtype {
+        DType::U8 => Dtype::U8,
+        DType::I8 => Dtype::I8,
+        DType::U16 => Dtype::U16,
+        DType::I16 => Dtype::I16,
+        DType::U32 => Dtype::U32,
+        DType::I32 => Dtype::I32,
+        DType::U64 => Dtype::U64,
+        DType::I64 => Dtype::I64,
+        DType::F16 => Dtype::F16,
+        DType::BF16 => Dtype::BF16,
+        DType::F32 => Dtype::F32,
+        DType::F64 => Dtype::F64,
+    }
+}
+
+/// The little-endian bytes of a tensor to serialize.
+struct TensorBytes<'a> {
+    dtype: Dtype,
+    shape: Vec<usize>,
+    data: Cow<'a, [u8]>,
+}
+
+impl View for &TensorBytes<'_> {
+    fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    fn data(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.data)
+    }
+
+    fn data_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// A writer of safetensors files with named tensors of any element type and rank.
+///
+/// On little-endian targets the tensors are borrowed and written without intermediate copies.
+///
+/// # Example
+///
+/// ```no_run
+/// use kornia_io::safetensors::{SafeTensorsFile, SafeTensorsWriter};
+/// use kornia_tensor::{CpuAllocator, Tensor};
+///
+/// let weights = Tensor::<f32, 2, _>::from_shape_val([16, 8], 0.5, CpuAllocator);
+/// let labels = Tensor::<i64, 1, _>::from_shape_val([16], 1, CpuAllocator);
+///
+/// let mut writer = SafeTensorsWriter::new();
+/// writer.add_tensor("weights", &weights);
+/// writer.add_tensor("labels", &labels);
+/// writer.add_metadata("format", "kornia");
+/// writer.write("/tmp/model.safetensors").unwrap();
+///
+/// let file = SafeTensorsFile::open("/tmp/model.safetensors").unwrap();
+/// let weights = file.tensor::<f32, 2>("weights").unwrap();
+/// assert_eq!(weights.shape, [16, 8]);
+/// ```
+#[derive(Default)]
+pub struct SafeTensorsWriter<'a> {
+    tensors: Vec<(String, TensorBytes<'a>)>,
+    metadata: HashMap<String, String>,
+}
+
+impl<'a> SafeTensorsWriter<'a> {
+    /// Create a new empty writer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tensor to the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tensor in the file.
+    /// * `tensor` - The tensor to add.
+    pub fn add_tensor<T, const N: usize, A>(&mut self, name: &str, tensor: &'a Tensor<T, N, A>)
+    where
+        T: SafeTensorsElement,
+        A: TensorAllocator + 'static,
+    {
+        let data = tensor.as_slice();
+        let data = if cfg!(target_endian = "little") {
+            // Safety
+            // The numeric element types have no padding, so their memory is plain bytes.
+            Cow::Borrowed(unsafe {
+                std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+            })
+        } else {
+            let mut bytes = Vec::with_capacity(std::mem::size_of_val(data));
+            data.iter().for_each(|x| x.extend_le_bytes(&mut bytes));
+            Cow::Owned(bytes)
+        };
+
+        self.tensors.push((
+            name.to_string(),
+            TensorBytes {
+                dtype: T::SAFETENSORS_DTYPE,
+                shape: tensor.shape.to_vec(),
+                data,
+            },
+        ));
+    }
+
+    /// Add a dynamic tensor to the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tensor in the file.
+    /// * `tensor` - The tensor to add.
+    pub fn add_dyn_tensor(&mut self, name: &str, tensor: &'a DynTensor) {
+        let dtype = tensor.dtype();
+        let data = if cfg!(target_endian = "little") {
+            Cow::Borrowed(tensor.as_bytes())
+        } else {
+            Cow::Owned(
+                tensor
+                    .as_bytes()
+                    .chunks_exact(dtype.size_of())
+                    .flat_map(|x| x.iter().rev().copied())
+                    .collect(),
+            )
+        };
+
+        self.tensors.push((
+            name.to_string(),
+            TensorBytes {
+                dtype: safetensors_from_dtype(dtype),
+                shape: tensor.shape().to_vec(),
+                data,
+            },
+        ));
+    }
+
+    /// Add a free-form metadata entry to the header of the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry.
+    /// * `value` - The value of the entry.
+    pub fn add_metadata(&mut self, key: &str, value: &str) {
+        self.metadata.insert(key.to_string(), value.to_string());
+    }
+
+    fn metadata(&self) -> Option<HashMap<String, String>> {
+        (!self.metadata.is_empty()).then(|| self.metadata.clone())
+    }
+
+    /// Serialize the tensors to the bytes of a safetensors file.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IoError> {
+        let data = self.tensors.iter().map(|(name, t)| (name.as_str(), t));
+        Ok(::safetensors::serialize(data, &self.metadata()).map_err(SafeTensorsError::from)?)
+    }
+
+    /// Write the tensors to a safetensors file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the safetensors file.
+    pub fn write(&self, file_path: impl AsRef<Path>) -> Result<(), IoError> {
+        let data = self.tensors.iter().map(|(name, t)| (name.as_str(), t));
+        ::safetensors::serialize_to_file(data, &self.metadata(), file_path.as_ref())
+            .map_err(SafeTensorsError::from)?;
+        Ok(())
+    }
+}
+
+/// A safetensors file mapped in memory.
+///
+/// The tensors are copied out of the memory mapping when loaded. [`SafeTensorsFile::tensor_mmap`]
+/// loads them without copying instead: they point to the mapping, which is kept alive until the
+/// file and all the loaded tensors are dropped. The mapping is copy-on-write, so the tensors can
+/// be modified without changing the file.
+///
+/// See [`SafeTensorsWriter`] for an example.
+pub struct SafeTensorsFile {
+    mmap: Arc<memmap2::MmapMut>,
+    /// The address of the start of the data buffer in the mapping.
+    data_ptr: usize,
+    metadata: Metadata,
+}
+
+impl SafeTensorsFile {
+    /// Open and map a safetensors file.
+    ///
+    /// The file must not be modified by other processes while it is mapped.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the safetensors file.
+    pub fn open(file_path: impl AsRef<Path>) -> Result<Self, IoError> {
+        let file_path = file_path.as_ref();
+        if !file_path.exists() {
+            return Err(IoError::FileDoesNotExist(file_path.to_path_buf()));
+        }
+
+        let file = File::open(file_path)?;
+        // Safety
+        // The mapping is private, changes of the tensors are not written to the file.
+        let mut mmap = unsafe { memmap2::MmapOptions::new().map_copy(&file)? };
+        let (header_len, metadata) =
+            SafeTensors::read_metadata(&mmap).map_err(SafeTensorsError::from)?;
+        let data_ptr = mmap.as_mut_ptr() as usize + 8 + header_len;
+
+        Ok(Self {
+            mmap: Arc::new(mmap),
+            data_ptr,
+            metadata,
+        })
+    }
+
+    /// Returns the names of the tensors in the file.
+    pub fn names(&self) -> Vec<String> {
+        self.metadata.tensors().into_keys().collect()
+    }
+
+    /// Returns the data type and the shape of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tensor in the file.
+    pub fn info(&self, name: &str) -> Option<(Dtype, &[usize])> {
+        self.metadata
+            .info(name)
+            .map(|info| (info.dtype, info.shape.as_slice()))
+    }
+
+    /// Returns the free-form metadata of the file.
+    pub fn metadata(&self) -> Option<&HashMap<String, String>> {
+        self.metadata.metadata().as_ref()
+    }
+
+    /// Load a tensor from the file.
+    ///
+    /// The data is copied out of the memory mapping, see [`SafeTensorsFile::tensor_mmap`] to load
+    /// it without copying.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tensor in the file.
+    ///
+    /// # Errors
+    ///
+    /// If the tensor is not found, or its data type or rank do not match, an error is returned.
+    pub fn tensor<T, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<Tensor<T, N, CpuAllocator>, IoError>
+    where
+        T: SafeTensorsElement,
+    {
+        let (info, shape) = self.typed_info::<T, N>(name)?;
+        self.copy(info, shape)
+    }
+
+    /// Load a tensor from the file without copying.
+    ///
+    /// The tensor points to the memory mapping if the data is aligned for `T` and the target is
+    /// little-endian, otherwise the data is copied like with [`SafeTensorsFile::tensor`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tensor in the file.
+    ///
+    /// # Errors
+    ///
+    /// If the tensor is not found, or its data type or rank do not match, an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// The returned tensor can be mutated and points to the memory mapping, so the same tensor
+    /// must not be loaded from the file again, with or without copying, while the returned tensor
+    /// is alive.
+    pub unsafe fn tensor_mmap<T, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<Tensor<T, N, CpuAllocator>, IoError>
+    where
+        T: SafeTensorsElement,
+    {
+        let (info, shape) = self.typed_info::<T, N>(name)?;
+        let ptr = self.data_ptr + info.data_offsets.0;
+        if cfg!(target_endian = "big") || ptr % std::mem::align_of::<T>() != 0 {
+            return self.copy(info, shape);
+        }
+
+        let mmap = self.mmap.clone();
+        // Safety
+        // The offsets were validated against the size of the mapping when parsing the header,
+        // and the mapping is kept alive by the tensor until it is dropped. The caller guarantees
+        // that no other tensor points to the same data.
+        let tensor = Tensor::from_foreign(
+            shape,
+            NonNull::new_unchecked(ptr as *mut T),
+            CpuAllocator,
+            move || drop(mmap),
+        );
+        Ok(tensor.map_err(SafeTensorsError::from)?)
+    }
+
+    /// Look up a tensor and check its data type and rank.
+    fn typed_info<T, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<(&TensorInfo, [usize; N]), IoError>
+    where
+        T: SafeTensorsElement,
+    {
+        let info = self
+            .metadata
+            .info(name)
+            .ok_or_else(|| SafeTensorsError::TensorNotFound(name.to_string()))?;
+        if info.dtype != T::SAFETENSORS_DTYPE {
+            return Err(SafeTensorsError::DtypeMismatch(T::SAFETENSORS_DTYPE, info.dtype).into());
+        }
+        let shape: [usize; N] = info
+            .shape
+            .as_slice()
+            .try_into()
+            .map_err(|_| SafeTensorsError::RankMismatch(info.shape.clone(), N))?;
+        Ok((info, shape))
+    }
+
+    /// Load a tensor of any data type and rank from the file.
+    ///
+    /// Like [`SafeTensorsFile::tensor`], the data is copied out of the memory mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tensor in the file.
+    ///
+    /// # Errors
+    ///
+    /// If the tensor is not found, or its data type is not supported, an error is returned.
+    pub fn dyn_tensor(&self, name: &str) -> Result<DynTensor, IoError> {
+        let info = self
+            .metadata
+            .info(name)
+            .ok_or_else(|| SafeTensorsError::TensorNotFound(name.to_string()))?;
+        match dtype_from_safetensors(info.dtype) {
+            Some(DType::U8) => self.load_dyn::<u8>(info),
+            Some(DType::I8) => self.load_dyn::<i8>(info),
+            Some(DType::U16) => self.load_dyn::<u16>(info),
+            Some(DType::I16) => self.load_dyn::<i16>(info),
+            Some(DType::U32) => self.load_dyn::<u32>(info),
+            Some(DType::I32) => self.load_dyn::<i32>(info),
+            Some(DType::U64) => self.load_dyn::<u64>(info),
+            Some(DType::I64) => self.load_dyn::<i64>(info),
+            Some(DType::F32) => self.load_dyn::<f32>(info),
+            Some(DType::F64) => self.load_dyn::<f64>(info),
+            #[cfg(feature = "half")]
+            Some(DType::F16) => self.load_dyn::<half::f16>(info),
+            #[cfg(feature = "half")]
+            Some(DType::BF16) => self.load_dyn::<half::bf16>(info),
+            _ => Err(SafeTensorsError::UnsupportedDtype(info.dtype).into()),
+        }
+    }
+
+    /// Load the data of a tensor as a flat tensor, then restore its shape.
+    fn load_dyn<T>(&self, info: &TensorInfo) -> Result<DynTensor, IoError>
+    where
+        T: SafeTensorsElement + DTypeElement,
+    {
+        let numel = info.shape.iter().product::<usize>();
+        let tensor = self.copy::<T, 1>(info, [numel])?;
+        Ok(DynTensor::from(tensor)
+            .reshape(info.shape.clone())
+            .map_err(SafeTensorsError::from)?)
+    }
+
+    /// Copy the data of a tensor out of the memory mapping.
+    fn copy<T, const N: usize>(
+        &self,
+        info: &TensorInfo,
+        shape: [usize; N],
+    ) -> Result<Tensor<T, N, CpuAllocator>, IoError>
+    where
+        T: SafeTensorsElement,
+    {
+        let (begin, end) = info.data_offsets;
+        let offset = self.data_ptr + begin - self.mmap.as_ptr() as usize;
+        let data = self.mmap[offset..offset + end - begin]
+            .chunks_exact(std::mem::size_of::<T>())
+            .map(T::from_le_slice)
+            .collect();
+        Ok(Tensor::from_shape_vec(shape, data, CpuAllocator).map_err(SafeTensorsError::from)?)
+    }
+}
+
+/// Write a collection of named tensors with the same element type and rank to a safetensors file.
+///
+/// Use [`SafeTensorsWriter`] to write tensors of different element types or ranks.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the safetensors file.
+/// * `tensors` - The tensors to write by name.
+/// * `metadata` - Optional free-form metadata stored in the header.
+pub fn write_safetensors<T, const N: usize, A>(
+    file_path: impl AsRef<Path>,
+    tensors: &HashMap<String, Tensor<T, N, A>>,
+    metadata: Option<&HashMap<String, String>>,
+) -> Result<(), IoError>
+where
+    T: SafeTensorsElement,
+    A: TensorAllocator + 'static,
+{
+    let mut writer = SafeTensorsWriter::new();
+    for (name, tensor) in tensors {
+        writer.add_tensor(name, tensor);
+    }
+    for (key, value) in metadata.into_iter().flatten() {
+        writer.add_metadata(key, value);
+    }
+    writer.write(file_path)
+}
+
+/// Read all the tensors of a safetensors file with the same element type and rank.
+///
+/// The tensors are copied out of the memory mapping of the file, see [`SafeTensorsFile::tensor`].
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the safetensors file.
+///
+/// # Returns
+///
+/// The tensors by name.
+pub fn read_safetensors<T, const N: usize>(
+    file_path: impl AsRef<Path>,
+) -> Result<HashMap<String, Tensor<T, N, CpuAllocator>>, IoError>
+where
+    T: SafeTensorsElement,
+{
+    let file = SafeTensorsFile::open(file_path)?;
+    file.names()
+        .into_iter()
+        .map(|name| {
+            let tensor = file.tensor(&name)?;
+            Ok((name, tensor))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safetensors_roundtrip() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("model.safetensors");
+
+        let weights = Tensor::<f32, 2, _>::from_shape_fn([3, 4], CpuAllocator, |[i, j]| {
+            i as f32 - j as f32 * 0.5
+        });
+        let mask = Tensor::<u8, 1, _>::from_shape_vec([3], vec![1, 0, 1], CpuAllocator)
+            .map_err(SafeTensorsError::from)?;
+        let ids = Tensor::<i64, 1, _>::from_shape_vec([2], vec![-7, 1 << 40], CpuAllocator)
+            .map_err(SafeTensorsError::from)?;
+
+        let mut writer = SafeTensorsWriter::new();
+        writer.add_tensor("weights", &weights);
+        writer.add_tensor("mask", &mask);
+        writer.add_tensor("ids", &ids);
+        writer.add_metadata("source", "kornia-rs");
+        writer.write(&file_path)?;
+
+        let file = SafeTensorsFile::open(&file_path)?;
+        let mut names = file.names();
+        names.sort();
+        assert_eq!(names, ["ids", "mask", "weights"]);
+        assert_eq!(file.info("weights"), Some((Dtype::F32, &[3usize, 4][..])));
+        assert_eq!(
+            file.metadata()
+                .and_then(|m| m.get("source"))
+                .map(String::as_str),
+            Some("kornia-rs")
+        );
+
+        assert_eq!(
+            file.tensor::<f32, 2>("weights")?.as_slice(),
+            weights.as_slice()
+        );
+        assert_eq!(file.tensor::<u8, 1>("mask")?.as_slice(), mask.as_slice());
+        assert_eq!(file.tensor::<i64, 1>("ids")?.as_slice(), ids.as_slice());
+
+        // the tensors loaded twice do not share their data
+        let mut a = file.tensor::<f32, 2>("weights")?;
+        let b = file.tensor::<f32, 2>("weights")?;
+        a.as_slice_mut()[1] = 7.0;
+        assert_eq!(b.as_slice(), weights.as_slice());
+
+        // the tensors loaded without copying outlive the file and can be modified
+        let mut weights2 = unsafe { file.tensor_mmap::<f32, 2>("weights")? };
+        assert_eq!(weights2.as_slice(), weights.as_slice());
+        drop(file);
+        weights2.as_slice_mut()[0] = 42.0;
+        assert_eq!(weights2.as_slice()[0], 42.0);
+        let file = SafeTensorsFile::open(&file_path)?;
+        assert_eq!(file.tensor::<f32, 2>("weights")?.as_slice()[0], 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_safetensors_errors() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("tensors.safetensors");
+
+        let mut tensors = HashMap::new();
+        tensors.insert(
+            "a".to_string(),
+            Tensor::<u16, 2, _>::from_shape_val([2, 2], 3, CpuAllocator),
+        );
+        write_safetensors(&file_path, &tensors, None)?;
+
+        let file = SafeTensorsFile::open(&file_path)?;
+        assert!(matches!(
+            file.tensor::<u16, 2>("b"),
+            Err(IoError::SafeTensorsError(SafeTensorsError::TensorNotFound(
+                _
+            )))
+        ));
+        assert!(matches!(
+            file.tensor::<f32, 2>("a"),
+            Err(IoError::SafeTensorsError(SafeTensorsError::DtypeMismatch(
+                Dtype::F32,
+                Dtype::U16
+            )))
+        ));
+        assert!(matches!(
+            file.tensor::<u16, 3>("a"),
+            Err(IoError::SafeTensorsError(SafeTensorsError::RankMismatch(
+                _,
+                3
+            )))
+        ));
+
+        let loaded = read_safetensors::<u16, 2>(&file_path)?;
+        assert_eq!(loaded["a"].as_slice(), [3, 3, 3, 3]);
+        Ok(())
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_safetensors_half() -> Result<(), IoError> {
+        use half::{bf16, f16};
+
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("half.safetensors");
+
+        let a = Tensor::<f16, 1, _>::from_shape_fn([3], CpuAllocator, |[i]| {
+            f16::from_f32(i as f32 * 0.5)
+        });
+        let b = Tensor::<bf16, 1, _>::from_shape_fn([3], CpuAllocator, |[i]| {
+            bf16::from_f32(-(i as f32))
+        });
+        let mut writer = SafeTensorsWriter::new();
+        writer.add_tensor("a", &a);
+        writer.add_tensor("b", &b);
+        writer.write(&file_path)?;
+
+        let file = SafeTensorsFile::open(&file_path)?;
+        assert_eq!(file.info("a"), Some((Dtype::F16, &[3usize][..])));
+        assert_eq!(file.tensor::<f16, 1>("a")?.as_slice(), a.as_slice());
+        assert_eq!(file.tensor::<bf16, 1>("b")?.as_slice(), b.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn test_safetensors_bytes() -> Result<(), IoError> {
+        let t = Tensor::<i32, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator)
+            .map_err(SafeTensorsError::from)?;
+        let mut writer = SafeTensorsWriter::new();
+        writer.add_tensor("test", &t);
+        let bytes = writer.to_bytes()?;
+
+        // same layout as the reference implementation
+        let tensors = SafeTensors::deserialize(&bytes).map_err(SafeTensorsError::from)?;
+        let view = tensors.tensor("test").map_err(SafeTensorsError::from)?;
+        assert_eq!(view.dtype(), Dtype::I32);
+        assert_eq!(view.shape(), [2, 2]);
+        assert_eq!(view.data()[..8], [1, 0, 0, 0, 2, 0, 0, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_safetensors_dyn() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        let file_path = tmp_dir.path().join("dyn.safetensors");
+
+        let a = DynTensor::from_shape_vec(vec![2, 3, 2], (0..12).map(|x| x as f32).collect())
+            .map_err(SafeTensorsError::from)?;
+        let b = DynTensor::from_shape_vec(vec![4], vec![1u8, 2, 3, 4])
+            .map_err(SafeTensorsError::from)?;
+        let mut writer = SafeTensorsWriter::new();
+        writer.add_dyn_tensor("a", &a);
+        writer.add_dyn_tensor("b", &b);
+        writer.write(&file_path)?;
+
+        let file = SafeTensorsFile::open(&file_path)?;
+        let a2 = file.dyn_tensor("a")?;
+        assert_eq!(a2.dtype(), DType::F32);
+        assert_eq!(a2.shape(), &[2, 3, 2]);
+        assert_eq!(a2.as_bytes(), a.as_bytes());
+        let b2 = file.dyn_tensor("b")?;
+        assert_eq!(b2.dtype(), DType::U8);
+        assert_eq!(
+            b2.as_slice::<u8>().map_err(SafeTensorsError::from)?,
+            &[1, 2, 3, 4]
+        );
+
+        // the typed and dynamic api read the same files
+        let a3 = file.tensor::<f32, 3>("a")?;
+        assert_eq!(
+            a3.as_slice(),
+            a.as_slice::<f32>().map_err(SafeTensorsError::from)?
+        );
+        assert!(matches!(
+            file.dyn_tensor("missing"),
+            Err(IoError::SafeTensorsError(SafeTensorsError::TensorNotFound(
+                _
+            )))
+        ));
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use std::any::Any;
 use crate::stream::{
     error::StreamCaptureError,
     rtsp::{rtsp_camera_pipeline_description, RTSPCameraConfig},
-    v4l2::{v4l2_camera_pipeline_description, V4L2CameraConfig},
+    v4l2::{v4l2_camera_pipeline_description_with_format, V4L2CameraConfig},
     StreamCapture,
 };
 
@@ -53,7 +53,12 @@ impl CameraCapture {
                     "device is empty".to_string(),
                 ));
             }
-            v4l2_camera_pipeline_description(&config.device, config.size, config.fps)
+            v4l2_camera_pipeline_description_with_format(
+                &config.device,
+                config.size,
+                config.fps,
+                config.format,
+            )
         } else if let Some(config) = config.as_any().downcast_ref::<RTSPCameraConfig>() {
             // check that the url is not empty
             if config.url.is_empty() {
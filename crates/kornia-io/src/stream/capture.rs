@@ -90,6 +90,43 @@ impl StreamCapture {
     ///
     /// An Option containing the last captured Image or None if no image has been captured yet.
     pub fn grab(&mut self) -> Result<Option<Image<u8, 3>>, StreamCaptureError> {
+        self.grab_frame(|buffer| buffer.to_owned())
+    }
+
+    /// Grabs the last captured raw 8-bit Bayer mosaic.
+    ///
+    /// The pipeline must deliver Bayer frames, e.g. a V4L2 camera configured with
+    /// [`crate::stream::V4L2PixelFormat::Bayer8`].
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the last captured mosaic or None if no image has been captured yet.
+    pub fn grab_bayer8(&mut self) -> Result<Option<Image<u8, 1>>, StreamCaptureError> {
+        self.grab_frame(|buffer| buffer.to_owned())
+    }
+
+    /// Grabs the last captured raw Bayer mosaic with 10 to 16 bits per pixel.
+    ///
+    /// The pipeline must deliver 16-bit little endian Bayer frames, e.g. a V4L2 camera configured
+    /// with [`crate::stream::V4L2PixelFormat::Bayer16`].
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the last captured mosaic or None if no image has been captured yet.
+    pub fn grab_bayer16(&mut self) -> Result<Option<Image<u16, 1>>, StreamCaptureError> {
+        self.grab_frame(|buffer| {
+            buffer
+                .chunks_exact(2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]))
+                .collect()
+        })
+    }
+
+    /// Takes the last captured frame and decodes its buffer into an image.
+    fn grab_frame<T: Clone, const C: usize>(
+        &mut self,
+        decode: impl Fn(&[u8]) -> Vec<T>,
+    ) -> Result<Option<Image<T, C>>, StreamCaptureError> {
         let mut last_frame = self
             .last_frame
             .lock()
@@ -98,9 +135,9 @@ impl StreamCapture {
         last_frame.take().map_or(Ok(None), |frame_buffer| {
             // TODO: solve the zero copy issue
             // https://discourse.gstreamer.org/t/zero-copy-video-frames/3856/2
-            let img = Image::<u8, 3>::new(
+            let img = Image::<T, C>::new(
                 [frame_buffer.width, frame_buffer.height].into(),
-                frame_buffer.buffer.to_owned(),
+                decode(&frame_buffer.buffer[..]),
             )
             .map_err(|_| StreamCaptureError::CreateImageFrameError)?;
 
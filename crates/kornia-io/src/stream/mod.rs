@@ -20,5 +20,5 @@ pub use crate::stream::camera::{CameraCapture, CameraCaptureConfig};
 pub use crate::stream::capture::StreamCapture;
 pub use crate::stream::error::StreamCaptureError;
 pub use crate::stream::rtsp::RTSPCameraConfig;
-pub use crate::stream::v4l2::V4L2CameraConfig;
+pub use crate::stream::v4l2::{V4L2CameraConfig, V4L2PixelFormat};
 pub use crate::stream::video::VideoWriter;
@@ -7,6 +7,41 @@ use crate::stream::{
 
 use kornia_image::ImageSize;
 
+/// The pixel format of the frames requested from a V4L2 camera.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum V4L2PixelFormat {
+    /// 8-bit RGB frames, converted by GStreamer from the camera format.
+    ///
+    /// Grab the frames with [`crate::stream::StreamCapture::grab`].
+    #[default]
+    Rgb8,
+    /// Raw 8-bit Bayer mosaics as delivered by the sensor.
+    ///
+    /// Grab the frames with [`crate::stream::StreamCapture::grab_bayer8`].
+    Bayer8,
+    /// Raw 10 to 16-bit Bayer mosaics as delivered by the sensor, stored in 16-bit little endian
+    /// words without rescaling.
+    ///
+    /// Grab the frames with [`crate::stream::StreamCapture::grab_bayer16`].
+    Bayer16,
+}
+
+impl V4L2PixelFormat {
+    /// Returns the GStreamer caps of the format.
+    fn caps(&self) -> &'static str {
+        match self {
+            V4L2PixelFormat::Rgb8 => "video/x-raw",
+            V4L2PixelFormat::Bayer8 => "video/x-bayer,format=(string){rggb,bggr,grbg,gbrg}",
+            V4L2PixelFormat::Bayer16 => concat!(
+                "video/x-bayer,format=(string){",
+                "rggb16le,bggr16le,grbg16le,gbrg16le,",
+                "rggb12le,bggr12le,grbg12le,gbrg12le,",
+                "rggb10le,bggr10le,grbg10le,gbrg10le}"
+            ),
+        }
+    }
+}
+
 /// A configuration object for capturing frames from a V4L2 camera.
 pub struct V4L2CameraConfig {
     /// The camera device path
@@ -15,6 +50,8 @@ pub struct V4L2CameraConfig {
     pub size: Option<ImageSize>,
     /// The desired frames per second
     pub fps: u32,
+    /// The pixel format of the captured frames, RGB by default
+    pub format: V4L2PixelFormat,
 }
 
 impl CameraCaptureConfig for V4L2CameraConfig {
@@ -26,7 +63,8 @@ impl CameraCaptureConfig for V4L2CameraConfig {
 impl V4L2CameraConfig {
     /// Creates a new V4L2CameraConfig object with default values.
     ///
-    /// Note: The default device is "/dev/video0", the default image size is None, and the default fps is 30.
+    /// Note: The default device is "/dev/video0", the default image size is None, the default fps is 30,
+    /// and the default format is RGB.
     ///
     /// # Returns
     ///
@@ -36,6 +74,7 @@ impl V4L2CameraConfig {
             device: "/dev/video0".to_string(),
             size: None,
             fps: 30,
+            format: V4L2PixelFormat::Rgb8,
         }
     }
 
@@ -79,6 +118,19 @@ impl V4L2CameraConfig {
         self
     }
 
+    /// Sets the pixel format of the captured frames.
+    ///
+    /// Use one of the Bayer formats to grab the raw sensor mosaics and demosaic them with
+    /// `kornia_imgproc::color::rgb_from_bayer`.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The desired pixel format
+    pub fn with_format(mut self, format: V4L2PixelFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     /// Create a new [`CameraCapture`] object.
     pub fn build(self) -> Result<CameraCapture, StreamCaptureError> {
         CameraCapture::new(&self)
@@ -91,7 +143,7 @@ impl Default for V4L2CameraConfig {
     }
 }
 
-/// Returns a GStreamer pipeline string for capturing frames from a V4L2 camera.
+/// Returns a GStreamer pipeline string for capturing RGB frames from a V4L2 camera.
 ///
 /// # Arguments
 ///
@@ -103,14 +155,42 @@ impl Default for V4L2CameraConfig {
 ///
 /// A GStreamer pipeline string
 pub fn v4l2_camera_pipeline_description(device: &str, size: Option<ImageSize>, fps: u32) -> String {
+    v4l2_camera_pipeline_description_with_format(device, size, fps, V4L2PixelFormat::Rgb8)
+}
+
+/// Returns a GStreamer pipeline string for capturing frames of a given format from a V4L2 camera.
+///
+/// # Arguments
+///
+/// * `device` - The camera device path
+/// * `size` - The image size to capture
+/// * `fps` - The desired frames per second
+/// * `format` - The pixel format of the captured frames
+///
+/// # Returns
+///
+/// A GStreamer pipeline string
+pub fn v4l2_camera_pipeline_description_with_format(
+    device: &str,
+    size: Option<ImageSize>,
+    fps: u32,
+    format: V4L2PixelFormat,
+) -> String {
+    let caps = format.caps();
     let video_resize = if let Some(size) = size {
-        format!("! video/x-raw,width={},height={} ", size.width, size.height)
+        format!("! {},width={},height={} ", caps, size.width, size.height)
     } else {
         "".to_string()
     };
 
+    // the raw mosaics must reach the sink untouched
+    let convert = match format {
+        V4L2PixelFormat::Rgb8 => "! videoconvert ! video/x-raw,format=RGB ",
+        V4L2PixelFormat::Bayer8 | V4L2PixelFormat::Bayer16 => "",
+    };
+
     format!(
-            "v4l2src device={} {}! videorate ! video/x-raw,framerate={}/1 ! videoconvert ! video/x-raw,format=RGB ! appsink name=sink",
-            device, video_resize, fps
-        )
+        "v4l2src device={} {}! videorate ! {},framerate={}/1 {}! appsink name=sink",
+        device, video_resize, caps, fps, convert
+    )
 }
@@ -8,6 +8,30 @@ pub enum TensorOpsError {
     #[error("Dimension out of bounds. The dimension {0} is out of bounds ({1}).")]
     DimOutOfBounds(usize, usize),
 
+    /// A dimension appears more than once in the list of dimensions to reduce.
+    #[error("Dimension {0} is repeated in the list of dimensions to reduce.")]
+    RepeatedDim(usize),
+
+    /// The rank of the output tensor does not match the reduction.
+    #[error("Invalid output rank {0}, the reduction produces a tensor of rank {1}.")]
+    InvalidOutputRank(usize, usize),
+
+    /// The reduction requires at least one element.
+    #[error("Cannot reduce over an empty set of elements.")]
+    EmptyReduction,
+
+    /// The shapes of the tensors are not valid for the operation.
+    #[error("Shape mismatch: {0}")]
+    ShapeMismatch(String),
+
+    /// The matrix is singular and cannot be inverted.
+    #[error("The matrix is singular.")]
+    SingularMatrix,
+
+    /// An iterative algorithm did not converge.
+    #[error("The algorithm did not converge after {0} iterations.")]
+    NotConverged(usize),
+
     /// Tensor error
     #[error("Error with the tensor: {0}")]
     TensorError(#[from] TensorError),
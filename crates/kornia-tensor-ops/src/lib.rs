@@ -4,5 +4,8 @@
 /// Error types for the core-ops module.
 pub mod error;
 
+/// module containing linear algebra operations.
+pub mod linalg;
+
 /// module containing ops implementations.
 pub mod ops;
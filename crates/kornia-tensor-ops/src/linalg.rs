@@ -0,0 +1,1364 @@
+use kornia_tensor::{Tensor1, Tensor2, Tensor3, TensorAllocator};
+use num_traits::{Float, Zero};
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+use std::ops::Mul;
+
+use crate::error::TensorOpsError;
+
+/// Minimum number of multiply-add operations of a matrix product to run it in parallel.
+const PARALLEL_THRESHOLD: usize = 1 << 18;
+
+/// Maximum number of sweeps of the Jacobi iterations used by the native backend.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// A backend implementing the matrix decompositions.
+///
+/// The matrices are passed as row-major buffers. The tensor level functions of this module
+/// take care of validating the shapes, so implementations can assume consistent sizes.
+///
+/// [`NativeBackend`] is always available. When the `faer` feature is enabled, the
+/// `FaerBackend` delegates the decompositions to the [faer](https://docs.rs/faer) crate.
+pub trait LinalgBackend<T> {
+    /// Solve the linear system `A * X = B` in place, where `A` is `n x n` and `B` is `n x nrhs`.
+    ///
+    /// # Errors
+    ///
+    /// If the matrix `A` is singular, an error is returned.
+    fn solve(&self, a: &[T], b: &mut [T], n: usize, nrhs: usize) -> Result<(), TensorOpsError>;
+
+    /// Compute the thin QR decomposition of a `rows x cols` matrix.
+    ///
+    /// # Returns
+    ///
+    /// The `rows x k` orthonormal matrix `Q` and the `k x cols` upper triangular matrix `R`,
+    /// where `k = min(rows, cols)`.
+    fn qr(&self, a: &[T], rows: usize, cols: usize) -> Result<(Vec<T>, Vec<T>), TensorOpsError>;
+
+    /// Compute the thin singular value decomposition of a `rows x cols` matrix.
+    ///
+    /// # Returns
+    ///
+    /// The `rows x k` matrix `U`, the `k` singular values in descending order and the
+    /// `k x cols` matrix `V^T`, where `k = min(rows, cols)`.
+    #[allow(clippy::type_complexity)]
+    fn svd(
+        &self,
+        a: &[T],
+        rows: usize,
+        cols: usize,
+    ) -> Result<(Vec<T>, Vec<T>, Vec<T>), TensorOpsError>;
+
+    /// Compute the eigendecomposition of a symmetric `n x n` matrix.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues in ascending order and the `n x n` matrix with the corresponding
+    /// eigenvectors as columns.
+    fn eigh(&self, a: &[T], n: usize) -> Result<(Vec<T>, Vec<T>), TensorOpsError>;
+}
+
+/// A pure Rust backend for the matrix decompositions.
+///
+/// It uses LU with partial pivoting to solve linear systems, Householder reflections for the
+/// QR decomposition and Jacobi rotations for the eigendecomposition and the SVD, which are
+/// accurate and well suited to the small matrices common in geometry.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NativeBackend;
+
+impl<T: Float> LinalgBackend<T> for NativeBackend {
+    fn solve(&self, a: &[T], b: &mut [T], n: usize, nrhs: usize) -> Result<(), TensorOpsError> {
+        let mut lu = a.to_vec();
+
+        // tolerance relative to the magnitude of the matrix to detect singularity
+        let scale = lu.iter().fold(T::zero(), |acc, &x| acc.max(x.abs()));
+        let tol = scale * T::epsilon() * T::from(n).unwrap_or_else(T::one);
+
+        for k in 0..n {
+            // partial pivoting: pick the row with the largest value in the column
+            let pivot = (k..n)
+                .max_by(|&i, &j| {
+                    lu[i * n + k]
+                        .abs()
+                        .partial_cmp(&lu[j * n + k].abs())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+            let pivot_value = lu[pivot * n + k].abs();
+            if pivot_value.is_nan() || pivot_value <= tol {
+                return Err(TensorOpsError::SingularMatrix);
+            }
+            if pivot != k {
+                for j in 0..n {
+                    lu.swap(k * n + j, pivot * n + j);
+                }
+                for j in 0..nrhs {
+                    b.swap(k * nrhs + j, pivot * nrhs + j);
+                }
+            }
+
+            for i in k + 1..n {
+                let factor = lu[i * n + k] / lu[k * n + k];
+                for j in k..n {
+                    lu[i * n + j] = lu[i * n + j] - factor * lu[k * n + j];
+                }
+                for j in 0..nrhs {
+                    b[i * nrhs + j] = b[i * nrhs + j] - factor * b[k * nrhs + j];
+                }
+            }
+        }
+
+        // back substitution
+        for i in (0..n).rev() {
+            for j in 0..nrhs {
+                let mut acc = b[i * nrhs + j];
+                for k in i + 1..n {
+                    acc = acc - lu[i * n + k] * b[k * nrhs + j];
+                }
+                b[i * nrhs + j] = acc / lu[i * n + i];
+            }
+        }
+
+        Ok(())
+    }
+
+    fn qr(&self, a: &[T], rows: usize, cols: usize) -> Result<(Vec<T>, Vec<T>), TensorOpsError> {
+        let k = rows.min(cols);
+        let mut r = a.to_vec();
+        // accumulate the reflections in a full rows x rows matrix
+        let mut q = identity(rows);
+
+        for j in 0..k {
+            // householder vector that zeros the column below the diagonal
+            let norm = (j..rows)
+                .fold(T::zero(), |acc, i| acc + r[i * cols + j] * r[i * cols + j])
+                .sqrt();
+            if norm.is_zero() {
+                continue;
+            }
+            let alpha = if r[j * cols + j] > T::zero() {
+                -norm
+            } else {
+                norm
+            };
+            let mut v = vec![T::zero(); rows];
+            for i in j..rows {
+                v[i] = r[i * cols + j];
+            }
+            v[j] = v[j] - alpha;
+            let v_norm2 = (j..rows).fold(T::zero(), |acc, i| acc + v[i] * v[i]);
+            if v_norm2.is_zero() {
+                continue;
+            }
+            let two = T::one() + T::one();
+
+            // R = H * R
+            for c in 0..cols {
+                let dot = (j..rows).fold(T::zero(), |acc, i| acc + v[i] * r[i * cols + c]);
+                let f = two * dot / v_norm2;
+                for i in j..rows {
+                    r[i * cols + c] = r[i * cols + c] - f * v[i];
+                }
+            }
+
+            // Q = Q * H
+            for row in 0..rows {
+                let dot = (j..rows).fold(T::zero(), |acc, i| acc + q[row * rows + i] * v[i]);
+                let f = two * dot / v_norm2;
+                for i in j..rows {
+                    q[row * rows + i] = q[row * rows + i] - f * v[i];
+                }
+            }
+        }
+
+        // keep the thin factors
+        let mut q_thin = vec![T::zero(); rows * k];
+        for i in 0..rows {
+            q_thin[i * k..(i + 1) * k].copy_from_slice(&q[i * rows..i * rows + k]);
+        }
+        let mut r_thin = vec![T::zero(); k * cols];
+        for i in 0..k {
+            for j in i..cols {
+                r_thin[i * cols + j] = r[i * cols + j];
+            }
+        }
+
+        Ok((q_thin, r_thin))
+    }
+
+    fn svd(
+        &self,
+        a: &[T],
+        rows: usize,
+        cols: usize,
+    ) -> Result<(Vec<T>, Vec<T>, Vec<T>), TensorOpsError> {
+        // the one-sided jacobi method needs at least as many rows as columns
+        if rows < cols {
+            let (u, s, vt) = self.svd(&transpose_buf(a, rows, cols), cols, rows)?;
+            return Ok((
+                transpose_buf(&vt, rows, rows),
+                s,
+                transpose_buf(&u, cols, rows),
+            ));
+        }
+
+        let n = cols;
+        let mut u = a.to_vec();
+        let mut v = identity(n);
+        let tol = T::epsilon();
+
+        let mut converged = false;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                    for i in 0..rows {
+                        let (up, uq) = (u[i * n + p], u[i * n + q]);
+                        alpha = alpha + up * up;
+                        beta = beta + uq * uq;
+                        gamma = gamma + up * uq;
+                    }
+                    if gamma.abs() <= tol * (alpha * beta).sqrt() || gamma.is_zero() {
+                        continue;
+                    }
+                    rotated = true;
+                    let (c, s) = jacobi_rotation(alpha, beta, gamma);
+                    rotate_columns(&mut u, rows, n, p, q, c, s);
+                    rotate_columns(&mut v, n, n, p, q, c, s);
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(TensorOpsError::NotConverged(MAX_JACOBI_SWEEPS));
+        }
+
+        // the singular values are the norms of the orthogonalized columns
+        let norms = (0..n)
+            .map(|j| (0..rows).fold(T::zero(), |acc, i| acc + u[i * n + j] * u[i * n + j]))
+            .map(|x| x.sqrt())
+            .collect::<Vec<_>>();
+        let mut order = (0..n).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| {
+            norms[j]
+                .partial_cmp(&norms[i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let s_max = norms.iter().fold(T::zero(), |acc, &x| acc.max(x));
+        let rank_tol = s_max * T::epsilon() * T::from(rows).unwrap_or_else(T::one);
+
+        let mut u_out = vec![T::zero(); rows * n];
+        let mut s_out = vec![T::zero(); n];
+        let mut vt_out = vec![T::zero(); n * n];
+        let mut valid = vec![false; n];
+        for (k, &j) in order.iter().enumerate() {
+            s_out[k] = norms[j];
+            if norms[j] > rank_tol {
+                valid[k] = true;
+                for i in 0..rows {
+                    u_out[i * n + k] = u[i * n + j] / norms[j];
+                }
+            }
+            for i in 0..n {
+                vt_out[k * n + i] = v[i * n + j];
+            }
+        }
+
+        // complete the left singular vectors of the null singular values
+        complete_orthonormal_columns(&mut u_out, rows, n, &valid);
+
+        Ok((u_out, s_out, vt_out))
+    }
+
+    fn eigh(&self, a: &[T], n: usize) -> Result<(Vec<T>, Vec<T>), TensorOpsError> {
+        let mut m = a.to_vec();
+        let mut v = identity(n);
+
+        let mut converged = n < 2;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let off = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .fold(T::zero(), |acc, (i, j)| acc + m[i * n + j] * m[i * n + j]);
+            let diag = (0..n).fold(T::zero(), |acc, i| acc + m[i * n + i] * m[i * n + i]);
+            if off <= T::epsilon() * T::epsilon() * diag || off.is_zero() {
+                converged = true;
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = m[p * n + q];
+                    if apq.is_zero() {
+                        continue;
+                    }
+                    let (c, s) = jacobi_rotation(m[p * n + p], m[q * n + q], apq);
+                    // M = J^T * M * J
+                    rotate_columns(&mut m, n, n, p, q, c, s);
+                    for k in 0..n {
+                        let (mp, mq) = (m[p * n + k], m[q * n + k]);
+                        m[p * n + k] = c * mp - s * mq;
+                        m[q * n + k] = s * mp + c * mq;
+                    }
+                    rotate_columns(&mut v, n, n, p, q, c, s);
+                }
+            }
+        }
+        if !converged {
+            return Err(TensorOpsError::NotConverged(MAX_JACOBI_SWEEPS));
+        }
+
+        let mut order = (0..n).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| {
+            m[i * n + i]
+                .partial_cmp(&m[j * n + j])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let values = order.iter().map(|&j| m[j * n + j]).collect();
+        let mut vectors = vec![T::zero(); n * n];
+        for (k, &j) in order.iter().enumerate() {
+            for i in 0..n {
+                vectors[i * n + k] = v[i * n + j];
+            }
+        }
+
+        Ok((values, vectors))
+    }
+}
+
+/// Compute the cosine and sine of the jacobi rotation that diagonalizes the 2x2 symmetric
+/// matrix `[[alpha, gamma], [gamma, beta]]`.
+fn jacobi_rotation<T: Float>(alpha: T, beta: T, gamma: T) -> (T, T) {
+    let two = T::one() + T::one();
+    let zeta = (beta - alpha) / (two * gamma);
+    let sign = if zeta >= T::zero() {
+        T::one()
+    } else {
+        -T::one()
+    };
+    let t = sign / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+    let c = T::one() / (T::one() + t * t).sqrt();
+    (c, c * t)
+}
+
+/// Apply a plane rotation to the columns `p` and `q` of a row-major matrix.
+fn rotate_columns<T: Float>(m: &mut [T], rows: usize, cols: usize, p: usize, q: usize, c: T, s: T) {
+    for i in 0..rows {
+        let (mp, mq) = (m[i * cols + p], m[i * cols + q]);
+        m[i * cols + p] = c * mp - s * mq;
+        m[i * cols + q] = s * mp + c * mq;
+    }
+}
+
+/// Replace the invalid columns of a row-major matrix with orthonormal vectors.
+fn complete_orthonormal_columns<T: Float>(m: &mut [T], rows: usize, cols: usize, valid: &[bool]) {
+    let mut candidate = 0;
+    for j in (0..cols).filter(|&j| !valid[j]) {
+        // gram-schmidt on the canonical basis until an independent vector is found
+        while candidate < rows {
+            let mut e = vec![T::zero(); rows];
+            e[candidate] = T::one();
+            candidate += 1;
+            for k in (0..cols).filter(|&k| k != j && (valid[k] || k < j)) {
+                let dot = (0..rows).fold(T::zero(), |acc, i| acc + e[i] * m[i * cols + k]);
+                for (i, ei) in e.iter_mut().enumerate() {
+                    *ei = *ei - dot * m[i * cols + k];
+                }
+            }
+            let norm = e.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+            if norm > T::from(1e-3).unwrap_or_else(T::epsilon) {
+                for (i, ei) in e.iter().enumerate() {
+                    m[i * cols + j] = *ei / norm;
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// Create a row-major identity matrix.
+fn identity<T: Float>(n: usize) -> Vec<T> {
+    let mut m = vec![T::zero(); n * n];
+    for i in 0..n {
+        m[i * n + i] = T::one();
+    }
+    m
+}
+
+/// Transpose a row-major `rows x cols` buffer.
+fn transpose_buf<T: Copy>(a: &[T], rows: usize, cols: usize) -> Vec<T> {
+    let mut out = Vec::with_capacity(a.len());
+    for j in 0..cols {
+        for i in 0..rows {
+            out.push(a[i * cols + j]);
+        }
+    }
+    out
+}
+
+/// Compute the product of two row-major matrices into `out`.
+fn matmul_buf<T>(a: &[T], b: &[T], out: &mut [T], m: usize, k: usize, n: usize)
+where
+    T: Zero + Mul<Output = T> + Copy + Send + Sync,
+{
+    let row_op = |(i, out_row): (usize, &mut [T])| {
+        out_row.iter_mut().for_each(|x| *x = T::zero());
+        for (p, &a_ip) in a[i * k..(i + 1) * k].iter().enumerate() {
+            for (o, &b_pj) in out_row.iter_mut().zip(b[p * n..(p + 1) * n].iter()) {
+                *o = *o + a_ip * b_pj;
+            }
+        }
+    };
+
+    if n == 0 {
+        return;
+    }
+    if m * k * n >= PARALLEL_THRESHOLD {
+        out.par_chunks_exact_mut(n).enumerate().for_each(row_op);
+    } else {
+        out.chunks_exact_mut(n).enumerate().for_each(row_op);
+    }
+}
+
+/// Check that a matrix is square.
+fn check_square<T, A>(a: &Tensor2<T, A>) -> Result<usize, TensorOpsError>
+where
+    A: TensorAllocator,
+{
+    let [rows, cols] = a.shape;
+    if rows != cols {
+        return Err(TensorOpsError::ShapeMismatch(format!(
+            "Expected a square matrix, got shape {:?}",
+            a.shape
+        )));
+    }
+    Ok(rows)
+}
+
+/// Check that a batch of matrices is square.
+fn check_square_batch<T, A>(a: &Tensor3<T, A>) -> Result<usize, TensorOpsError>
+where
+    A: TensorAllocator,
+{
+    let [_, rows, cols] = a.shape;
+    if rows != cols {
+        return Err(TensorOpsError::ShapeMismatch(format!(
+            "Expected a batch of square matrices, got shape {:?}",
+            a.shape
+        )));
+    }
+    Ok(rows)
+}
+
+/// Apply a decomposition to each matrix of a batch in parallel.
+///
+/// # Returns
+///
+/// The outputs of the decomposition, in the order of the batch.
+fn map_batch<T, R, F>(data: &[T], batch: usize, size: usize, f: F) -> Result<Vec<R>, TensorOpsError>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> Result<R, TensorOpsError> + Sync + Send,
+{
+    if size == 0 {
+        return (0..batch).map(|_| f(&[])).collect();
+    }
+    data.par_chunks_exact(size).map(f).collect()
+}
+
+/// Solve a batch of linear systems in place with a backend, in parallel.
+fn solve_batch<T, B>(
+    a: &[T],
+    x: &mut [T],
+    n: usize,
+    nrhs: usize,
+    backend: &B,
+) -> Result<(), TensorOpsError>
+where
+    T: Send + Sync,
+    B: LinalgBackend<T> + Sync,
+{
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+    x.par_chunks_exact_mut(n * nrhs)
+        .zip(a.par_chunks_exact(n * n))
+        .try_for_each(|(x, a)| backend.solve(a, x, n, nrhs))
+}
+
+/// Compute the matrix product of two 2D tensors.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side matrix with shape `[m, k]`.
+/// * `b` - The right hand side matrix with shape `[k, n]`.
+///
+/// # Returns
+///
+/// The product matrix with shape `[m, n]`.
+///
+/// # Errors
+///
+/// If the inner dimensions of the matrices do not match, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor2, CpuAllocator};
+/// use kornia_tensor_ops::linalg::matmul;
+///
+/// let a = Tensor2::<f32, _>::from_shape_vec([2, 3], vec![1., 2., 3., 4., 5., 6.], CpuAllocator).unwrap();
+/// let b = Tensor2::<f32, _>::from_shape_vec([3, 1], vec![1., 0., 1.], CpuAllocator).unwrap();
+/// let c = matmul(&a, &b).unwrap();
+/// assert_eq!(c.shape, [2, 1]);
+/// assert_eq!(c.as_slice(), [4., 10.]);
+/// ```
+pub fn matmul<T, A>(a: &Tensor2<T, A>, b: &Tensor2<T, A>) -> Result<Tensor2<T, A>, TensorOpsError>
+where
+    T: Zero + Mul<Output = T> + Copy + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    let ([m, k], [k2, n]) = (a.shape, b.shape);
+    if k != k2 {
+        return Err(TensorOpsError::ShapeMismatch(format!(
+            "Cannot multiply matrices of shapes {:?} and {:?}",
+            a.shape, b.shape
+        )));
+    }
+
+    let mut out = vec![T::zero(); m * n];
+    matmul_buf(a.as_slice(), b.as_slice(), &mut out, m, k, n);
+
+    Ok(Tensor2::from_shape_vec(
+        [m, n],
+        out,
+        a.storage.alloc().clone(),
+    )?)
+}
+
+/// Compute the batched matrix product of two 3D tensors.
+///
+/// # Arguments
+///
+/// * `a` - The left hand side batch of matrices with shape `[b, m, k]`.
+/// * `b` - The right hand side batch of matrices with shape `[b, k, n]`.
+///
+/// # Returns
+///
+/// The batch of product matrices with shape `[b, m, n]`.
+///
+/// # Errors
+///
+/// If the batch sizes or the inner dimensions do not match, an error is returned.
+pub fn bmm<T, A>(a: &Tensor3<T, A>, b: &Tensor3<T, A>) -> Result<Tensor3<T, A>, TensorOpsError>
+where
+    T: Zero + Mul<Output = T> + Copy + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    let ([batch, m, k], [batch2, k2, n]) = (a.shape, b.shape);
+    if batch != batch2 || k != k2 {
+        return Err(TensorOpsError::ShapeMismatch(format!(
+            "Cannot multiply batches of matrices of shapes {:?} and {:?}",
+            a.shape, b.shape
+        )));
+    }
+
+    let mut out = vec![T::zero(); batch * m * n];
+    if m * n > 0 {
+        let (a_data, b_data) = (a.as_slice(), b.as_slice());
+        let batch_op = |(i, out_mat): (usize, &mut [T])| {
+            let a_mat = &a_data[i * m * k..(i + 1) * m * k];
+            let b_mat = &b_data[i * k * n..(i + 1) * k * n];
+            matmul_buf(a_mat, b_mat, out_mat, m, k, n);
+        };
+        if batch * m * k * n >= PARALLEL_THRESHOLD {
+            out.par_chunks_exact_mut(m * n)
+                .enumerate()
+                .for_each(batch_op);
+        } else {
+            out.chunks_exact_mut(m * n).enumerate().for_each(batch_op);
+        }
+    }
+
+    Ok(Tensor3::from_shape_vec(
+        [batch, m, n],
+        out,
+        a.storage.alloc().clone(),
+    )?)
+}
+
+/// Compute the transpose of a 2D tensor as a new contiguous tensor.
+///
+/// For a zero-copy transpose use [`kornia_tensor::view::TensorView::transpose`].
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor2, CpuAllocator};
+/// use kornia_tensor_ops::linalg::transpose;
+///
+/// let a = Tensor2::<u8, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
+/// let t = transpose(&a);
+/// assert_eq!(t.shape, [3, 2]);
+/// assert_eq!(t.as_slice(), [1, 4, 2, 5, 3, 6]);
+/// ```
+pub fn transpose<T, A>(a: &Tensor2<T, A>) -> Tensor2<T, A>
+where
+    T: Copy,
+    A: TensorAllocator + 'static,
+{
+    let [rows, cols] = a.shape;
+    let data = transpose_buf(a.as_slice(), rows, cols);
+    Tensor2::from_shape_vec([cols, rows], data, a.storage.alloc().clone())
+        .expect("the transposed data has the same number of elements")
+}
+
+/// Solve the linear system `A * X = B`.
+///
+/// # Arguments
+///
+/// * `a` - The square coefficient matrix with shape `[n, n]`.
+/// * `b` - The right hand side with shape `[n, nrhs]`.
+/// * `backend` - The backend computing the solution, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// The solution `X` with shape `[n, nrhs]`.
+///
+/// # Errors
+///
+/// If the shapes are not compatible or the matrix is singular, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor2, CpuAllocator};
+/// use kornia_tensor_ops::linalg::{solve, NativeBackend};
+///
+/// let a = Tensor2::<f64, _>::from_shape_vec([2, 2], vec![2., 1., 1., 3.], CpuAllocator).unwrap();
+/// let b = Tensor2::<f64, _>::from_shape_vec([2, 1], vec![3., 5.], CpuAllocator).unwrap();
+/// let x = solve(&a, &b, &NativeBackend).unwrap();
+/// assert!((x.as_slice()[0] - 0.8).abs() < 1e-12);
+/// assert!((x.as_slice()[1] - 1.4).abs() < 1e-12);
+/// ```
+pub fn solve<T, A, B>(
+    a: &Tensor2<T, A>,
+    b: &Tensor2<T, A>,
+    backend: &B,
+) -> Result<Tensor2<T, A>, TensorOpsError>
+where
+    T: Float,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T>,
+{
+    let n = check_square(a)?;
+    let [rows, nrhs] = b.shape;
+    if rows != n {
+        return Err(TensorOpsError::ShapeMismatch(format!(
+            "Cannot solve a system with matrix of shape {:?} and right hand side of shape {:?}",
+            a.shape, b.shape
+        )));
+    }
+
+    let mut x = b.as_slice().to_vec();
+    backend.solve(a.as_slice(), &mut x, n, nrhs)?;
+
+    Ok(Tensor2::from_shape_vec(
+        [n, nrhs],
+        x,
+        a.storage.alloc().clone(),
+    )?)
+}
+
+/// Compute the inverse of a square matrix.
+///
+/// # Arguments
+///
+/// * `a` - The square matrix with shape `[n, n]`.
+/// * `backend` - The backend computing the inverse, e.g. [`NativeBackend`].
+///
+/// # Errors
+///
+/// If the matrix is not square or is singular, an error is returned.
+pub fn inverse<T, A, B>(a: &Tensor2<T, A>, backend: &B) -> Result<Tensor2<T, A>, TensorOpsError>
+where
+    T: Float,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T>,
+{
+    let n = check_square(a)?;
+    let mut x = identity(n);
+    backend.solve(a.as_slice(), &mut x, n, n)?;
+
+    Ok(Tensor2::from_shape_vec(
+        [n, n],
+        x,
+        a.storage.alloc().clone(),
+    )?)
+}
+
+/// Compute the thin QR decomposition of a matrix.
+///
+/// # Arguments
+///
+/// * `a` - The matrix with shape `[m, n]`.
+/// * `backend` - The backend computing the decomposition, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// A tuple with the orthonormal matrix `Q` with shape `[m, k]` and the upper triangular
+/// matrix `R` with shape `[k, n]`, where `k = min(m, n)`.
+#[allow(clippy::type_complexity)]
+pub fn qr<T, A, B>(
+    a: &Tensor2<T, A>,
+    backend: &B,
+) -> Result<(Tensor2<T, A>, Tensor2<T, A>), TensorOpsError>
+where
+    T: Float,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T>,
+{
+    let [m, n] = a.shape;
+    let k = m.min(n);
+    let (q, r) = backend.qr(a.as_slice(), m, n)?;
+    let alloc = a.storage.alloc();
+
+    Ok((
+        Tensor2::from_shape_vec([m, k], q, alloc.clone())?,
+        Tensor2::from_shape_vec([k, n], r, alloc.clone())?,
+    ))
+}
+
+/// Compute the thin singular value decomposition of a matrix.
+///
+/// The matrix is decomposed as `A = U * diag(S) * V^T`.
+///
+/// # Arguments
+///
+/// * `a` - The matrix with shape `[m, n]`.
+/// * `backend` - The backend computing the decomposition, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// A tuple with `U` with shape `[m, k]`, the singular values `S` in descending order with
+/// shape `[k]` and `V^T` with shape `[k, n]`, where `k = min(m, n)`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor2, CpuAllocator};
+/// use kornia_tensor_ops::linalg::{svd, NativeBackend};
+///
+/// let a = Tensor2::<f64, _>::from_shape_vec([2, 2], vec![3., 0., 0., -2.], CpuAllocator).unwrap();
+/// let (u, s, vt) = svd(&a, &NativeBackend).unwrap();
+/// assert_eq!(s.as_slice(), [3., 2.]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn svd<T, A, B>(
+    a: &Tensor2<T, A>,
+    backend: &B,
+) -> Result<(Tensor2<T, A>, Tensor1<T, A>, Tensor2<T, A>), TensorOpsError>
+where
+    T: Float,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T>,
+{
+    let [m, n] = a.shape;
+    let k = m.min(n);
+    let (u, s, vt) = backend.svd(a.as_slice(), m, n)?;
+    let alloc = a.storage.alloc();
+
+    Ok((
+        Tensor2::from_shape_vec([m, k], u, alloc.clone())?,
+        Tensor1::from_shape_vec([k], s, alloc.clone())?,
+        Tensor2::from_shape_vec([k, n], vt, alloc.clone())?,
+    ))
+}
+
+/// Compute the eigendecomposition of a symmetric matrix.
+///
+/// Only symmetric matrices are supported, the result is undefined otherwise.
+///
+/// # Arguments
+///
+/// * `a` - The symmetric matrix with shape `[n, n]`.
+/// * `backend` - The backend computing the decomposition, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// A tuple with the eigenvalues in ascending order with shape `[n]` and the matrix with the
+/// corresponding eigenvectors as columns with shape `[n, n]`.
+#[allow(clippy::type_complexity)]
+pub fn eigh<T, A, B>(
+    a: &Tensor2<T, A>,
+    backend: &B,
+) -> Result<(Tensor1<T, A>, Tensor2<T, A>), TensorOpsError>
+where
+    T: Float,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T>,
+{
+    let n = check_square(a)?;
+    let (values, vectors) = backend.eigh(a.as_slice(), n)?;
+    let alloc = a.storage.alloc();
+
+    Ok((
+        Tensor1::from_shape_vec([n], values, alloc.clone())?,
+        Tensor2::from_shape_vec([n, n], vectors, alloc.clone())?,
+    ))
+}
+
+/// Solve a batch of linear systems `A * X = B`.
+///
+/// The systems are solved in parallel, see [`solve`].
+///
+/// # Arguments
+///
+/// * `a` - The batch of square coefficient matrices with shape `[b, n, n]`.
+/// * `b` - The batch of right hand sides with shape `[b, n, nrhs]`.
+/// * `backend` - The backend computing the solutions, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// The batch of solutions `X` with shape `[b, n, nrhs]`.
+///
+/// # Errors
+///
+/// If the shapes are not compatible or any of the matrices is singular, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor3, CpuAllocator};
+/// use kornia_tensor_ops::linalg::{batch_solve, NativeBackend};
+///
+/// let a = Tensor3::<f64, _>::from_shape_vec([2, 1, 1], vec![2., 4.], CpuAllocator).unwrap();
+/// let b = Tensor3::<f64, _>::from_shape_vec([2, 1, 1], vec![1., 1.], CpuAllocator).unwrap();
+/// let x = batch_solve(&a, &b, &NativeBackend).unwrap();
+/// assert_eq!(x.as_slice(), [0.5, 0.25]);
+/// ```
+pub fn batch_solve<T, A, B>(
+    a: &Tensor3<T, A>,
+    b: &Tensor3<T, A>,
+    backend: &B,
+) -> Result<Tensor3<T, A>, TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T> + Sync,
+{
+    let n = check_square_batch(a)?;
+    let [batch, rows, nrhs] = b.shape;
+    if batch != a.shape[0] || rows != n {
+        return Err(TensorOpsError::ShapeMismatch(format!(
+            "Cannot solve a batch of systems with matrices of shape {:?} and right hand sides of shape {:?}",
+            a.shape, b.shape
+        )));
+    }
+
+    let mut x = b.as_slice().to_vec();
+    solve_batch(a.as_slice(), &mut x, n, nrhs, backend)?;
+
+    Ok(Tensor3::from_shape_vec(
+        [batch, n, nrhs],
+        x,
+        a.storage.alloc().clone(),
+    )?)
+}
+
+/// Compute the inverses of a batch of square matrices.
+///
+/// The matrices are inverted in parallel, see [`inverse`].
+///
+/// # Arguments
+///
+/// * `a` - The batch of square matrices with shape `[b, n, n]`.
+/// * `backend` - The backend computing the inverses, e.g. [`NativeBackend`].
+///
+/// # Errors
+///
+/// If the matrices are not square or any of them is singular, an error is returned.
+pub fn batch_inverse<T, A, B>(
+    a: &Tensor3<T, A>,
+    backend: &B,
+) -> Result<Tensor3<T, A>, TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T> + Sync,
+{
+    let n = check_square_batch(a)?;
+    let batch = a.shape[0];
+    let mut x = identity(n).repeat(batch);
+    solve_batch(a.as_slice(), &mut x, n, n, backend)?;
+
+    Ok(Tensor3::from_shape_vec(
+        [batch, n, n],
+        x,
+        a.storage.alloc().clone(),
+    )?)
+}
+
+/// Compute the thin QR decompositions of a batch of matrices.
+///
+/// The matrices are decomposed in parallel, see [`qr`].
+///
+/// # Arguments
+///
+/// * `a` - The batch of matrices with shape `[b, m, n]`.
+/// * `backend` - The backend computing the decompositions, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// A tuple with the orthonormal matrices `Q` with shape `[b, m, k]` and the upper triangular
+/// matrices `R` with shape `[b, k, n]`, where `k = min(m, n)`.
+#[allow(clippy::type_complexity)]
+pub fn batch_qr<T, A, B>(
+    a: &Tensor3<T, A>,
+    backend: &B,
+) -> Result<(Tensor3<T, A>, Tensor3<T, A>), TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T> + Sync,
+{
+    let [batch, m, n] = a.shape;
+    let k = m.min(n);
+    let factors = map_batch(a.as_slice(), batch, m * n, |a| backend.qr(a, m, n))?;
+    let (q, r): (Vec<_>, Vec<_>) = factors.into_iter().unzip();
+    let alloc = a.storage.alloc();
+
+    Ok((
+        Tensor3::from_shape_vec([batch, m, k], q.concat(), alloc.clone())?,
+        Tensor3::from_shape_vec([batch, k, n], r.concat(), alloc.clone())?,
+    ))
+}
+
+/// Compute the thin singular value decompositions of a batch of matrices.
+///
+/// The matrices are decomposed in parallel, see [`svd`].
+///
+/// # Arguments
+///
+/// * `a` - The batch of matrices with shape `[b, m, n]`.
+/// * `backend` - The backend computing the decompositions, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// A tuple with `U` with shape `[b, m, k]`, the singular values `S` in descending order with
+/// shape `[b, k]` and `V^T` with shape `[b, k, n]`, where `k = min(m, n)`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor3, CpuAllocator};
+/// use kornia_tensor_ops::linalg::{batch_svd, NativeBackend};
+///
+/// let a = Tensor3::<f64, _>::from_shape_vec(
+///     [2, 2, 2],
+///     vec![3., 0., 0., -2., 0., 1., 5., 0.],
+///     CpuAllocator,
+/// )
+/// .unwrap();
+/// let (u, s, vt) = batch_svd(&a, &NativeBackend).unwrap();
+/// assert_eq!(s.shape, [2, 2]);
+/// assert_eq!(s.as_slice(), [3., 2., 5., 1.]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn batch_svd<T, A, B>(
+    a: &Tensor3<T, A>,
+    backend: &B,
+) -> Result<(Tensor3<T, A>, Tensor2<T, A>, Tensor3<T, A>), TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T> + Sync,
+{
+    let [batch, m, n] = a.shape;
+    let k = m.min(n);
+    let factors = map_batch(a.as_slice(), batch, m * n, |a| backend.svd(a, m, n))?;
+    let (mut u, mut s, mut vt) = (Vec::new(), Vec::new(), Vec::new());
+    for (ui, si, vti) in factors {
+        u.extend(ui);
+        s.extend(si);
+        vt.extend(vti);
+    }
+    let alloc = a.storage.alloc();
+
+    Ok((
+        Tensor3::from_shape_vec([batch, m, k], u, alloc.clone())?,
+        Tensor2::from_shape_vec([batch, k], s, alloc.clone())?,
+        Tensor3::from_shape_vec([batch, k, n], vt, alloc.clone())?,
+    ))
+}
+
+/// Compute the eigendecompositions of a batch of symmetric matrices.
+///
+/// The matrices are decomposed in parallel, see [`eigh`].
+///
+/// # Arguments
+///
+/// * `a` - The batch of symmetric matrices with shape `[b, n, n]`.
+/// * `backend` - The backend computing the decompositions, e.g. [`NativeBackend`].
+///
+/// # Returns
+///
+/// A tuple with the eigenvalues in ascending order with shape `[b, n]` and the matrices with
+/// the corresponding eigenvectors as columns with shape `[b, n, n]`.
+#[allow(clippy::type_complexity)]
+pub fn batch_eigh<T, A, B>(
+    a: &Tensor3<T, A>,
+    backend: &B,
+) -> Result<(Tensor2<T, A>, Tensor3<T, A>), TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+    B: LinalgBackend<T> + Sync,
+{
+    let n = check_square_batch(a)?;
+    let batch = a.shape[0];
+    let factors = map_batch(a.as_slice(), batch, n * n, |a| backend.eigh(a, n))?;
+    let (values, vectors): (Vec<_>, Vec<_>) = factors.into_iter().unzip();
+    let alloc = a.storage.alloc();
+
+    Ok((
+        Tensor2::from_shape_vec([batch, n], values.concat(), alloc.clone())?,
+        Tensor3::from_shape_vec([batch, n, n], vectors.concat(), alloc.clone())?,
+    ))
+}
+
+#[cfg(feature = "faer")]
+mod faer_backend {
+    use super::{LinalgBackend, TensorOpsError};
+
+    /// A backend delegating the matrix decompositions to the [faer](https://docs.rs/faer) crate.
+    ///
+    /// It is faster than the [`super::NativeBackend`] for large matrices.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct FaerBackend;
+
+    fn to_mat<T: faer::RealField + Copy>(a: &[T], rows: usize, cols: usize) -> faer::Mat<T> {
+        faer::Mat::from_fn(rows, cols, |i, j| a[i * cols + j])
+    }
+
+    fn to_buf<T: faer::RealField + Copy>(m: faer::MatRef<'_, T>) -> Vec<T> {
+        (0..m.nrows())
+            .flat_map(|i| (0..m.ncols()).map(move |j| m.read(i, j)))
+            .collect()
+    }
+
+    macro_rules! impl_faer_backend {
+        ($t:ty) => {
+            impl LinalgBackend<$t> for FaerBackend {
+                fn solve(
+                    &self,
+                    a: &[$t],
+                    b: &mut [$t],
+                    n: usize,
+                    nrhs: usize,
+                ) -> Result<(), TensorOpsError> {
+                    use faer::prelude::SpSolver;
+                    let lu = to_mat(a, n, n).partial_piv_lu();
+                    let x = lu.solve(to_mat(b, n, nrhs));
+                    let x = to_buf(x.as_ref());
+                    if x.iter().any(|v| !v.is_finite()) {
+                        return Err(TensorOpsError::SingularMatrix);
+                    }
+                    b.copy_from_slice(&x);
+                    Ok(())
+                }
+
+                fn qr(
+                    &self,
+                    a: &[$t],
+                    rows: usize,
+                    cols: usize,
+                ) -> Result<(Vec<$t>, Vec<$t>), TensorOpsError> {
+                    let qr = to_mat(a, rows, cols).qr();
+                    Ok((
+                        to_buf(qr.compute_thin_q().as_ref()),
+                        to_buf(qr.compute_thin_r().as_ref()),
+                    ))
+                }
+
+                fn svd(
+                    &self,
+                    a: &[$t],
+                    rows: usize,
+                    cols: usize,
+                ) -> Result<(Vec<$t>, Vec<$t>, Vec<$t>), TensorOpsError> {
+                    let svd = to_mat(a, rows, cols).thin_svd();
+                    let s = svd.s_diagonal();
+                    Ok((
+                        to_buf(svd.u()),
+                        (0..s.nrows()).map(|i| s.read(i)).collect(),
+                        to_buf(svd.v().transpose()),
+                    ))
+                }
+
+                fn eigh(&self, a: &[$t], n: usize) -> Result<(Vec<$t>, Vec<$t>), TensorOpsError> {
+                    let evd = to_mat(a, n, n).selfadjoint_eigendecomposition(faer::Side::Lower);
+                    let s = evd.s().column_vector();
+                    Ok(((0..n).map(|i| s.read(i)).collect(), to_buf(evd.u())))
+                }
+            }
+        };
+    }
+
+    impl_faer_backend!(f32);
+    impl_faer_backend!(f64);
+}
+
+#[cfg(feature = "faer")]
+pub use faer_backend::FaerBackend;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_tensor::CpuAllocator;
+
+    fn assert_close(a: &[f64], b: &[f64], tol: f64) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < tol, "{a:?} != {b:?}");
+        }
+    }
+
+    fn mat(shape: [usize; 2], data: Vec<f64>) -> Tensor2<f64, CpuAllocator> {
+        Tensor2::from_shape_vec(shape, data, CpuAllocator).unwrap()
+    }
+
+    #[test]
+    fn test_matmul() -> Result<(), TensorOpsError> {
+        let a = mat([2, 3], vec![1., 2., 3., 4., 5., 6.]);
+        let b = mat([3, 2], vec![7., 8., 9., 10., 11., 12.]);
+        let c = matmul(&a, &b)?;
+        assert_eq!(c.shape, [2, 2]);
+        assert_eq!(c.as_slice(), [58., 64., 139., 154.]);
+
+        let res = matmul(&a, &a);
+        assert!(res.is_err_and(|e| matches!(e, TensorOpsError::ShapeMismatch(_))));
+
+        // large enough to run in parallel
+        let n = 80;
+        let a = Tensor2::<f64, _>::from_shape_fn([n, n], CpuAllocator, |[i, j]| (i + j) as f64);
+        let eye =
+            Tensor2::<f64, _>::from_shape_fn(
+                [n, n],
+                CpuAllocator,
+                |[i, j]| {
+                    if i == j {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                },
+            );
+        assert_eq!(matmul(&a, &eye)?.as_slice(), a.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bmm_transpose() -> Result<(), TensorOpsError> {
+        let data: Vec<i32> = (0..12).collect();
+        let a = Tensor3::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        let b = Tensor3::from_shape_vec([2, 3, 1], vec![1, 1, 1, 1, 0, 0], CpuAllocator)?;
+        let c = bmm(&a, &b)?;
+        assert_eq!(c.shape, [2, 2, 1]);
+        assert_eq!(c.as_slice(), [3, 12, 6, 9]);
+
+        let t = transpose(&mat([2, 3], vec![1., 2., 3., 4., 5., 6.]));
+        assert_eq!(t.shape, [3, 2]);
+        assert_eq!(t.as_slice(), [1., 4., 2., 5., 3., 6.]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_inverse() -> Result<(), TensorOpsError> {
+        let a = mat([3, 3], vec![0., 2., 1., 1., 1., 0., 3., 0., 1.]);
+        let inv = inverse(&a, &NativeBackend)?;
+        let eye = matmul(&a, &inv)?;
+        assert_close(eye.as_slice(), &[1., 0., 0., 0., 1., 0., 0., 0., 1.], 1e-12);
+
+        let b = mat([3, 1], vec![3., 2., 4.]);
+        let x = solve(&a, &b, &NativeBackend)?;
+        assert_close(matmul(&a, &x)?.as_slice(), b.as_slice(), 1e-12);
+
+        let singular = mat([2, 2], vec![1., 2., 2., 4.]);
+        let res = inverse(&singular, &NativeBackend);
+        assert!(res.is_err_and(|e| e == TensorOpsError::SingularMatrix));
+        Ok(())
+    }
+
+    #[test]
+    fn test_qr() -> Result<(), TensorOpsError> {
+        let a = mat([3, 2], vec![1., 2., 3., 4., 5., 6.]);
+        let (q, r) = qr(&a, &NativeBackend)?;
+        assert_eq!(q.shape, [3, 2]);
+        assert_eq!(r.shape, [2, 2]);
+        assert_eq!(r.as_slice()[2], 0.0);
+        assert_close(matmul(&q, &r)?.as_slice(), a.as_slice(), 1e-12);
+        let qtq = matmul(&transpose(&q), &q)?;
+        assert_close(qtq.as_slice(), &[1., 0., 0., 1.], 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_svd() -> Result<(), TensorOpsError> {
+        for (shape, data) in [
+            ([3, 2], vec![1., 2., 3., 4., 5., 6.]),
+            ([2, 3], vec![1., 2., 3., 4., 5., 6.]),
+            // rank deficient, e.g. the covariance of planar points
+            ([3, 3], vec![1., 0., 0., 0., 2., 0., 0., 0., 0.]),
+        ] {
+            let a = mat(shape, data);
+            let (u, s, vt) = svd(&a, &NativeBackend)?;
+            let k = shape[0].min(shape[1]);
+            assert_eq!(u.shape, [shape[0], k]);
+            assert_eq!(vt.shape, [k, shape[1]]);
+            assert!(s.as_slice().windows(2).all(|w| w[0] >= w[1]));
+
+            let us = Tensor2::from_shape_fn([shape[0], k], CpuAllocator, |[i, j]| {
+                u.as_slice()[i * k + j] * s.as_slice()[j]
+            });
+            assert_close(matmul(&us, &vt)?.as_slice(), a.as_slice(), 1e-12);
+
+            let utu = matmul(&transpose(&u), &u)?;
+            let eye = Tensor2::from_shape_fn(
+                [k, k],
+                CpuAllocator,
+                |[i, j]| {
+                    if i == j {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                },
+            );
+            assert_close(utu.as_slice(), eye.as_slice(), 1e-12);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_eigh() -> Result<(), TensorOpsError> {
+        let a = mat([3, 3], vec![2., -1., 0., -1., 2., -1., 0., -1., 2.]);
+        let (values, vectors) = eigh(&a, &NativeBackend)?;
+        let sqrt2 = 2f64.sqrt();
+        assert_close(values.as_slice(), &[2. - sqrt2, 2., 2. + sqrt2], 1e-12);
+
+        // A * V = V * diag(values)
+        let av = matmul(&a, &vectors)?;
+        let vd = Tensor2::from_shape_fn([3, 3], CpuAllocator, |[i, j]| {
+            vectors.as_slice()[i * 3 + j] * values.as_slice()[j]
+        });
+        assert_close(av.as_slice(), vd.as_slice(), 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_batched_decompositions() -> Result<(), TensorOpsError> {
+        let mats = [
+            vec![4., 1., 0., 1., 3., 1., 0., 1., 2.],
+            vec![2., -1., 0., -1., 2., -1., 0., -1., 2.],
+        ];
+        let a = Tensor3::from_shape_vec([2, 3, 3], mats.concat(), CpuAllocator)?;
+
+        // each matrix of the batch matches the single matrix decomposition
+        let inv = batch_inverse(&a, &NativeBackend)?;
+        let b = Tensor3::from_shape_vec([2, 3, 1], vec![1., 2., 3., 3., 2., 1.], CpuAllocator)?;
+        let x = batch_solve(&a, &b, &NativeBackend)?;
+        let (q, r) = batch_qr(&a, &NativeBackend)?;
+        let (u, s, vt) = batch_svd(&a, &NativeBackend)?;
+        let (values, vectors) = batch_eigh(&a, &NativeBackend)?;
+        assert_eq!(
+            (q.shape, r.shape, u.shape, vt.shape),
+            ([2, 3, 3], [2, 3, 3], [2, 3, 3], [2, 3, 3])
+        );
+        assert_eq!(
+            (s.shape, values.shape, vectors.shape),
+            ([2, 3], [2, 3], [2, 3, 3])
+        );
+
+        for (i, data) in mats.into_iter().enumerate() {
+            let m = mat([3, 3], data);
+            let rhs = mat([3, 1], b.as_slice()[i * 3..(i + 1) * 3].to_vec());
+            let batch = |t: &[f64], len: usize| t[i * len..(i + 1) * len].to_vec();
+            assert_close(
+                &batch(inv.as_slice(), 9),
+                inverse(&m, &NativeBackend)?.as_slice(),
+                1e-12,
+            );
+            assert_close(
+                &batch(x.as_slice(), 3),
+                solve(&m, &rhs, &NativeBackend)?.as_slice(),
+                1e-12,
+            );
+            assert_close(
+                &batch(q.as_slice(), 9),
+                qr(&m, &NativeBackend)?.0.as_slice(),
+                1e-12,
+            );
+            assert_close(
+                &batch(r.as_slice(), 9),
+                qr(&m, &NativeBackend)?.1.as_slice(),
+                1e-12,
+            );
+            assert_close(
+                &batch(s.as_slice(), 3),
+                svd(&m, &NativeBackend)?.1.as_slice(),
+                1e-12,
+            );
+            assert_close(
+                &batch(values.as_slice(), 3),
+                eigh(&m, &NativeBackend)?.0.as_slice(),
+                1e-12,
+            );
+        }
+
+        // a singular matrix in the batch and mismatched shapes
+        let singular = Tensor3::from_shape_vec(
+            [2, 2, 2],
+            vec![1., 0., 0., 1., 1., 2., 2., 4.],
+            CpuAllocator,
+        )?;
+        let res = batch_inverse(&singular, &NativeBackend);
+        assert!(res.is_err_and(|e| e == TensorOpsError::SingularMatrix));
+        assert!(batch_solve(&a, &singular, &NativeBackend).is_err());
+        let rect = Tensor3::from_shape_vec([1, 2, 3], vec![1.; 6], CpuAllocator)?;
+        assert!(batch_eigh(&rect, &NativeBackend).is_err());
+
+        // an empty batch
+        let empty = Tensor3::<f64, _>::from_shape_vec([0, 3, 3], vec![], CpuAllocator)?;
+        assert_eq!(batch_svd(&empty, &NativeBackend)?.1.shape, [0, 3]);
+        Ok(())
+    }
+
+    #[cfg(feature = "faer")]
+    #[test]
+    fn test_faer_backend() -> Result<(), TensorOpsError> {
+        let a = mat([3, 3], vec![4., 1., 0., 1., 3., 1., 0., 1., 2.]);
+
+        let (s_native, s_faer) = (svd(&a, &NativeBackend)?.1, svd(&a, &FaerBackend)?.1);
+        assert_close(s_native.as_slice(), s_faer.as_slice(), 1e-12);
+
+        let (e_native, e_faer) = (eigh(&a, &NativeBackend)?.0, eigh(&a, &FaerBackend)?.0);
+        assert_close(e_native.as_slice(), e_faer.as_slice(), 1e-12);
+
+        let inv_native = inverse(&a, &NativeBackend)?;
+        let inv_faer = inverse(&a, &FaerBackend)?;
+        assert_close(inv_native.as_slice(), inv_faer.as_slice(), 1e-12);
+
+        let (q, r) = qr(&a, &FaerBackend)?;
+        assert_close(matmul(&q, &r)?.as_slice(), a.as_slice(), 1e-12);
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
-use kornia_tensor::{storage::TensorStorage, Tensor, TensorAllocator};
-use num_traits::Zero;
+use kornia_tensor::{storage::TensorStorage, view::StridedOffsets, Tensor, TensorAllocator};
+use num_traits::{Float, One, Zero};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::error::TensorOpsError;
 
@@ -76,6 +77,795 @@ where
     })
 }
 
+/// Minimum number of elements of a tensor to run a reduction in parallel.
+const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// A reduction over a group of elements of a tensor.
+///
+/// The accumulators of two partial reductions can be merged, which allows to
+/// split large reductions across threads.
+trait Reducer<T>: Sync {
+    /// The accumulator type of the reduction.
+    type Acc: Send;
+    /// The output type of the reduction.
+    type Output: Send;
+
+    /// Create an empty accumulator.
+    fn init(&self) -> Self::Acc;
+
+    /// Update the accumulator with the element at position `pos` within the group.
+    fn update(&self, acc: Self::Acc, pos: usize, value: &T) -> Self::Acc;
+
+    /// Merge the accumulator `b` into `a`, where `b` holds the elements after the ones of `a`.
+    fn merge(&self, a: Self::Acc, b: Self::Acc) -> Self::Acc;
+
+    /// Compute the output from the accumulator of a group of `count` elements.
+    fn finish(&self, acc: Self::Acc, count: usize) -> Self::Output;
+}
+
+/// Validate the dimensions to reduce and the rank of the output tensor.
+///
+/// # Returns
+///
+/// A mask with the dimensions to reduce. An empty list of dimensions reduces all dimensions.
+fn reduce_mask<const N: usize, const M: usize>(
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<[bool; N], TensorOpsError> {
+    let mut mask = [dims.is_empty(); N];
+    for &dim in dims {
+        if dim >= N {
+            return Err(TensorOpsError::DimOutOfBounds(dim, N - 1));
+        }
+        if mask[dim] {
+            return Err(TensorOpsError::RepeatedDim(dim));
+        }
+        mask[dim] = true;
+    }
+
+    let out_rank = if keepdim {
+        N
+    } else {
+        mask.iter().filter(|&&m| !m).count()
+    };
+    if M != out_rank {
+        return Err(TensorOpsError::InvalidOutputRank(M, out_rank));
+    }
+
+    Ok(mask)
+}
+
+/// Reduce a group of elements of a tensor, optionally splitting it across threads.
+fn reduce_group<T, const N: usize, R>(
+    data: &[T],
+    shape: [usize; N],
+    strides: [usize; N],
+    base: usize,
+    reducer: &R,
+    parallel: bool,
+) -> R::Acc
+where
+    T: Sync,
+    R: Reducer<T>,
+{
+    let count = shape.iter().product::<usize>();
+
+    // split the group along its outermost dimension
+    match shape.iter().position(|&s| s > 1) {
+        Some(dim) if parallel => {
+            let inner = count / shape[dim];
+            let mut inner_shape = shape;
+            inner_shape[dim] = 1;
+            (0..shape[dim])
+                .into_par_iter()
+                .map(|i| {
+                    StridedOffsets::new(inner_shape, strides, base + i * strides[dim])
+                        .enumerate()
+                        .fold(reducer.init(), |acc, (pos, offset)| {
+                            reducer.update(acc, i * inner + pos, &data[offset])
+                        })
+                })
+                .reduce(|| reducer.init(), |a, b| reducer.merge(a, b))
+        }
+        _ => StridedOffsets::new(shape, strides, base)
+            .enumerate()
+            .fold(reducer.init(), |acc, (pos, offset)| {
+                reducer.update(acc, pos, &data[offset])
+            }),
+    }
+}
+
+/// Apply a reduction over the given dimensions of a tensor.
+fn reduce<T, const N: usize, const M: usize, A, R>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+    reducer: R,
+) -> Result<Tensor<R::Output, M, A>, TensorOpsError>
+where
+    T: Sync,
+    A: TensorAllocator + 'static,
+    R: Reducer<T>,
+{
+    let mask = reduce_mask::<N, M>(dims, keepdim)?;
+
+    // the shape of the output with the reduced dimensions kept and the layout of a group
+    let mut keep_shape = tensor.shape;
+    let mut base_strides = tensor.strides;
+    let mut group_shape = [1; N];
+    for i in (0..N).filter(|&i| mask[i]) {
+        keep_shape[i] = 1;
+        base_strides[i] = 0;
+        group_shape[i] = tensor.shape[i];
+    }
+    let count = group_shape.iter().product::<usize>();
+
+    let data = tensor.as_slice();
+    let bases = StridedOffsets::new(keep_shape, base_strides, 0);
+    let parallel = tensor.numel() >= PARALLEL_THRESHOLD;
+
+    let reduce_at = |base: usize, parallel: bool| {
+        let acc = reduce_group(data, group_shape, tensor.strides, base, &reducer, parallel);
+        reducer.finish(acc, count)
+    };
+
+    let values: Vec<R::Output> = if parallel && bases.len() > 1 {
+        bases
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|base| reduce_at(base, false))
+            .collect()
+    } else {
+        bases.map(|base| reduce_at(base, parallel)).collect()
+    };
+
+    let mut shape = [1; M];
+    if keepdim {
+        shape.copy_from_slice(&keep_shape[..M]);
+    } else {
+        for (j, i) in (0..N).filter(|&i| !mask[i]).enumerate() {
+            shape[j] = tensor.shape[i];
+        }
+    }
+
+    Ok(Tensor::from_shape_vec(
+        shape,
+        values,
+        tensor.storage.alloc().clone(),
+    )?)
+}
+
+/// Check that the reduced groups of a tensor are not empty.
+fn check_non_empty<T, const N: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+) -> Result<(), TensorOpsError>
+where
+    A: TensorAllocator + 'static,
+{
+    let empty = if dims.is_empty() {
+        tensor.shape.contains(&0)
+    } else {
+        dims.iter().any(|&d| tensor.shape.get(d) == Some(&0))
+    };
+    if empty {
+        return Err(TensorOpsError::EmptyReduction);
+    }
+    Ok(())
+}
+
+struct SumReducer;
+
+impl<T> Reducer<T> for SumReducer
+where
+    T: Zero + Clone + Send + Sync,
+{
+    type Acc = T;
+    type Output = T;
+
+    fn init(&self) -> T {
+        T::zero()
+    }
+
+    fn update(&self, acc: T, _pos: usize, value: &T) -> T {
+        acc + value.clone()
+    }
+
+    fn merge(&self, a: T, b: T) -> T {
+        a + b
+    }
+
+    fn finish(&self, acc: T, _count: usize) -> T {
+        acc
+    }
+}
+
+struct ProdReducer;
+
+impl<T> Reducer<T> for ProdReducer
+where
+    T: One + Clone + Send + Sync,
+{
+    type Acc = T;
+    type Output = T;
+
+    fn init(&self) -> T {
+        T::one()
+    }
+
+    fn update(&self, acc: T, _pos: usize, value: &T) -> T {
+        acc * value.clone()
+    }
+
+    fn merge(&self, a: T, b: T) -> T {
+        a * b
+    }
+
+    fn finish(&self, acc: T, _count: usize) -> T {
+        acc
+    }
+}
+
+struct MeanReducer;
+
+impl<T> Reducer<T> for MeanReducer
+where
+    T: Float + Send + Sync,
+{
+    type Acc = T;
+    type Output = T;
+
+    fn init(&self) -> T {
+        T::zero()
+    }
+
+    fn update(&self, acc: T, _pos: usize, value: &T) -> T {
+        acc + *value
+    }
+
+    fn merge(&self, a: T, b: T) -> T {
+        a + b
+    }
+
+    fn finish(&self, acc: T, count: usize) -> T {
+        acc / T::from(count).unwrap_or_else(T::nan)
+    }
+}
+
+/// Computes the variance with the Welford algorithm and merges partial results with
+/// the Chan et al. parallel algorithm.
+struct VarReducer {
+    correction: usize,
+}
+
+impl<T> Reducer<T> for VarReducer
+where
+    T: Float + Send + Sync,
+{
+    // (count, mean, sum of squared differences from the mean)
+    type Acc = (usize, T, T);
+    type Output = T;
+
+    fn init(&self) -> Self::Acc {
+        (0, T::zero(), T::zero())
+    }
+
+    fn update(&self, (n, mean, m2): Self::Acc, _pos: usize, value: &T) -> Self::Acc {
+        let n = n + 1;
+        let delta = *value - mean;
+        let mean = mean + delta / T::from(n).unwrap_or_else(T::nan);
+        (n, mean, m2 + delta * (*value - mean))
+    }
+
+    fn merge(&self, (na, mean_a, m2_a): Self::Acc, (nb, mean_b, m2_b): Self::Acc) -> Self::Acc {
+        if na == 0 {
+            return (nb, mean_b, m2_b);
+        }
+        if nb == 0 {
+            return (na, mean_a, m2_a);
+        }
+        let n = na + nb;
+        let (fa, fb, f) = (
+            T::from(na).unwrap_or_else(T::nan),
+            T::from(nb).unwrap_or_else(T::nan),
+            T::from(n).unwrap_or_else(T::nan),
+        );
+        let delta = mean_b - mean_a;
+        let mean = mean_a + delta * fb / f;
+        (n, mean, m2_a + m2_b + delta * delta * fa * fb / f)
+    }
+
+    fn finish(&self, (n, _mean, m2): Self::Acc, _count: usize) -> T {
+        if n <= self.correction {
+            return T::nan();
+        }
+        m2 / T::from(n - self.correction).unwrap_or_else(T::nan)
+    }
+}
+
+/// Keeps the extreme value of a group according to `keep`, which returns true when the
+/// new value replaces the current one.
+struct ExtremumReducer<F> {
+    keep: F,
+}
+
+impl<T, F> Reducer<T> for ExtremumReducer<F>
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    // (position, value) of the extreme value
+    type Acc = Option<(usize, T)>;
+    type Output = Option<(usize, T)>;
+
+    fn init(&self) -> Self::Acc {
+        None
+    }
+
+    fn update(&self, acc: Self::Acc, pos: usize, value: &T) -> Self::Acc {
+        match acc {
+            Some((_, ref current)) if !(self.keep)(value, current) => acc,
+            _ => Some((pos, value.clone())),
+        }
+    }
+
+    fn merge(&self, a: Self::Acc, b: Self::Acc) -> Self::Acc {
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                if (self.keep)(&b.1, &a.1) {
+                    Some(b)
+                } else {
+                    Some(a)
+                }
+            }
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+
+    fn finish(&self, acc: Self::Acc, _count: usize) -> Self::Acc {
+        acc
+    }
+}
+
+/// Reduces with a boolean predicate, short-circuiting is not possible with strided groups.
+struct PredicateReducer<F> {
+    pred: F,
+    all: bool,
+}
+
+impl<T, F> Reducer<T> for PredicateReducer<F>
+where
+    F: Fn(&T) -> bool + Sync,
+{
+    type Acc = bool;
+    type Output = bool;
+
+    fn init(&self) -> bool {
+        self.all
+    }
+
+    fn update(&self, acc: bool, _pos: usize, value: &T) -> bool {
+        if self.all {
+            acc && (self.pred)(value)
+        } else {
+            acc || (self.pred)(value)
+        }
+    }
+
+    fn merge(&self, a: bool, b: bool) -> bool {
+        if self.all {
+            a && b
+        } else {
+            a || b
+        }
+    }
+
+    fn finish(&self, acc: bool, _count: usize) -> bool {
+        acc
+    }
+}
+
+struct NormReducer {
+    p: u8,
+}
+
+impl<T> Reducer<T> for NormReducer
+where
+    T: Float + Send + Sync,
+{
+    type Acc = T;
+    type Output = T;
+
+    fn init(&self) -> T {
+        T::zero()
+    }
+
+    fn update(&self, acc: T, _pos: usize, value: &T) -> T {
+        match self.p {
+            1 => acc + value.abs(),
+            _ => acc + *value * *value,
+        }
+    }
+
+    fn merge(&self, a: T, b: T) -> T {
+        a + b
+    }
+
+    fn finish(&self, acc: T, _count: usize) -> T {
+        match self.p {
+            1 => acc,
+            _ => acc.sqrt(),
+        }
+    }
+}
+
+/// Compute the sum of the elements of a tensor over the given dimensions.
+///
+/// The output rank `M` must be `N` when `keepdim` is true, otherwise `N` minus the
+/// number of reduced dimensions.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dims` - The dimensions to reduce. An empty slice reduces all the dimensions.
+/// * `keepdim` - Whether to keep the reduced dimensions with size 1.
+///
+/// # Returns
+///
+/// A new `Tensor` containing the sums.
+///
+/// # Errors
+///
+/// If a dimension is out of bounds or repeated, or the output rank is invalid.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::sum;
+///
+/// let data: [u8; 6] = [1, 2, 3, 4, 5, 6];
+/// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator).unwrap();
+///
+/// let rows: Tensor<u8, 1, _> = sum(&t, &[1], false).unwrap();
+/// assert_eq!(rows.shape, [2]);
+/// assert_eq!(rows.as_slice(), [6, 15]);
+///
+/// let total: Tensor<u8, 2, _> = sum(&t, &[], true).unwrap();
+/// assert_eq!(total.shape, [1, 1]);
+/// assert_eq!(total.as_slice(), [21]);
+/// ```
+pub fn sum<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: Zero + Clone + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    reduce(tensor, dims, keepdim, SumReducer)
+}
+
+/// Compute the product of the elements of a tensor over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::prod;
+///
+/// let data: [i32; 6] = [1, 2, 3, 4, 5, 6];
+/// let t = Tensor::<i32, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator).unwrap();
+/// let p: Tensor<i32, 1, _> = prod(&t, &[0], false).unwrap();
+/// assert_eq!(p.as_slice(), [4, 10, 18]);
+/// ```
+pub fn prod<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: One + Clone + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    reduce(tensor, dims, keepdim, ProdReducer)
+}
+
+/// Compute the mean of the elements of a tensor over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+///
+/// # Errors
+///
+/// If any of the reduced dimensions is empty, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::mean;
+///
+/// // per-channel mean of a 2x2 image with 3 channels
+/// let data: [f32; 12] = [0., 1., 2., 2., 3., 4., 4., 5., 6., 6., 7., 8.];
+/// let t = Tensor::<f32, 3, CpuAllocator>::from_shape_slice([2, 2, 3], &data, CpuAllocator).unwrap();
+/// let m: Tensor<f32, 1, _> = mean(&t, &[0, 1], false).unwrap();
+/// assert_eq!(m.as_slice(), [3., 4., 5.]);
+/// ```
+pub fn mean<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    check_non_empty(tensor, dims)?;
+    reduce(tensor, dims, keepdim, MeanReducer)
+}
+
+/// Compute the variance of the elements of a tensor over the given dimensions.
+///
+/// The variance is computed as `sum((x - mean)^2) / (count - correction)`. Use a
+/// `correction` of 0 for the population variance and 1 for the unbiased sample variance.
+/// Groups with `count <= correction` elements produce NaN.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::var;
+///
+/// let data: [f32; 4] = [1., 2., 3., 4.];
+/// let t = Tensor::<f32, 1, CpuAllocator>::from_shape_slice([4], &data, CpuAllocator).unwrap();
+/// let v: Tensor<f32, 1, _> = var(&t, &[0], 0, true).unwrap();
+/// assert_eq!(v.as_slice(), [1.25]);
+/// ```
+pub fn var<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    correction: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    reduce(tensor, dims, keepdim, VarReducer { correction })
+}
+
+/// Compute the standard deviation of the elements of a tensor over the given dimensions.
+///
+/// See [`var`] for the meaning of `correction` and [`sum`] for `dims` and `keepdim`.
+pub fn std<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    correction: usize,
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    let variance = var(tensor, dims, correction, keepdim)?;
+    Ok(variance.map(|v| v.sqrt()))
+}
+
+/// Compute the minimum of the elements of a tensor over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+///
+/// # Errors
+///
+/// If any of the reduced dimensions is empty, an error is returned.
+pub fn min<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: PartialOrd + Clone + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    extremum(tensor, dims, keepdim, |a: &T, b: &T| a < b).map(|t| t.map(|(_, v)| v.clone()))
+}
+
+/// Compute the maximum of the elements of a tensor over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+///
+/// # Errors
+///
+/// If any of the reduced dimensions is empty, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::max;
+///
+/// let data: [u8; 6] = [1, 7, 3, 4, 5, 6];
+/// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator).unwrap();
+/// let m: Tensor<u8, 1, _> = max(&t, &[1], false).unwrap();
+/// assert_eq!(m.as_slice(), [7, 6]);
+/// ```
+pub fn max<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: PartialOrd + Clone + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    extremum(tensor, dims, keepdim, |a: &T, b: &T| a > b).map(|t| t.map(|(_, v)| v.clone()))
+}
+
+/// Compute the index of the minimum along a dimension of a tensor.
+///
+/// The first occurrence is returned when the minimum is repeated.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to reduce.
+/// * `dim` - The dimension to reduce.
+/// * `keepdim` - Whether to keep the reduced dimension with size 1.
+///
+/// # Errors
+///
+/// If the dimension is out of bounds or empty, or the output rank is invalid.
+pub fn argmin<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<usize, M, A>, TensorOpsError>
+where
+    T: PartialOrd + Clone + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    extremum(tensor, &[dim], keepdim, |a: &T, b: &T| a < b).map(|t| t.map(|(i, _)| *i))
+}
+
+/// Compute the index of the maximum along a dimension of a tensor.
+///
+/// The first occurrence is returned when the maximum is repeated.
+///
+/// See [`argmin`] for the arguments.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::argmax;
+///
+/// let data: [f32; 6] = [0.1, 0.7, 0.2, 0.5, 0.3, 0.2];
+/// let t = Tensor::<f32, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator).unwrap();
+/// let idx: Tensor<usize, 1, _> = argmax(&t, 1, false).unwrap();
+/// assert_eq!(idx.as_slice(), [1, 0]);
+/// ```
+pub fn argmax<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<usize, M, A>, TensorOpsError>
+where
+    T: PartialOrd + Clone + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    extremum(tensor, &[dim], keepdim, |a: &T, b: &T| a > b).map(|t| t.map(|(i, _)| *i))
+}
+
+/// Find the extreme values of the groups and their position within the group.
+fn extremum<T, const N: usize, const M: usize, A, F>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+    keep: F,
+) -> Result<Tensor<(usize, T), M, A>, TensorOpsError>
+where
+    T: Clone + Send + Sync,
+    A: TensorAllocator + 'static,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    check_non_empty(tensor, dims)?;
+    let res = reduce(tensor, dims, keepdim, ExtremumReducer { keep })?;
+    // the groups are not empty, so all the accumulators hold a value
+    Ok(res.map(|v| v.clone().expect("non empty reduction")))
+}
+
+/// Test if any element of a tensor is non zero over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::any;
+///
+/// let data: [u8; 4] = [0, 0, 0, 255];
+/// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_slice([2, 2], &data, CpuAllocator).unwrap();
+/// let a: Tensor<bool, 1, _> = any(&t, &[1], false).unwrap();
+/// assert_eq!(a.as_slice(), [false, true]);
+/// ```
+pub fn any<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<bool, M, A>, TensorOpsError>
+where
+    T: Zero + PartialEq + Sync,
+    A: TensorAllocator + 'static,
+{
+    let pred = |x: &T| !x.is_zero();
+    reduce(tensor, dims, keepdim, PredicateReducer { pred, all: false })
+}
+
+/// Test if all the elements of a tensor are non zero over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+pub fn all<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<bool, M, A>, TensorOpsError>
+where
+    T: Zero + PartialEq + Sync,
+    A: TensorAllocator + 'static,
+{
+    let pred = |x: &T| !x.is_zero();
+    reduce(tensor, dims, keepdim, PredicateReducer { pred, all: true })
+}
+
+/// Compute the L1 norm, i.e. the sum of absolute values, over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+pub fn l1_norm<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    reduce(tensor, dims, keepdim, NormReducer { p: 1 })
+}
+
+/// Compute the L2 norm, i.e. the square root of the sum of squares, over the given dimensions.
+///
+/// See [`sum`] for the semantics of `dims` and `keepdim`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::ops::l2_norm;
+///
+/// let data: [f32; 4] = [3., 4., 0., 1.];
+/// let t = Tensor::<f32, 2, CpuAllocator>::from_shape_slice([2, 2], &data, CpuAllocator).unwrap();
+/// let n: Tensor<f32, 2, _> = l2_norm(&t, &[1], true).unwrap();
+/// assert_eq!(n.shape, [2, 1]);
+/// assert_eq!(n.as_slice(), [5., 1.]);
+/// ```
+pub fn l2_norm<T, const N: usize, const M: usize, A>(
+    tensor: &Tensor<T, N, A>,
+    dims: &[usize],
+    keepdim: bool,
+) -> Result<Tensor<T, M, A>, TensorOpsError>
+where
+    T: Float + Send + Sync,
+    A: TensorAllocator + 'static,
+{
+    reduce(tensor, dims, keepdim, NormReducer { p: 2 })
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_tensor::{CpuAllocator, TensorError};
@@ -151,4 +941,129 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reduce_invalid_dims() -> Result<(), TensorOpsError> {
+        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_val([2, 3], 1, CpuAllocator);
+        let res = sum::<_, 2, 1, _>(&t, &[2], false);
+        assert!(res.is_err_and(|e| e == TensorOpsError::DimOutOfBounds(2, 1)));
+        let res = sum::<_, 2, 0, _>(&t, &[1, 1], false);
+        assert!(res.is_err_and(|e| e == TensorOpsError::RepeatedDim(1)));
+        let res = sum::<_, 2, 2, _>(&t, &[1], false);
+        assert!(res.is_err_and(|e| e == TensorOpsError::InvalidOutputRank(2, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sum_prod_dims() -> Result<(), TensorOpsError> {
+        let data: Vec<i32> = (1..=24).collect();
+        let t = Tensor::<i32, 3, CpuAllocator>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
+
+        let agg: Tensor<i32, 1, _> = sum(&t, &[0, 2], false)?;
+        assert_eq!(agg.shape, [3]);
+        assert_eq!(agg.as_slice(), [68, 100, 132]);
+
+        let agg: Tensor<i32, 3, _> = sum(&t, &[0, 2], true)?;
+        assert_eq!(agg.shape, [1, 3, 1]);
+        assert_eq!(agg.strides, [3, 1, 1]);
+
+        let agg: Tensor<i32, 0, _> = sum(&t, &[], false)?;
+        assert_eq!(agg.as_slice(), [300]);
+
+        let agg: Tensor<i32, 2, _> = prod(&t, &[0], false)?;
+        assert_eq!(agg.shape, [3, 4]);
+        assert_eq!(agg.as_slice()[..3], [13, 28, 45]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_var_std() -> Result<(), TensorOpsError> {
+        let data: [f64; 6] = [1., 2., 3., 4., 6., 8.];
+        let t = Tensor::<f64, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator)?;
+
+        let m: Tensor<f64, 1, _> = mean(&t, &[1], false)?;
+        assert_eq!(m.as_slice(), [2., 6.]);
+
+        let v: Tensor<f64, 1, _> = var(&t, &[1], 1, false)?;
+        assert_eq!(v.as_slice(), [1., 4.]);
+
+        let v: Tensor<f64, 1, _> = var(&t, &[0], 0, false)?;
+        assert_eq!(v.as_slice(), [2.25, 4., 6.25]);
+
+        let s: Tensor<f64, 2, _> = std(&t, &[1], 1, true)?;
+        assert_eq!(s.shape, [2, 1]);
+        assert_eq!(s.as_slice(), [1., 2.]);
+
+        let v: Tensor<f64, 1, _> = var(&t, &[0], 2, false)?;
+        assert!(v.as_slice().iter().all(|x| x.is_nan()));
+
+        let empty = Tensor::<f64, 2, CpuAllocator>::from_shape_vec([0, 3], vec![], CpuAllocator)?;
+        let res = mean::<_, 2, 1, _>(&empty, &[0], false);
+        assert!(res.is_err_and(|e| e == TensorOpsError::EmptyReduction));
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_max_arg() -> Result<(), TensorOpsError> {
+        let data: [f32; 6] = [3., -1., 2., 0., 5., 5.];
+        let t = Tensor::<f32, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator)?;
+
+        let v: Tensor<f32, 1, _> = min(&t, &[1], false)?;
+        assert_eq!(v.as_slice(), [-1., 0.]);
+        let v: Tensor<f32, 1, _> = max(&t, &[0], false)?;
+        assert_eq!(v.as_slice(), [3., 5., 5.]);
+        let v: Tensor<f32, 0, _> = max(&t, &[], false)?;
+        assert_eq!(v.as_slice(), [5.]);
+
+        let i: Tensor<usize, 1, _> = argmin(&t, 1, false)?;
+        assert_eq!(i.as_slice(), [1, 0]);
+        let i: Tensor<usize, 2, _> = argmax(&t, 1, true)?;
+        assert_eq!(i.shape, [2, 1]);
+        assert_eq!(i.as_slice(), [0, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_any_all_norms() -> Result<(), TensorOpsError> {
+        let data: [f32; 6] = [0., 0., 0., 3., -4., 0.];
+        let t = Tensor::<f32, 2, CpuAllocator>::from_shape_slice([2, 3], &data, CpuAllocator)?;
+
+        let a: Tensor<bool, 1, _> = any(&t, &[1], false)?;
+        assert_eq!(a.as_slice(), [false, true]);
+        let a: Tensor<bool, 1, _> = all(&t, &[0], false)?;
+        assert_eq!(a.as_slice(), [false, false, false]);
+
+        let n: Tensor<f32, 1, _> = l1_norm(&t, &[1], false)?;
+        assert_eq!(n.as_slice(), [0., 7.]);
+        let n: Tensor<f32, 1, _> = l2_norm(&t, &[1], false)?;
+        assert_eq!(n.as_slice(), [0., 5.]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce_parallel() -> Result<(), TensorOpsError> {
+        let (rows, cols) = (512, 256);
+        let t =
+            Tensor::<f64, 2, CpuAllocator>::from_shape_fn([rows, cols], CpuAllocator, |[i, j]| {
+                (i * cols + j) as f64
+            });
+        let n = (rows * cols) as f64;
+
+        // full reduction splits a single group across threads
+        let m: Tensor<f64, 0, _> = mean(&t, &[], false)?;
+        assert_eq!(m.as_slice(), [(n - 1.) / 2.]);
+        let v: Tensor<f64, 0, _> = var(&t, &[], 0, false)?;
+        assert!((v.as_slice()[0] - (n * n - 1.) / 12.).abs() < 1e-3);
+        let i: Tensor<usize, 0, _> = argmax(&t.reshape([rows * cols])?.as_contiguous(), 0, false)?;
+        assert_eq!(i.as_slice(), [rows * cols - 1]);
+
+        // many groups are reduced in parallel
+        let s: Tensor<f64, 1, _> = sum(&t, &[0], false)?;
+        assert_eq!(s.shape, [cols]);
+        assert_eq!(
+            s.as_slice()[1],
+            (0..rows).map(|i| (i * cols + 1) as f64).sum::<f64>()
+        );
+        Ok(())
+    }
 }
@@ -1,5 +1,9 @@
 use std::alloc;
 use std::alloc::Layout;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use thiserror::Error;
 
@@ -31,6 +35,14 @@ pub trait TensorAllocator: Clone {
 
     /// Deallocates memory for a tensor with the given layout.
     fn dealloc(&self, ptr: *mut u8, layout: Layout);
+
+    /// Returns true if the memory of this allocator is interchangeable with the memory of a `Vec`.
+    ///
+    /// When true, storages adopt the buffer of a `Vec` without copying and can be converted back
+    /// into a `Vec` for free. Otherwise the data is copied from/into memory owned by the allocator.
+    fn is_vec_compatible(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Clone)]
@@ -81,6 +93,323 @@ impl TensorAllocator for CpuAllocator {
     }
 }
 
+/// A snapshot of the allocation statistics of an allocator.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocatorStats {
+    /// The number of bytes currently allocated by the tensors.
+    pub bytes_live: usize,
+    /// The maximum number of bytes allocated at the same time.
+    pub bytes_peak: usize,
+    /// The number of bytes kept in the cache of a pool allocator.
+    pub bytes_cached: usize,
+    /// The total number of allocation requests.
+    pub num_allocs: usize,
+    /// The total number of deallocation requests.
+    pub num_deallocs: usize,
+    /// The number of allocation requests served from the cache of a pool allocator.
+    pub num_reused: usize,
+    /// The time elapsed since the allocator was created.
+    pub elapsed: Duration,
+}
+
+impl AllocatorStats {
+    /// Returns the average number of allocation requests per second.
+    pub fn allocs_per_sec(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            self.num_allocs as f64 / secs
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Thread-safe counters shared by the clones of an allocator.
+#[derive(Debug)]
+struct StatsCounters {
+    bytes_live: AtomicUsize,
+    bytes_peak: AtomicUsize,
+    num_allocs: AtomicUsize,
+    num_deallocs: AtomicUsize,
+    num_reused: AtomicUsize,
+    start: Instant,
+}
+
+impl StatsCounters {
+    fn new() -> Self {
+        Self {
+            bytes_live: AtomicUsize::new(0),
+            bytes_peak: AtomicUsize::new(0),
+            num_allocs: AtomicUsize::new(0),
+            num_deallocs: AtomicUsize::new(0),
+            num_reused: AtomicUsize::new(0),
+            start: Instant::now(),
+        }
+    }
+
+    fn record_alloc(&self, size: usize, reused: bool) {
+        let live = self.bytes_live.fetch_add(size, Ordering::Relaxed) + size;
+        self.bytes_peak.fetch_max(live, Ordering::Relaxed);
+        self.num_allocs.fetch_add(1, Ordering::Relaxed);
+        if reused {
+            self.num_reused.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn record_dealloc(&self, size: usize) {
+        self.bytes_live.fetch_sub(size, Ordering::Relaxed);
+        self.num_deallocs.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self, bytes_cached: usize) -> AllocatorStats {
+        AllocatorStats {
+            bytes_live: self.bytes_live.load(Ordering::Relaxed),
+            bytes_peak: self.bytes_peak.load(Ordering::Relaxed),
+            bytes_cached,
+            num_allocs: self.num_allocs.load(Ordering::Relaxed),
+            num_deallocs: self.num_deallocs.load(Ordering::Relaxed),
+            num_reused: self.num_reused.load(Ordering::Relaxed),
+            elapsed: self.start.elapsed(),
+        }
+    }
+}
+
+/// A tensor allocator returning memory aligned to at least [`AlignedAllocator::ALIGNMENT`] bytes.
+///
+/// The alignment matches the cache line size and the widest SIMD registers, which allows
+/// kernels to use aligned loads and stores. The clones of the allocator share the statistics.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{allocator::AlignedAllocator, Tensor};
+///
+/// let alloc = AlignedAllocator::new();
+/// let t = Tensor::<f32, 2, _>::from_shape_val([3, 5], 1.0, alloc.clone());
+/// assert_eq!(t.as_ptr() as usize % AlignedAllocator::ALIGNMENT, 0);
+/// assert_eq!(alloc.stats().bytes_live, 3 * 5 * 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct AlignedAllocator {
+    stats: Arc<StatsCounters>,
+}
+
+impl AlignedAllocator {
+    /// The minimum alignment in bytes of the allocated memory.
+    pub const ALIGNMENT: usize = 64;
+
+    /// Creates a new aligned allocator.
+    pub fn new() -> Self {
+        Self {
+            stats: Arc::new(StatsCounters::new()),
+        }
+    }
+
+    /// Returns the allocation statistics of the allocator.
+    pub fn stats(&self) -> AllocatorStats {
+        self.stats.snapshot(0)
+    }
+
+    fn aligned_layout(layout: Layout) -> Result<Layout, TensorAllocatorError> {
+        layout
+            .align_to(Self::ALIGNMENT)
+            .map_err(TensorAllocatorError::LayoutError)
+    }
+}
+
+impl Default for AlignedAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TensorAllocator for AlignedAllocator {
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        let ptr = CpuAllocator.alloc(Self::aligned_layout(layout)?)?;
+        self.stats.record_alloc(layout.size(), false);
+        Ok(ptr)
+    }
+
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr.is_null() {
+            return;
+        }
+        // the layout was already validated when allocating
+        if let Ok(aligned) = Self::aligned_layout(layout) {
+            CpuAllocator.dealloc(ptr, aligned);
+            self.stats.record_dealloc(layout.size());
+        }
+    }
+
+    fn is_vec_compatible(&self) -> bool {
+        false
+    }
+}
+
+/// The buffers released to a pool allocator, grouped by layout.
+#[derive(Debug, Default)]
+struct PoolCache {
+    /// The addresses of the free buffers of each layout.
+    buffers: HashMap<Layout, Vec<usize>>,
+    /// The total number of bytes of the free buffers.
+    bytes: usize,
+}
+
+#[derive(Debug)]
+struct PoolInner<A: TensorAllocator> {
+    alloc: A,
+    cache: Mutex<PoolCache>,
+    max_cached_bytes: usize,
+    stats: StatsCounters,
+}
+
+impl<A: TensorAllocator> Drop for PoolInner<A> {
+    fn drop(&mut self) {
+        let cache = self.cache.get_mut().unwrap_or_else(|e| e.into_inner());
+        for (layout, ptrs) in cache.buffers.drain() {
+            for ptr in ptrs {
+                self.alloc.dealloc(ptr as *mut u8, layout);
+            }
+        }
+    }
+}
+
+/// A tensor allocator recycling the released buffers for the next allocations with the same layout.
+///
+/// Pipelines processing a stream of frames allocate buffers of the same sizes over and over.
+/// The pool keeps the released buffers and hands them back to the next allocation with the
+/// same [`Layout`], so after the first frame no memory is requested from the system. The memory
+/// is obtained from an inner allocator, [`AlignedAllocator`] by default.
+///
+/// The clones of the allocator share the same pool and statistics. The cached buffers are
+/// released when the last clone is dropped, or with [`PoolAllocator::clear`].
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{allocator::PoolAllocator, Tensor};
+///
+/// let pool = PoolAllocator::new();
+/// for _ in 0..3 {
+///     let frame = Tensor::<u8, 3, _>::from_shape_val([480, 640, 3], 0, pool.clone());
+///     assert_eq!(frame.numel(), 480 * 640 * 3);
+/// }
+///
+/// let stats = pool.stats();
+/// assert_eq!(stats.num_allocs, 3);
+/// assert_eq!(stats.num_reused, 2);
+/// assert_eq!(stats.bytes_live, 0);
+/// ```
+#[derive(Debug)]
+pub struct PoolAllocator<A: TensorAllocator = AlignedAllocator> {
+    inner: Arc<PoolInner<A>>,
+}
+
+impl<A: TensorAllocator> Clone for PoolAllocator<A> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl PoolAllocator<AlignedAllocator> {
+    /// Creates a new pool allocator backed by an [`AlignedAllocator`] without cache limit.
+    pub fn new() -> Self {
+        Self::with_allocator(AlignedAllocator::new(), usize::MAX)
+    }
+}
+
+impl Default for PoolAllocator<AlignedAllocator> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: TensorAllocator> PoolAllocator<A> {
+    /// Creates a new pool allocator on top of the given allocator.
+    ///
+    /// # Arguments
+    ///
+    /// * `alloc` - The allocator used to obtain and release the memory.
+    /// * `max_cached_bytes` - The maximum number of bytes kept in the pool. The buffers released
+    ///   beyond this limit are returned to the inner allocator.
+    pub fn with_allocator(alloc: A, max_cached_bytes: usize) -> Self {
+        Self {
+            inner: Arc::new(PoolInner {
+                alloc,
+                cache: Mutex::new(PoolCache::default()),
+                max_cached_bytes,
+                stats: StatsCounters::new(),
+            }),
+        }
+    }
+
+    /// Returns the allocation statistics of the pool.
+    pub fn stats(&self) -> AllocatorStats {
+        self.inner.stats.snapshot(self.lock_cache().bytes)
+    }
+
+    /// Releases all the cached buffers to the inner allocator.
+    pub fn clear(&self) {
+        let mut cache = self.lock_cache();
+        for (layout, ptrs) in cache.buffers.drain() {
+            for ptr in ptrs {
+                self.inner.alloc.dealloc(ptr as *mut u8, layout);
+            }
+        }
+        cache.bytes = 0;
+    }
+
+    fn lock_cache(&self) -> std::sync::MutexGuard<'_, PoolCache> {
+        // the cache is always left in a consistent state, so a poisoned lock is still usable
+        self.inner.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<A: TensorAllocator> TensorAllocator for PoolAllocator<A> {
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        let cached = {
+            let mut cache = self.lock_cache();
+            let ptr = cache.buffers.get_mut(&layout).and_then(|ptrs| ptrs.pop());
+            if ptr.is_some() {
+                cache.bytes -= layout.size();
+            }
+            ptr
+        };
+
+        let ptr = match cached {
+            Some(ptr) => ptr as *mut u8,
+            None => self.inner.alloc.alloc(layout)?,
+        };
+        self.inner
+            .stats
+            .record_alloc(layout.size(), cached.is_some());
+        Ok(ptr)
+    }
+
+    fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr.is_null() {
+            return;
+        }
+        self.inner.stats.record_dealloc(layout.size());
+
+        let mut cache = self.lock_cache();
+        if cache.bytes.saturating_add(layout.size()) > self.inner.max_cached_bytes {
+            drop(cache);
+            self.inner.alloc.dealloc(ptr, layout);
+            return;
+        }
+        cache.bytes += layout.size();
+        cache.buffers.entry(layout).or_default().push(ptr as usize);
+    }
+
+    fn is_vec_compatible(&self) -> bool {
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,4 +422,75 @@ mod tests {
         allocator.dealloc(ptr, layout);
         Ok(())
     }
+
+    #[test]
+    fn test_aligned_allocator() -> Result<(), TensorAllocatorError> {
+        let allocator = AlignedAllocator::new();
+        let layout = Layout::array::<u8>(13).map_err(TensorAllocatorError::LayoutError)?;
+        let ptr = allocator.alloc(layout)?;
+        assert_eq!(ptr as usize % AlignedAllocator::ALIGNMENT, 0);
+
+        let stats = allocator.clone().stats();
+        assert_eq!(stats.bytes_live, 13);
+        assert_eq!(stats.num_allocs, 1);
+
+        allocator.dealloc(ptr, layout);
+        let stats = allocator.stats();
+        assert_eq!(stats.bytes_live, 0);
+        assert_eq!(stats.bytes_peak, 13);
+        assert_eq!(stats.num_deallocs, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_allocator() -> Result<(), TensorAllocatorError> {
+        let pool = PoolAllocator::new();
+        let layout = Layout::array::<f32>(256).map_err(TensorAllocatorError::LayoutError)?;
+        let other = Layout::array::<f32>(128).map_err(TensorAllocatorError::LayoutError)?;
+
+        let ptr = pool.alloc(layout)?;
+        pool.dealloc(ptr, layout);
+        assert_eq!(pool.stats().bytes_cached, 1024);
+
+        // same layout reuses the buffer, a different one does not
+        let ptr2 = pool.alloc(layout)?;
+        assert_eq!(ptr, ptr2);
+        let ptr3 = pool.alloc(other)?;
+
+        let stats = pool.stats();
+        assert_eq!(stats.num_allocs, 3);
+        assert_eq!(stats.num_reused, 1);
+        assert_eq!(stats.bytes_live, 1024 + 512);
+        assert_eq!(stats.bytes_peak, 1024 + 512);
+        assert_eq!(stats.bytes_cached, 0);
+
+        pool.dealloc(ptr2, layout);
+        pool.dealloc(ptr3, other);
+        assert_eq!(pool.stats().bytes_cached, 1024 + 512);
+
+        pool.clear();
+        let stats = pool.stats();
+        assert_eq!(stats.bytes_cached, 0);
+        assert_eq!(stats.bytes_live, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_allocator_max_cached() -> Result<(), TensorAllocatorError> {
+        let inner = AlignedAllocator::new();
+        let pool = PoolAllocator::with_allocator(inner.clone(), 100);
+        let layout = Layout::array::<u8>(64).map_err(TensorAllocatorError::LayoutError)?;
+
+        let (a, b) = (pool.alloc(layout)?, pool.alloc(layout)?);
+        pool.dealloc(a, layout);
+        pool.dealloc(b, layout);
+
+        // only one buffer fits in the pool, the other one goes back to the inner allocator
+        assert_eq!(pool.stats().bytes_cached, 64);
+        assert_eq!(inner.stats().bytes_live, 64);
+
+        drop(pool);
+        assert_eq!(inner.stats().bytes_live, 0);
+        Ok(())
+    }
 }
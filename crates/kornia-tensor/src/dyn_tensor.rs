@@ -0,0 +1,502 @@
+use std::any::Any;
+
+use crate::{
+    allocator::CpuAllocator,
+    storage::TensorStorage,
+    tensor::{checked_numel, get_strides_from_shape},
+    Tensor, TensorError,
+};
+
+/// The data type of the elements of a [`DynTensor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DType {
+    /// Unsigned 8-bit integer.
+    U8,
+    /// Signed 8-bit integer.
+    I8,
+    /// Unsigned 16-bit integer.
+    U16,
+    /// Signed 16-bit integer.
+    I16,
+    /// Unsigned 32-bit integer.
+    U32,
+    /// Signed 32-bit integer.
+    I32,
+    /// Unsigned 64-bit integer.
+    U64,
+    /// Signed 64-bit integer.
+    I64,
+    /// IEEE 754 half precision float.
+    F16,
+    /// Brain floating point with 16 bits.
+    BF16,
+    /// IEEE 754 single precision float.
+    F32,
+    /// IEEE 754 double precision float.
+    F64,
+}
+
+impl DType {
+    /// Returns the size in bytes of one element of this data type.
+    pub fn size_of(&self) -> usize {
+        match self {
+            DType::U8 | DType::I8 => 1,
+            DType::U16 | DType::I16 | DType::F16 | DType::BF16 => 2,
+            DType::U32 | DType::I32 | DType::F32 => 4,
+            DType::U64 | DType::I64 | DType::F64 => 8,
+        }
+    }
+
+    /// Returns the name of the data type, e.g. `f32`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DType::U8 => "u8",
+            DType::I8 => "i8",
+            DType::U16 => "u16",
+            DType::I16 => "i16",
+            DType::U32 => "u32",
+            DType::I32 => "i32",
+            DType::U64 => "u64",
+            DType::I64 => "i64",
+            DType::F16 => "f16",
+            DType::BF16 => "bf16",
+            DType::F32 => "f32",
+            DType::F64 => "f64",
+        }
+    }
+}
+
+impl std::fmt::Display for DType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An element type that can be stored in a [`DynTensor`].
+pub trait DTypeElement: Copy + Send + Sync + 'static {
+    /// The runtime data type of the element.
+    const DTYPE: DType;
+}
+
+macro_rules! impl_dtype_element {
+    ($t:ty, $dtype:expr) => {
+        impl DTypeElement for $t {
+            const DTYPE: DType = $dtype;
+        }
+    };
+}
+
+impl_dtype_element!(u8, DType::U8);
+impl_dtype_element!(i8, DType::I8);
+impl_dtype_element!(u16, DType::U16);
+impl_dtype_element!(i16, DType::I16);
+impl_dtype_element!(u32, DType::U32);
+impl_dtype_element!(i32, DType::I32);
+impl_dtype_element!(u64, DType::U64);
+impl_dtype_element!(i64, DType::I64);
+impl_dtype_element!(f32, DType::F32);
+impl_dtype_element!(f64, DType::F64);
+#[cfg(feature = "half")]
+impl_dtype_element!(half::f16, DType::F16);
+#[cfg(feature = "half")]
+impl_dtype_element!(half::bf16, DType::BF16);
+
+/// A storage whose element type is only known at runtime.
+trait ErasedStorage: Send + Sync {
+    fn as_bytes(&self) -> &[u8];
+    fn as_any(&self) -> &dyn Any;
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+    fn clone_box(&self) -> Box<dyn ErasedStorage>;
+}
+
+impl<T: DTypeElement> ErasedStorage for TensorStorage<T, CpuAllocator> {
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: the storage holds `len` initialized bytes of plain old data.
+        unsafe { std::slice::from_raw_parts(self.as_ptr() as *const u8, self.len()) }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedStorage> {
+        Box::new(self.clone())
+    }
+}
+
+/// A tensor with a rank and a data type only known at runtime.
+///
+/// A `DynTensor` holds "some tensor" at the interop boundaries: an array loaded from a file,
+/// received over the wire or passed from Python. It is converted into a typed [`Tensor`] with
+/// [`DynTensor::try_into_tensor`], which checks the data type and the rank and moves the storage
+/// without copying the data.
+///
+/// The data is always contiguous in C order.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{CpuAllocator, DType, DynTensor, Tensor};
+///
+/// let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator).unwrap();
+/// let d = DynTensor::from(t);
+/// assert_eq!(d.dtype(), DType::U8);
+/// assert_eq!(d.shape(), &[2, 2]);
+///
+/// let t = d.try_into_tensor::<u8, 2>().unwrap();
+/// assert_eq!(t.as_slice(), &[1, 2, 3, 4]);
+/// ```
+pub struct DynTensor {
+    storage: Box<dyn ErasedStorage>,
+    dtype: DType,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+}
+
+impl DynTensor {
+    /// Creates a new `DynTensor` with the given shape and vector of data.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `data` - The data of the tensor in C order.
+    ///
+    /// # Errors
+    ///
+    /// If the number of elements in the data does not match the shape, or if the number of
+    /// elements of the shape overflows, an error is returned.
+    pub fn from_shape_vec<T: DTypeElement>(
+        shape: Vec<usize>,
+        data: Vec<T>,
+    ) -> Result<Self, TensorError> {
+        let numel = checked_numel(&shape)?;
+        if numel != data.len() {
+            return Err(TensorError::InvalidShape(numel));
+        }
+        Ok(Self::from_storage(
+            TensorStorage::from_vec(data, CpuAllocator),
+            shape,
+        ))
+    }
+
+    /// Creates a new `DynTensor` from the raw little-endian bytes of its elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `dtype` - The data type of the elements.
+    /// * `shape` - The shape of the tensor.
+    /// * `bytes` - The bytes of the elements in C order and little-endian byte order.
+    ///
+    /// # Errors
+    ///
+    /// If the number of bytes does not match the shape and the data type, if the size of the
+    /// shape overflows, or if the data type is not supported by the enabled features, an error
+    /// is returned.
+    pub fn from_le_bytes(
+        dtype: DType,
+        shape: Vec<usize>,
+        bytes: &[u8],
+    ) -> Result<Self, TensorError> {
+        fn decode<T: DTypeElement, const S: usize>(
+            shape: Vec<usize>,
+            bytes: &[u8],
+            f: fn([u8; S]) -> T,
+        ) -> Result<DynTensor, TensorError> {
+            let data = bytes
+                .chunks_exact(S)
+                .map(|c| f(c.try_into().expect("chunks have the element size")))
+                .collect();
+            DynTensor::from_shape_vec(shape, data)
+        }
+
+        let len = checked_numel(&shape)?
+            .checked_mul(dtype.size_of())
+            .ok_or_else(|| TensorError::ShapeOverflow(shape.clone()))?;
+        if bytes.len() != len {
+            return Err(TensorError::InvalidShape(bytes.len() / dtype.size_of()));
+        }
+
+        match dtype {
+            DType::U8 => decode(shape, bytes, u8::from_le_bytes),
+            DType::I8 => decode(shape, bytes, i8::from_le_bytes),
+            DType::U16 => decode(shape, bytes, u16::from_le_bytes),
+            DType::I16 => decode(shape, bytes, i16::from_le_bytes),
+            DType::U32 => decode(shape, bytes, u32::from_le_bytes),
+            DType::I32 => decode(shape, bytes, i32::from_le_bytes),
+            DType::U64 => decode(shape, bytes, u64::from_le_bytes),
+            DType::I64 => decode(shape, bytes, i64::from_le_bytes),
+            DType::F32 => decode(shape, bytes, f32::from_le_bytes),
+            DType::F64 => decode(shape, bytes, f64::from_le_bytes),
+            #[cfg(feature = "half")]
+            DType::F16 => decode(shape, bytes, half::f16::from_le_bytes),
+            #[cfg(feature = "half")]
+            DType::BF16 => decode(shape, bytes, half::bf16::from_le_bytes),
+            #[cfg(not(feature = "half"))]
+            DType::F16 | DType::BF16 => Err(TensorError::UnsupportedOperation(format!(
+                "the {dtype} data type requires the half feature"
+            ))),
+        }
+    }
+
+    fn from_storage<T: DTypeElement>(
+        storage: TensorStorage<T, CpuAllocator>,
+        shape: Vec<usize>,
+    ) -> Self {
+        let strides = dyn_strides(&shape);
+        Self {
+            storage: Box::new(storage),
+            dtype: T::DTYPE,
+            shape,
+            strides,
+        }
+    }
+
+    /// Returns the data type of the elements.
+    pub fn dtype(&self) -> DType {
+        self.dtype
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the strides of the tensor in elements.
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Returns the number of dimensions of the tensor.
+    pub fn rank(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Returns the number of elements in the tensor.
+    pub fn numel(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Changes the shape of the tensor without copying the data.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The new shape of the tensor.
+    ///
+    /// # Errors
+    ///
+    /// If the number of elements of the new shape does not match the tensor, or overflows, an
+    /// error is returned.
+    pub fn reshape(self, shape: Vec<usize>) -> Result<Self, TensorError> {
+        let numel = checked_numel(&shape)?;
+        if numel != self.numel() {
+            return Err(TensorError::DimensionMismatch(format!(
+                "cannot reshape a tensor of shape {:?} into {:?}",
+                self.shape, shape
+            )));
+        }
+        let strides = dyn_strides(&shape);
+        Ok(Self {
+            shape,
+            strides,
+            ..self
+        })
+    }
+
+    /// Returns the data of the tensor as raw bytes in the native byte order.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.storage.as_bytes()
+    }
+
+    /// Returns the data of the tensor as a typed slice.
+    ///
+    /// # Errors
+    ///
+    /// If `T` does not match the data type of the tensor, an error is returned.
+    pub fn as_slice<T: DTypeElement>(&self) -> Result<&[T], TensorError> {
+        self.check_dtype::<T>()?;
+        let storage = self
+            .storage
+            .as_any()
+            .downcast_ref::<TensorStorage<T, CpuAllocator>>()
+            .ok_or(TensorError::DTypeMismatch(T::DTYPE, self.dtype))?;
+        Ok(storage.as_slice())
+    }
+
+    /// Converts the tensor into a typed tensor without copying the data.
+    ///
+    /// # Errors
+    ///
+    /// If `T` does not match the data type, or `N` does not match the rank of the tensor, an
+    /// error is returned.
+    pub fn try_into_tensor<T: DTypeElement, const N: usize>(
+        self,
+    ) -> Result<Tensor<T, N, CpuAllocator>, TensorError> {
+        self.check_dtype::<T>()?;
+        let shape: [usize; N] = self.shape.as_slice().try_into().map_err(|_| {
+            TensorError::DimensionMismatch(format!(
+                "cannot convert a tensor of rank {} into a tensor of rank {N}",
+                self.rank()
+            ))
+        })?;
+        let storage = self
+            .storage
+            .into_any()
+            .downcast::<TensorStorage<T, CpuAllocator>>()
+            .map_err(|_| TensorError::DTypeMismatch(T::DTYPE, self.dtype))?;
+        Ok(Tensor {
+            storage: *storage,
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+
+    fn check_dtype<T: DTypeElement>(&self) -> Result<(), TensorError> {
+        if T::DTYPE != self.dtype {
+            return Err(TensorError::DTypeMismatch(T::DTYPE, self.dtype));
+        }
+        Ok(())
+    }
+}
+
+/// Compute the C order strides of a shape known at runtime.
+fn dyn_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+impl<T: DTypeElement, const N: usize> From<Tensor<T, N, CpuAllocator>> for DynTensor {
+    fn from(tensor: Tensor<T, N, CpuAllocator>) -> Self {
+        // the views are not stored, so the data of a tensor is always contiguous.
+        Self::from_storage(tensor.storage, tensor.shape.to_vec())
+    }
+}
+
+impl<T: DTypeElement, const N: usize> TryFrom<DynTensor> for Tensor<T, N, CpuAllocator> {
+    type Error = TensorError;
+
+    fn try_from(value: DynTensor) -> Result<Self, Self::Error> {
+        value.try_into_tensor()
+    }
+}
+
+impl Clone for DynTensor {
+    fn clone(&self) -> Self {
+        Self {
+            storage: self.storage.clone_box(),
+            dtype: self.dtype,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for DynTensor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynTensor")
+            .field("dtype", &self.dtype)
+            .field("shape", &self.shape)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DType, DynTensor};
+    use crate::{CpuAllocator, Tensor, TensorError};
+
+    #[test]
+    fn test_dyn_tensor_roundtrip() -> Result<(), TensorError> {
+        let t = Tensor::<f32, 3, _>::from_shape_fn([2, 3, 4], CpuAllocator, |[i, j, k]| {
+            (i * 12 + j * 4 + k) as f32
+        });
+        let ptr = t.as_ptr();
+        let d = DynTensor::from(t);
+        assert_eq!(d.dtype(), DType::F32);
+        assert_eq!(d.shape(), &[2, 3, 4]);
+        assert_eq!(d.strides(), &[12, 4, 1]);
+        assert_eq!(d.rank(), 3);
+        assert_eq!(d.numel(), 24);
+        assert_eq!(d.as_bytes().len(), 24 * 4);
+        assert_eq!(d.as_slice::<f32>()?[5], 5.0);
+
+        let t: Tensor<f32, 3, _> = d.try_into()?;
+        assert_eq!(t.as_ptr(), ptr);
+        assert_eq!(t.shape, [2, 3, 4]);
+        assert_eq!(t.strides, [12, 4, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dyn_tensor_mismatch() -> Result<(), TensorError> {
+        let d = DynTensor::from_shape_vec(vec![2, 2], vec![1u16, 2, 3, 4])?;
+        assert_eq!(
+            d.as_slice::<u8>(),
+            Err(TensorError::DTypeMismatch(DType::U8, DType::U16))
+        );
+        assert!(matches!(
+            d.clone().try_into_tensor::<u16, 3>(),
+            Err(TensorError::DimensionMismatch(_))
+        ));
+        assert!(matches!(
+            d.clone().try_into_tensor::<f32, 2>(),
+            Err(TensorError::DTypeMismatch(DType::F32, DType::U16))
+        ));
+        assert_eq!(d.try_into_tensor::<u16, 2>()?.as_slice(), &[1, 2, 3, 4]);
+
+        assert_eq!(
+            DynTensor::from_shape_vec(vec![3], vec![0u8; 2]).err(),
+            Some(TensorError::InvalidShape(3))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dyn_tensor_from_le_bytes() -> Result<(), TensorError> {
+        let bytes = [1.5f32, -2.0]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>();
+        let d = DynTensor::from_le_bytes(DType::F32, vec![2, 1], &bytes)?;
+        assert_eq!(d.as_slice::<f32>()?, &[1.5, -2.0]);
+        assert!(DynTensor::from_le_bytes(DType::F64, vec![2, 1], &bytes).is_err());
+
+        let d = d.reshape(vec![1, 2])?;
+        assert_eq!(d.strides(), &[2, 1]);
+        assert!(d.reshape(vec![3]).is_err());
+
+        let d = DynTensor::from_le_bytes(DType::U8, vec![], &[7])?;
+        assert_eq!(d.rank(), 0);
+        assert_eq!(d.try_into_tensor::<u8, 0>()?.as_slice(), &[7]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dyn_tensor_shape_overflow() -> Result<(), TensorError> {
+        let shape = vec![usize::MAX / 2 + 1, 2];
+        assert_eq!(
+            DynTensor::from_shape_vec(shape.clone(), vec![0u8; 4]).err(),
+            Some(TensorError::ShapeOverflow(shape.clone()))
+        );
+
+        // the number of elements fits but the number of bytes overflows
+        let shape = vec![usize::MAX / 4 + 1, 2];
+        assert_eq!(
+            DynTensor::from_le_bytes(DType::F32, shape.clone(), &[0; 16]).err(),
+            Some(TensorError::ShapeOverflow(shape))
+        );
+
+        let d = DynTensor::from_shape_vec(vec![4], vec![0u8; 4])?;
+        assert!(matches!(
+            d.reshape(vec![usize::MAX, 3]),
+            Err(TensorError::ShapeOverflow(_))
+        ));
+        Ok(())
+    }
+}
@@ -8,6 +8,9 @@ pub mod allocator;
 #[cfg(feature = "bincode")]
 pub mod bincode;
 
+/// dyn_tensor module containing the tensor with a runtime rank and data type.
+pub mod dyn_tensor;
+
 /// tensor module containing the tensor and storage implementations.
 pub mod tensor;
 
@@ -22,8 +25,11 @@ pub mod storage;
 pub mod view;
 
 pub use crate::allocator::{CpuAllocator, TensorAllocator};
+pub use crate::dyn_tensor::{DType, DTypeElement, DynTensor};
 pub(crate) use crate::tensor::get_strides_from_shape;
-pub use crate::tensor::{Tensor, TensorError};
+pub use crate::tensor::{checked_numel, Tensor, TensorError};
+#[cfg(feature = "half")]
+pub use half::{bf16, f16};
 
 /// Type alias for a 1-dimensional tensor.
 pub type Tensor1<T, A> = Tensor<T, 1, A>;
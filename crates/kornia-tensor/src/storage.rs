@@ -1,8 +1,16 @@
 use std::{alloc::Layout, ptr::NonNull};
 
 use crate::allocator::TensorAllocator;
+use crate::TensorError;
+
+/// A callback releasing the memory of a storage that is not owned by its allocator.
+type ReleaseFn = Box<dyn FnOnce() + Send + 'static>;
 
 /// Definition of the buffer for a tensor.
+///
+/// The memory is either owned by the allocator, or borrowed from a foreign owner (a memory-mapped
+/// file, a buffer of another library, ...) which is released by a callback when the storage is
+/// dropped.
 pub struct TensorStorage<T, A: TensorAllocator> {
     /// The pointer to the tensor memory which must be non null.
     pub(crate) ptr: NonNull<T>,
@@ -12,6 +20,8 @@ pub struct TensorStorage<T, A: TensorAllocator> {
     pub(crate) layout: Layout,
     /// The allocator used to allocate/deallocate the tensor memory.
     pub(crate) alloc: A,
+    /// The callback releasing the memory of a foreign buffer instead of the allocator.
+    pub(crate) release: Option<ReleaseFn>,
 }
 
 impl<T, A: TensorAllocator> TensorStorage<T, A> {
@@ -63,10 +73,47 @@ impl<T, A: TensorAllocator> TensorStorage<T, A> {
         &self.alloc
     }
 
-    // TODO: use the allocator somehow
+    /// Allocates uninitialized memory for `len` elements with the given allocator.
+    ///
+    /// Zero sized buffers are not allocated and use a dangling pointer instead.
+    fn allocate(len: usize, alloc: &A) -> (NonNull<T>, Layout) {
+        let layout = Layout::array::<T>(len).expect("capacity overflow");
+        if layout.size() == 0 {
+            return (NonNull::dangling(), layout);
+        }
+        match alloc.alloc(layout).map(|ptr| NonNull::new(ptr as *mut T)) {
+            Ok(Some(ptr)) => (ptr, layout),
+            _ => std::alloc::handle_alloc_error(layout),
+        }
+    }
+
     /// Creates a new tensor buffer from a vector.
+    ///
+    /// The buffer of the vector is adopted without copying if the allocator is compatible with
+    /// `Vec` memory (see [`TensorAllocator::is_vec_compatible`]), otherwise the data is moved
+    /// into memory allocated by the allocator.
     pub fn from_vec(value: Vec<T>, alloc: A) -> Self {
-        //let buf = arrow_buffer::Buffer::from_vec(value);
+        if !alloc.is_vec_compatible() {
+            let mut value = std::mem::ManuallyDrop::new(value);
+            let (ptr, layout) = Self::allocate(value.len(), &alloc);
+            let len = value.len() * std::mem::size_of::<T>();
+            // Safety
+            // The elements are moved bitwise into the new buffer and the vector is released
+            // without dropping them.
+            unsafe {
+                std::ptr::copy_nonoverlapping(value.as_ptr(), ptr.as_ptr(), value.len());
+                value.set_len(0);
+                std::mem::ManuallyDrop::drop(&mut value);
+            }
+            return Self {
+                ptr,
+                len,
+                layout,
+                alloc,
+                release: None,
+            };
+        }
+
         // Safety
         // Vec::as_ptr guaranteed to not be null
         let ptr = unsafe { NonNull::new_unchecked(value.as_ptr() as _) };
@@ -82,7 +129,34 @@ impl<T, A: TensorAllocator> TensorStorage<T, A> {
             len,
             layout,
             alloc,
+            release: None,
+        }
+    }
+
+    /// Creates a new tensor buffer with `len` elements generated by a function.
+    ///
+    /// The memory is allocated with the given allocator and the function `f` is called with
+    /// the index of each element.
+    pub fn from_fn<F>(len: usize, alloc: A, mut f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        let (ptr, layout) = Self::allocate(len, &alloc);
+        let mut storage = Self {
+            ptr,
+            len: 0,
+            layout,
+            alloc,
+            release: None,
+        };
+        for i in 0..len {
+            // Safety
+            // The buffer has capacity for `len` elements. The length is updated after each
+            // write so a panic in `f` never exposes uninitialized memory.
+            unsafe { storage.ptr.as_ptr().add(i).write(f(i)) };
+            storage.len += std::mem::size_of::<T>();
         }
+        storage
     }
 
     /// Creates a new tensor buffer from a raw pointer.
@@ -98,26 +172,134 @@ impl<T, A: TensorAllocator> TensorStorage<T, A> {
             len,
             layout,
             alloc,
+            release: None,
         }
     }
 
-    /// Converts the `TensorStorage` into a `Vec<T>`.
+    /// Creates a new tensor buffer from memory owned by a foreign owner.
     ///
-    /// Returns `Err(self)` if the buffer does not have the same layout as the destination Vec.
-    pub fn into_vec(self) -> Vec<T> {
-        // TODO: check if the buffer is a cpu buffer or comes from a custom allocator
-        let _layout = &self.layout;
+    /// The allocator is not used to release the memory. Instead, the `release` callback is
+    /// called when the storage is dropped, e.g. to unref a GStreamer buffer, call the deleter of
+    /// a DLPack capsule or unmap a shared memory segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr` - The pointer to the first element of the buffer.
+    /// * `len` - The number of elements of the buffer.
+    /// * `alloc` - The allocator used to allocate the clones of the storage.
+    /// * `release` - The callback releasing the buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the size in bytes of the buffer overflows, an error is returned and `release` is
+    /// called.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be aligned for `T` and valid for reads and writes of `len` elements
+    /// until `release` is called.
+    pub unsafe fn from_foreign<F>(
+        ptr: NonNull<T>,
+        len: usize,
+        alloc: A,
+        release: F,
+    ) -> Result<Self, TensorError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let Some(size) = len.checked_mul(std::mem::size_of::<T>()) else {
+            release();
+            return Err(TensorError::ShapeOverflow(vec![len]));
+        };
+        Ok(Self {
+            ptr,
+            len: size,
+            layout: Layout::from_size_align_unchecked(size, std::mem::align_of::<T>()),
+            alloc,
+            release: Some(Box::new(release)),
+        })
+    }
 
-        let vec_capacity = self.layout.size() / std::mem::size_of::<T>();
-        //match Layout::array::<T>(vec_capacity) {
-        //    Ok(expected) if layout == &expected => {}
-        //    e => return Err(TensorAllocatorError::LayoutError(e.unwrap_err())),
-        //}
+    /// Creates a new tensor buffer backed by a memory-mapped file.
+    ///
+    /// The mapping is kept alive by the storage and unmapped when the storage is dropped.
+    /// Use a copy-on-write mapping (`MmapOptions::map_copy`) to modify the data without writing
+    /// it back to the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `mmap` - The memory map.
+    /// * `offset` - The offset in bytes of the first element in the mapping.
+    /// * `len` - The number of elements of the buffer.
+    /// * `alloc` - The allocator used to allocate the clones of the storage.
+    ///
+    /// # Errors
+    ///
+    /// If the mapping is too small or the data is not aligned for `T`, an error is returned.
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap(
+        mut mmap: memmap2::MmapMut,
+        offset: usize,
+        len: usize,
+        alloc: A,
+    ) -> Result<Self, TensorError> {
+        let required = len
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|n| n.checked_add(offset))
+            .unwrap_or(usize::MAX);
+        if required > mmap.len() {
+            return Err(TensorError::BufferTooSmall(mmap.len(), required));
+        }
+
+        // Safety
+        // The offset is within the mapping as checked above.
+        let ptr = unsafe { mmap.as_mut_ptr().add(offset) };
+        if (ptr as usize) % std::mem::align_of::<T>() != 0 {
+            return Err(TensorError::MisalignedBuffer(
+                ptr as usize,
+                std::mem::align_of::<T>(),
+            ));
+        }
+
+        // Safety
+        // The mapping is valid for `len` elements and is moved into the release callback, so it
+        // outlives the storage. Moving the `MmapMut` does not move the mapped memory.
+        let ptr = unsafe { NonNull::new_unchecked(ptr as *mut T) };
+        unsafe { Self::from_foreign(ptr, len, alloc, move || drop(mmap)) }
+    }
+
+    /// Returns true if the memory is owned by a foreign owner instead of the allocator.
+    #[inline]
+    pub fn is_foreign(&self) -> bool {
+        self.release.is_some()
+    }
 
+    /// Converts the `TensorStorage` into a `Vec<T>`.
+    ///
+    /// The buffer is handed over without copying if the allocator is compatible with `Vec`
+    /// memory, otherwise (or for foreign buffers) the data is moved into a new vector and the
+    /// buffer is released.
+    pub fn into_vec(self) -> Vec<T> {
         let length = self.len;
         let ptr = self.ptr;
         let vec_len = length / std::mem::size_of::<T>();
 
+        if !self.alloc.is_vec_compatible() || self.is_foreign() {
+            let mut vec = Vec::with_capacity(vec_len);
+            // Safety
+            // The elements are moved bitwise into the vector and the buffer is released
+            // without dropping them.
+            unsafe {
+                std::ptr::copy_nonoverlapping(ptr.as_ptr(), vec.as_mut_ptr(), vec_len);
+                vec.set_len(vec_len);
+            }
+            // the storage releases the buffer without dropping the elements
+            drop(self);
+            return vec;
+        }
+
+        let vec_capacity = self.layout.size() / std::mem::size_of::<T>();
+
         // Safety
         std::mem::forget(self);
         unsafe { Vec::from_raw_parts(ptr.as_ptr(), vec_len, vec_capacity) }
@@ -131,6 +313,14 @@ unsafe impl<T, A: TensorAllocator> Sync for TensorStorage<T, A> {}
 
 impl<T, A: TensorAllocator> Drop for TensorStorage<T, A> {
     fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            release();
+            return;
+        }
+        // zero sized buffers (e.g. from an empty `Vec`) hold a dangling pointer
+        if self.layout.size() == 0 {
+            return;
+        }
         self.alloc
             .dealloc(self.ptr.as_ptr() as *mut u8, self.layout);
     }
@@ -142,13 +332,8 @@ where
     A: TensorAllocator + 'static,
 {
     fn clone(&self) -> Self {
-        let mut new_vec = Vec::<T>::with_capacity(self.len());
-
-        for i in self.as_slice() {
-            new_vec.push(i.clone());
-        }
-
-        Self::from_vec(new_vec, self.alloc.clone())
+        let data = self.as_slice();
+        Self::from_fn(data.len(), self.alloc.clone(), |i| data[i].clone())
     }
 }
 
@@ -156,8 +341,8 @@ where
 mod tests {
 
     use super::TensorStorage;
-    use crate::allocator::{CpuAllocator, TensorAllocatorError};
-    use crate::TensorAllocator;
+    use crate::allocator::{AlignedAllocator, CpuAllocator, TensorAllocatorError};
+    use crate::{Tensor, TensorAllocator, TensorError};
     use std::alloc::Layout;
     use std::cell::RefCell;
     use std::ptr::NonNull;
@@ -177,6 +362,7 @@ mod tests {
             len: size * std::mem::size_of::<u8>(),
             layout,
             ptr,
+            release: None,
         };
 
         assert_eq!(buffer.ptr.as_ptr(), ptr_raw);
@@ -218,6 +404,7 @@ mod tests {
             len: size,
             layout,
             ptr: ptr.cast::<f32>(),
+            release: None,
         };
 
         assert_eq!(buffer.as_ptr(), ptr.as_ptr() as *const f32);
@@ -337,6 +524,107 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_tensor_buffer_aligned() -> Result<(), TensorAllocatorError> {
+        let allocator = AlignedAllocator::new();
+
+        // the data of the vector is moved into aligned memory
+        let buffer = TensorStorage::from_vec(vec![1u16, 2, 3], allocator.clone());
+        assert_eq!(buffer.as_ptr() as usize % AlignedAllocator::ALIGNMENT, 0);
+        assert_eq!(buffer.as_slice(), [1, 2, 3]);
+        assert_eq!(allocator.stats().bytes_live, 6);
+
+        let cloned = buffer.clone();
+        assert_eq!(cloned.as_slice(), [1, 2, 3]);
+        assert_eq!(allocator.stats().bytes_live, 12);
+
+        assert_eq!(buffer.into_vec(), vec![1, 2, 3]);
+        drop(cloned);
+        assert_eq!(allocator.stats().bytes_live, 0);
+
+        let buffer = TensorStorage::from_fn(4, allocator.clone(), |i| i as f64);
+        assert_eq!(buffer.as_slice(), [0.0, 1.0, 2.0, 3.0]);
+
+        let empty = TensorStorage::<f32, _>::from_fn(0, allocator.clone(), |_| 0.0);
+        assert!(empty.is_empty());
+        assert_eq!(allocator.stats().num_allocs, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tensor_buffer_foreign() -> Result<(), TensorError> {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let mut data = vec![1u32, 2, 3, 4];
+        let released = Arc::new(AtomicBool::new(false));
+        let ptr = NonNull::new(data.as_mut_ptr()).ok_or(TensorAllocatorError::NullPointer)?;
+
+        let buffer = unsafe {
+            let released = released.clone();
+            TensorStorage::from_foreign(ptr, data.len(), CpuAllocator, move || {
+                released.store(true, Ordering::SeqCst)
+            })?
+        };
+        assert!(buffer.is_foreign());
+        assert!(std::ptr::eq(buffer.as_ptr(), data.as_ptr()));
+        assert_eq!(buffer.as_slice(), [1, 2, 3, 4]);
+
+        // clones own their memory
+        let cloned = buffer.clone();
+        assert!(!cloned.is_foreign());
+        assert_eq!(cloned.into_vec(), vec![1, 2, 3, 4]);
+        assert!(!released.load(Ordering::SeqCst));
+
+        // the data is copied out before releasing the foreign buffer
+        assert_eq!(buffer.into_vec(), vec![1, 2, 3, 4]);
+        assert!(released.load(Ordering::SeqCst));
+
+        // the buffer is released when the size overflows
+        for shape in [[usize::MAX, 2], [usize::MAX / 2, 1]] {
+            let released = Arc::new(AtomicBool::new(false));
+            let res = unsafe {
+                let released = released.clone();
+                Tensor::<u32, 2, _>::from_foreign(shape, ptr, CpuAllocator, move || {
+                    released.store(true, Ordering::SeqCst)
+                })
+            };
+            assert!(res.is_err_and(|e| matches!(e, TensorError::ShapeOverflow(_))));
+            assert!(released.load(Ordering::SeqCst));
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_tensor_buffer_mmap() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        let mut file = tempfile::tempfile()?;
+        let data = [1.0f32, 2.0, 3.0];
+        for x in data {
+            file.write_all(&x.to_ne_bytes())?;
+        }
+
+        let map = || unsafe { memmap2::MmapOptions::new().map_copy(&file) };
+        let mmap = map()?;
+        let mut buffer = TensorStorage::<f32, _>::from_mmap(mmap, 4, 2, CpuAllocator)?;
+        assert_eq!(buffer.as_slice(), [2.0, 3.0]);
+
+        // copy-on-write mapping, the file is not modified
+        buffer.as_mut_slice()[0] = 5.0;
+        assert_eq!(buffer.as_slice(), [5.0, 3.0]);
+
+        let mmap = map()?;
+        let res = TensorStorage::<f32, _>::from_mmap(mmap, 4, 3, CpuAllocator);
+        assert!(res.is_err_and(|e| e == crate::TensorError::BufferTooSmall(12, 16)));
+
+        let mmap = map()?;
+        let res = TensorStorage::<f32, _>::from_mmap(mmap, 1, 1, CpuAllocator);
+        assert!(res.is_err_and(|e| matches!(e, crate::TensorError::MisalignedBuffer(_, 4))));
+        Ok(())
+    }
+
     #[test]
     fn test_tensor_mutability() -> Result<(), TensorAllocatorError> {
         let vec: Vec<i32> = vec![1, 2, 3, 4, 5];
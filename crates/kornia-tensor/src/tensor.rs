@@ -4,7 +4,7 @@ use thiserror::Error;
 use super::{
     allocator::{CpuAllocator, TensorAllocator, TensorAllocatorError},
     storage::TensorStorage,
-    view::TensorView,
+    view::{TensorView, TensorViewMut},
 };
 
 /// An error type for tensor operations.
@@ -33,6 +33,51 @@ pub enum TensorError {
     /// Unsupported operation for the given data type or tensor configuration.
     #[error("Unsupported operation: {0}")]
     UnsupportedOperation(String),
+
+    /// The shapes of two tensors cannot be broadcast together.
+    #[error("Shapes {0:?} and {1:?} cannot be broadcast together: mismatch at dimension {2}")]
+    BroadcastError(Vec<usize>, Vec<usize>, usize),
+
+    /// The buffer is too small to hold the data of the tensor.
+    #[error("The buffer of {0} bytes is too small, {1} bytes are required")]
+    BufferTooSmall(usize, usize),
+
+    /// The buffer is not aligned for the element type of the tensor.
+    #[error("The buffer address {0:#x} is not aligned to {1} bytes")]
+    MisalignedBuffer(usize, usize),
+
+    /// The data type of a dynamic tensor does not match the requested element type.
+    #[error("Data type mismatch: expected {0}, found {1}")]
+    DTypeMismatch(crate::DType, crate::DType),
+
+    /// The size of a tensor with the given shape overflows `usize`.
+    #[error("The size of a tensor of shape {0:?} overflows")]
+    ShapeOverflow(Vec<usize>),
+}
+
+/// Compute the number of elements of a shape, checking for overflow.
+///
+/// # Arguments
+///
+/// * `shape` - The shape of the tensor.
+///
+/// # Errors
+///
+/// If the number of elements overflows `usize`, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::checked_numel;
+///
+/// assert_eq!(checked_numel(&[2, 3, 4]).unwrap(), 24);
+/// assert!(checked_numel(&[usize::MAX, 2]).is_err());
+/// ```
+pub fn checked_numel(shape: &[usize]) -> Result<usize, TensorError> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| TensorError::ShapeOverflow(shape.to_vec()))
 }
 
 /// Compute the strides from the shape of a tensor.
@@ -54,6 +99,78 @@ pub(crate) fn get_strides_from_shape<const N: usize>(shape: [usize; N]) -> [usiz
     strides
 }
 
+/// Compute the shape resulting from broadcasting two tensor shapes.
+///
+/// Two dimensions are compatible when they are equal, or when one of them is 1, like in NumPy.
+/// Unlike NumPy, both shapes must have the same rank: the shape with fewer dimensions is not
+/// extended with leading dimensions of size 1, which must be added explicitly, e.g. with
+/// `reshape`.
+///
+/// # Arguments
+///
+/// * `lhs` - The shape of the first tensor.
+/// * `rhs` - The shape of the second tensor.
+///
+/// # Returns
+///
+/// The broadcast shape.
+///
+/// # Errors
+///
+/// If the shapes are not compatible, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::tensor::broadcast_shapes;
+///
+/// assert_eq!(broadcast_shapes([4, 5, 3], [1, 1, 3]).unwrap(), [4, 5, 3]);
+/// assert_eq!(broadcast_shapes([4, 1], [1, 2]).unwrap(), [4, 2]);
+/// assert!(broadcast_shapes([4, 3], [2, 3]).is_err());
+/// ```
+pub fn broadcast_shapes<const N: usize>(
+    lhs: [usize; N],
+    rhs: [usize; N],
+) -> Result<[usize; N], TensorError> {
+    let mut shape = [0; N];
+    for (i, (&l, &r)) in lhs.iter().zip(rhs.iter()).enumerate() {
+        shape[i] = match (l, r) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            _ => return Err(TensorError::BroadcastError(lhs.to_vec(), rhs.to_vec(), i)),
+        };
+    }
+    Ok(shape)
+}
+
+/// Compute the strides to iterate a tensor as if it had the broadcast shape.
+///
+/// The dimensions that are broadcast get a stride of zero so that the same element is repeated.
+///
+/// # Arguments
+///
+/// * `shape` - The shape of the tensor.
+/// * `strides` - The strides of the tensor.
+/// * `broadcast_shape` - The shape to broadcast to.
+///
+/// # Returns
+///
+/// * `strides` - The broadcast strides of the tensor.
+pub(crate) fn get_broadcast_strides<const N: usize>(
+    shape: [usize; N],
+    strides: [usize; N],
+    broadcast_shape: [usize; N],
+) -> [usize; N] {
+    let mut out = strides;
+    for i in 0..N {
+        if shape[i] == 1 && broadcast_shape[i] != 1 {
+            out[i] = 0;
+        }
+    }
+    out
+}
+
 /// A data structure to represent a multi-dimensional tensor.
 ///
 /// NOTE: Internally, the data is stored as an `arrow::ScalarBuffer` which represents a contiguous memory
@@ -238,6 +355,101 @@ where
         })
     }
 
+    /// Creates a new `Tensor` wrapping a buffer owned by a foreign owner without copying.
+    ///
+    /// The `release` callback is called when the tensor is dropped, see
+    /// [`TensorStorage::from_foreign`].
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - An array containing the shape of the tensor.
+    /// * `data` - The pointer to the first element of the buffer.
+    /// * `alloc` - The allocator used to allocate the clones of the tensor.
+    /// * `release` - The callback releasing the buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the number of elements of the shape overflows, an error is returned and `release` is
+    /// called.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be aligned for `T` and valid for reads and writes of as many elements
+    /// as the shape holds until `release` is called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    /// use std::ptr::NonNull;
+    ///
+    /// let data = Box::leak(vec![1u8, 2, 3, 4].into_boxed_slice());
+    /// let ptr = NonNull::new(data.as_mut_ptr()).unwrap();
+    /// let (addr, len) = (ptr.as_ptr() as usize, data.len());
+    ///
+    /// let t = unsafe {
+    ///     Tensor::<u8, 2, _>::from_foreign([2, 2], ptr, CpuAllocator, move || {
+    ///         drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(addr as *mut u8, len)))
+    ///     })
+    /// }
+    /// .unwrap();
+    /// assert_eq!(t.as_slice(), [1, 2, 3, 4]);
+    /// ```
+    pub unsafe fn from_foreign<F>(
+        shape: [usize; N],
+        data: std::ptr::NonNull<T>,
+        alloc: A,
+        release: F,
+    ) -> Result<Self, TensorError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let numel = match checked_numel(&shape) {
+            Ok(numel) => numel,
+            Err(e) => {
+                release();
+                return Err(e);
+            }
+        };
+        let storage = TensorStorage::from_foreign(data, numel, alloc, release)?;
+        let strides = get_strides_from_shape(shape);
+        Ok(Self {
+            storage,
+            shape,
+            strides,
+        })
+    }
+
+    /// Creates a new `Tensor` backed by a memory-mapped file without copying.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - An array containing the shape of the tensor.
+    /// * `mmap` - The memory map, kept alive by the tensor.
+    /// * `offset` - The offset in bytes of the first element in the mapping.
+    /// * `alloc` - The allocator used to allocate the clones of the tensor.
+    ///
+    /// # Errors
+    ///
+    /// If the mapping is too small for the shape or the data is not aligned for `T`, an error
+    /// is returned.
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap(
+        shape: [usize; N],
+        mmap: memmap2::MmapMut,
+        offset: usize,
+        alloc: A,
+    ) -> Result<Self, TensorError> {
+        let numel = checked_numel(&shape)?;
+        let storage = TensorStorage::from_mmap(mmap, offset, numel, alloc)?;
+        let strides = get_strides_from_shape(shape);
+        Ok(Self {
+            storage,
+            shape,
+            strides,
+        })
+    }
+
     /// Creates a new `Tensor` with the given shape and a default value.
     /// Creates a new `Tensor` with the given shape and a default value.
     ///
@@ -269,8 +481,7 @@ where
         T: Clone,
     {
         let numel = shape.iter().product::<usize>();
-        let data = vec![value; numel];
-        let storage = TensorStorage::from_vec(data, alloc);
+        let storage = TensorStorage::from_fn(numel, alloc, |_| value.clone());
         let strides = get_strides_from_shape(shape);
         Self {
             storage,
@@ -308,18 +519,15 @@ where
         F: Fn([usize; N]) -> T,
     {
         let numel = shape.iter().product::<usize>();
-        let data: Vec<T> = (0..numel)
-            .map(|i| {
-                let mut index = [0; N];
-                let mut j = i;
-                for k in (0..N).rev() {
-                    index[k] = j % shape[k];
-                    j /= shape[k];
-                }
-                f(index)
-            })
-            .collect();
-        let storage = TensorStorage::from_vec(data, alloc);
+        let storage = TensorStorage::from_fn(numel, alloc, |i| {
+            let mut index = [0; N];
+            let mut j = i;
+            for k in (0..N).rev() {
+                index[k] = j % shape[k];
+                j /= shape[k];
+            }
+            f(index)
+        });
         let strides = get_strides_from_shape(shape);
         Self {
             storage,
@@ -511,12 +719,15 @@ where
     pub fn reshape<const M: usize>(
         &self,
         shape: [usize; M],
-    ) -> Result<TensorView<T, M, A>, TensorError> {
+    ) -> Result<TensorView<'_, T, M, A>, TensorError> {
         let numel = shape.iter().product::<usize>();
-        if numel != self.storage.len() {
+        if numel != self.numel() {
             return Err(TensorError::DimensionMismatch(format!(
                 "Cannot reshape tensor of shape {:?} with {} elements to shape {:?} with {} elements",
-                self.shape, self.storage.len(), shape, numel
+                self.shape,
+                self.numel(),
+                shape,
+                numel
             )));
         }
 
@@ -526,6 +737,7 @@ where
             storage: &self.storage,
             shape,
             strides,
+            offset: 0,
         })
     }
 
@@ -541,7 +753,7 @@ where
     /// # Returns
     ///
     /// A view of the tensor with the dimensions permuted.
-    pub fn permute_axes(&self, axes: [usize; N]) -> TensorView<T, N, A> {
+    pub fn permute_axes(&self, axes: [usize; N]) -> TensorView<'_, T, N, A> {
         let mut new_shape = [0; N];
         let mut new_strides = [0; N];
         for (i, &axis) in axes.iter().enumerate() {
@@ -553,6 +765,7 @@ where
             storage: &self.storage,
             shape: new_shape,
             strides: new_strides,
+            offset: 0,
         }
     }
 
@@ -563,11 +776,28 @@ where
     /// # Returns
     ///
     /// A `TensorView` instance.
-    pub fn view(&self) -> TensorView<T, N, A> {
+    pub fn view(&self) -> TensorView<'_, T, N, A> {
         TensorView {
             storage: &self.storage,
             shape: self.shape,
             strides: self.strides,
+            offset: 0,
+        }
+    }
+
+    /// Return a mutable view of the tensor.
+    ///
+    /// The view can be sliced to modify a region of the tensor in place.
+    ///
+    /// # Returns
+    ///
+    /// A `TensorViewMut` instance.
+    pub fn view_mut(&mut self) -> TensorViewMut<'_, T, N, A> {
+        TensorViewMut {
+            storage: &mut self.storage,
+            shape: self.shape,
+            strides: self.strides,
+            offset: 0,
         }
     }
 
@@ -612,8 +842,9 @@ where
     where
         F: Fn(&T) -> U,
     {
-        let data: Vec<U> = self.as_slice().iter().map(f).collect();
-        let storage = TensorStorage::from_vec(data, self.storage.alloc().clone());
+        let data = self.as_slice();
+        let storage =
+            TensorStorage::from_fn(data.len(), self.storage.alloc().clone(), |i| f(&data[i]));
 
         Tensor {
             storage,
@@ -701,6 +932,9 @@ where
 
     /// Perform an element-wise operation on two tensors.
     ///
+    /// The shapes of the two tensors are broadcast with [`broadcast_shapes`], i.e. the tensors
+    /// have the same rank and each dimension must either match or be 1 in one of the tensors.
+    ///
     /// # Arguments
     ///
     /// * `other` - The other tensor to perform the operation with.
@@ -708,7 +942,11 @@ where
     ///
     /// # Returns
     ///
-    /// A new `Tensor` instance.
+    /// A new `Tensor` instance with the broadcast shape.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes of the tensors cannot be broadcast together, an error is returned.
     ///
     /// # Example
     ///
@@ -732,40 +970,73 @@ where
     ///
     /// let t6 = t1.element_wise_op(&t2, |a, b| *a / *b).unwrap();
     /// assert_eq!(t6.as_slice(), vec![1, 1, 1, 1]);
+    ///
+    /// // broadcast a per-column vector against a matrix
+    /// let m = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator).unwrap();
+    /// let v = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([1, 2], vec![10, 20], CpuAllocator).unwrap();
+    /// let t7 = m.element_wise_op(&v, |a, b| *a + *b).unwrap();
+    /// assert_eq!(t7.as_slice(), vec![11, 22, 13, 24]);
     /// ```
-    pub fn element_wise_op<F>(
+    pub fn element_wise_op<B, F>(
         &self,
-        other: &Tensor<T, N, CpuAllocator>,
+        other: &Tensor<T, N, B>,
         op: F,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorError>
+    ) -> Result<Tensor<T, N, A>, TensorError>
     where
+        B: TensorAllocator + 'static,
         F: Fn(&T, &T) -> T,
     {
-        if self.shape != other.shape {
-            return Err(TensorError::DimensionMismatch(format!(
-                "Shapes {:?} and {:?} are not compatible for element-wise operations",
-                self.shape, other.shape
-            )));
-        }
+        let shape = broadcast_shapes(self.shape, other.shape)?;
+
+        let data: Vec<T> = if self.shape == other.shape {
+            self.as_slice()
+                .iter()
+                .zip(other.as_slice().iter())
+                .map(|(a, b)| op(a, b))
+                .collect()
+        } else {
+            let lhs_strides = get_broadcast_strides(self.shape, self.strides, shape);
+            let rhs_strides = get_broadcast_strides(other.shape, other.strides, shape);
+            let (lhs, rhs) = (self.as_slice(), other.as_slice());
+
+            let numel = shape.iter().product::<usize>();
+            let mut data = Vec::with_capacity(numel);
+            let mut index = [0; N];
+            let (mut lhs_offset, mut rhs_offset) = (0, 0);
+
+            for _ in 0..numel {
+                data.push(op(&lhs[lhs_offset], &rhs[rhs_offset]));
+
+                // advance the index in row-major order and update the offsets
+                for dim in (0..N).rev() {
+                    index[dim] += 1;
+                    lhs_offset += lhs_strides[dim];
+                    rhs_offset += rhs_strides[dim];
+                    if index[dim] < shape[dim] {
+                        break;
+                    }
+                    lhs_offset -= lhs_strides[dim] * shape[dim];
+                    rhs_offset -= rhs_strides[dim] * shape[dim];
+                    index[dim] = 0;
+                }
+            }
 
-        let data = self
-            .as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| op(a, b))
-            .collect();
+            data
+        };
 
-        let storage = TensorStorage::from_vec(data, CpuAllocator);
+        let storage = TensorStorage::from_vec(data, self.storage.alloc().clone());
 
         Ok(Tensor {
             storage,
-            shape: self.shape,
-            strides: self.strides,
+            shape,
+            strides: get_strides_from_shape(shape),
         })
     }
 
     /// Perform an element-wise addition on two tensors.
     ///
+    /// The shapes of the two tensors are broadcast with [`broadcast_shapes`].
+    ///
     /// # Arguments
     ///
     /// * `other` - The other tensor to add.
@@ -774,6 +1045,11 @@ where
     ///
     /// A new `Tensor` instance.
     ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors cannot be broadcast together, see
+    /// [`Tensor::element_wise_op`] for a fallible version.
+    ///
     /// # Example
     ///
     /// ```
@@ -788,8 +1064,9 @@ where
     /// let t3 = t1.add(&t2);
     /// assert_eq!(t3.as_slice(), vec![2, 4, 6, 8]);
     /// ```
-    pub fn add(&self, other: &Tensor<T, N, CpuAllocator>) -> Tensor<T, N, CpuAllocator>
+    pub fn add<B>(&self, other: &Tensor<T, N, B>) -> Tensor<T, N, A>
     where
+        B: TensorAllocator + 'static,
         T: std::ops::Add<Output = T> + Clone,
     {
         self.element_wise_op(other, |a, b| a.clone() + b.clone())
@@ -798,6 +1075,8 @@ where
 
     /// Perform an element-wise subtraction on two tensors.
     ///
+    /// The shapes of the two tensors are broadcast with [`broadcast_shapes`].
+    ///
     /// # Arguments
     ///
     /// * `other` - The other tensor to subtract.
@@ -806,6 +1085,11 @@ where
     ///
     /// A new `Tensor` instance.
     ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors cannot be broadcast together, see
+    /// [`Tensor::element_wise_op`] for a fallible version.
+    ///
     /// # Example
     ///
     /// ```
@@ -820,8 +1104,9 @@ where
     /// let t3 = t1.sub(&t2);
     /// assert_eq!(t3.as_slice(), vec![0, 0, 0, 0]);
     /// ```
-    pub fn sub(&self, other: &Tensor<T, N, CpuAllocator>) -> Tensor<T, N, CpuAllocator>
+    pub fn sub<B>(&self, other: &Tensor<T, N, B>) -> Tensor<T, N, A>
     where
+        B: TensorAllocator + 'static,
         T: std::ops::Sub<Output = T> + Clone,
     {
         self.element_wise_op(other, |a, b| a.clone() - b.clone())
@@ -830,6 +1115,8 @@ where
 
     /// Perform an element-wise multiplication on two tensors.
     ///
+    /// The shapes of the two tensors are broadcast with [`broadcast_shapes`].
+    ///
     /// # Arguments
     ///
     /// * `other` - The other tensor to multiply.
@@ -838,6 +1125,11 @@ where
     ///
     /// A new `Tensor` instance.
     ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors cannot be broadcast together, see
+    /// [`Tensor::element_wise_op`] for a fallible version.
+    ///
     /// # Example
     ///
     /// ```
@@ -852,8 +1144,9 @@ where
     /// let t3 = t1.mul(&t2);
     /// assert_eq!(t3.as_slice(), vec![1, 4, 9, 16]);
     /// ```
-    pub fn mul(&self, other: &Tensor<T, N, CpuAllocator>) -> Tensor<T, N, CpuAllocator>
+    pub fn mul<B>(&self, other: &Tensor<T, N, B>) -> Tensor<T, N, A>
     where
+        B: TensorAllocator + 'static,
         T: std::ops::Mul<Output = T> + Clone,
     {
         self.element_wise_op(other, |a, b| a.clone() * b.clone())
@@ -862,6 +1155,8 @@ where
 
     /// Perform an element-wise division on two tensors.
     ///
+    /// The shapes of the two tensors are broadcast with [`broadcast_shapes`].
+    ///
     /// # Arguments
     ///
     /// * `other` - The other tensor to divide.
@@ -870,6 +1165,11 @@ where
     ///
     /// A new `Tensor` instance.
     ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors cannot be broadcast together, see
+    /// [`Tensor::element_wise_op`] for a fallible version.
+    ///
     /// # Example
     ///
     /// ```
@@ -884,8 +1184,9 @@ where
     /// let t3 = t1.div(&t2);
     /// assert_eq!(t3.as_slice(), vec![1, 1, 1, 1]);
     /// ```
-    pub fn div(&self, other: &Tensor<T, N, CpuAllocator>) -> Tensor<T, N, CpuAllocator>
+    pub fn div<B>(&self, other: &Tensor<T, N, B>) -> Tensor<T, N, A>
     where
+        B: TensorAllocator + 'static,
         T: std::ops::Div<Output = T> + Clone,
     {
         self.element_wise_op(other, |a, b| a.clone() / b.clone())
@@ -893,6 +1194,76 @@ where
     }
 }
 
+/// Implement the binary arithmetic operators for tensors and scalars.
+///
+/// The operators between two tensors broadcast their shapes like the methods of the same name.
+macro_rules! impl_tensor_binary_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T, const N: usize, A, B> std::ops::$trait<&Tensor<T, N, B>> for &Tensor<T, N, A>
+        where
+            T: std::ops::$trait<Output = T> + Clone,
+            A: TensorAllocator + 'static,
+            B: TensorAllocator + 'static,
+        {
+            type Output = Tensor<T, N, A>;
+
+            /// # Panics
+            ///
+            /// If the shapes of the tensors cannot be broadcast together, see
+            /// [`Tensor::element_wise_op`] for a fallible version.
+            fn $method(self, rhs: &Tensor<T, N, B>) -> Self::Output {
+                Tensor::$method(self, rhs)
+            }
+        }
+
+        impl<T, const N: usize, A, B> std::ops::$trait<Tensor<T, N, B>> for Tensor<T, N, A>
+        where
+            T: std::ops::$trait<Output = T> + Clone,
+            A: TensorAllocator + 'static,
+            B: TensorAllocator + 'static,
+        {
+            type Output = Tensor<T, N, A>;
+
+            /// # Panics
+            ///
+            /// If the shapes of the tensors cannot be broadcast together, see
+            /// [`Tensor::element_wise_op`] for a fallible version.
+            fn $method(self, rhs: Tensor<T, N, B>) -> Self::Output {
+                Tensor::$method(&self, &rhs)
+            }
+        }
+
+        impl<T, const N: usize, A> std::ops::$trait<T> for &Tensor<T, N, A>
+        where
+            T: std::ops::$trait<Output = T> + Clone,
+            A: TensorAllocator + 'static,
+        {
+            type Output = Tensor<T, N, A>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                self.map(|x| x.clone() $op rhs.clone())
+            }
+        }
+
+        impl<T, const N: usize, A> std::ops::$trait<T> for Tensor<T, N, A>
+        where
+            T: std::ops::$trait<Output = T> + Clone,
+            A: TensorAllocator + 'static,
+        {
+            type Output = Tensor<T, N, A>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                &self $op rhs
+            }
+        }
+    };
+}
+
+impl_tensor_binary_op!(Add, add, +);
+impl_tensor_binary_op!(Sub, sub, -);
+impl_tensor_binary_op!(Mul, mul, *);
+impl_tensor_binary_op!(Div, div, /);
+
 impl<T, const N: usize, A> Clone for Tensor<T, N, A>
 where
     T: Clone,
@@ -1173,6 +1544,78 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn add_broadcast_channels() -> Result<(), TensorError> {
+        let data1: Vec<f32> = vec![
+            1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+        ];
+        let t1 = Tensor::<f32, 3, _>::from_shape_vec([2, 2, 3], data1, CpuAllocator)?;
+        let data2: Vec<f32> = vec![10.0, 20.0, 30.0];
+        let t2 = Tensor::<f32, 3, _>::from_shape_vec([1, 1, 3], data2, CpuAllocator)?;
+        let t3 = t1.add(&t2);
+        assert_eq!(t3.shape, [2, 2, 3]);
+        assert_eq!(t3.strides, [6, 3, 1]);
+        assert_eq!(
+            t3.as_slice(),
+            vec![11.0, 22.0, 33.0, 14.0, 25.0, 36.0, 17.0, 28.0, 39.0, 20.0, 31.0, 42.0]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sub_broadcast_row_column() -> Result<(), TensorError> {
+        let t1 = Tensor::<i32, 2, _>::from_shape_vec([3, 1], vec![10, 20, 30], CpuAllocator)?;
+        let t2 = Tensor::<i32, 2, _>::from_shape_vec([1, 2], vec![1, 2], CpuAllocator)?;
+        let t3 = t1.sub(&t2);
+        assert_eq!(t3.shape, [3, 2]);
+        assert_eq!(t3.as_slice(), vec![9, 8, 19, 18, 29, 28]);
+        Ok(())
+    }
+
+    #[test]
+    fn mul_broadcast_scalar_tensor() -> Result<(), TensorError> {
+        let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator)?;
+        let t2 = Tensor::<u8, 2, _>::from_shape_vec([1, 1], vec![3], CpuAllocator)?;
+        assert_eq!(t1.mul(&t2).as_slice(), vec![3, 6, 9, 12]);
+        assert_eq!(t2.mul(&t1).as_slice(), vec![3, 6, 9, 12]);
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_mismatch() -> Result<(), TensorError> {
+        let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![0; 6], CpuAllocator)?;
+        let t2 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![0; 4], CpuAllocator)?;
+        let res = t1.element_wise_op(&t2, |a, b| *a + *b);
+        assert_eq!(
+            res.err(),
+            Some(TensorError::BroadcastError(vec![2, 3], vec![2, 2], 1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn std_ops() -> Result<(), TensorError> {
+        let t1 =
+            Tensor::<f32, 2, _>::from_shape_vec([2, 2], vec![1.0, 2.0, 3.0, 4.0], CpuAllocator)?;
+        let t2 = Tensor::<f32, 2, _>::from_shape_vec([1, 2], vec![2.0, 4.0], CpuAllocator)?;
+        assert_eq!((&t1 + &t2).as_slice(), vec![3.0, 6.0, 5.0, 8.0]);
+        assert_eq!((&t1 - &t2).as_slice(), vec![-1.0, -2.0, 1.0, 0.0]);
+        assert_eq!((&t1 * &t2).as_slice(), vec![2.0, 8.0, 6.0, 16.0]);
+        assert_eq!((&t1 / &t2).as_slice(), vec![0.5, 0.5, 1.5, 1.0]);
+        assert_eq!((&t1 * 2.0).as_slice(), vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((t1.clone() + t2).as_slice(), vec![3.0, 6.0, 5.0, 8.0]);
+        assert_eq!((t1 - 1.0).as_slice(), vec![0.0, 1.0, 2.0, 3.0]);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Tensor dimension mismatch")]
+    fn std_ops_broadcast_mismatch() {
+        let t1 = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![0; 6], CpuAllocator).unwrap();
+        let t2 = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![0; 4], CpuAllocator).unwrap();
+        let _ = &t1 + &t2;
+    }
+
     #[test]
     fn reshape_1d() -> Result<(), TensorError> {
         let data: Vec<u8> = vec![1, 2, 3, 4];
@@ -1202,6 +1645,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn reshape_f32() -> Result<(), TensorError> {
+        let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let t = Tensor::<f32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+        let view = t.reshape([3, 2])?;
+        assert_eq!(view.shape, [3, 2]);
+        assert_eq!(view.numel(), 6);
+        assert!(t.reshape([4, 2]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn reshape_get_1d() -> Result<(), TensorError> {
         let data: Vec<u8> = vec![1, 2, 3, 4];
@@ -1,8 +1,196 @@
+use std::ops::Range;
+
 use crate::{
     get_strides_from_shape, storage::TensorStorage, CpuAllocator, Tensor, TensorAllocator,
+    TensorError,
 };
 
+/// An iterator over the storage offsets of a strided tensor view in row-major order.
+#[derive(Clone)]
+pub struct StridedOffsets<const N: usize> {
+    shape: [usize; N],
+    strides: [usize; N],
+    index: [usize; N],
+    offset: usize,
+    remaining: usize,
+}
+
+impl<const N: usize> StridedOffsets<N> {
+    /// Create a new iterator over the offsets of a view with the given layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the view.
+    /// * `strides` - The strides of the view.
+    /// * `offset` - The offset of the first element of the view in the storage.
+    pub fn new(shape: [usize; N], strides: [usize; N], offset: usize) -> Self {
+        Self {
+            shape,
+            strides,
+            index: [0; N],
+            offset,
+            remaining: shape.iter().product(),
+        }
+    }
+}
+
+impl<const N: usize> Iterator for StridedOffsets<N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let current = self.offset;
+        self.remaining -= 1;
+
+        // advance the index in row-major order and update the offset
+        for dim in (0..N).rev() {
+            self.index[dim] += 1;
+            self.offset += self.strides[dim];
+            if self.index[dim] < self.shape[dim] {
+                break;
+            }
+            self.offset -= self.strides[dim] * self.shape[dim];
+            self.index[dim] = 0;
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for StridedOffsets<N> {}
+
+/// Check that the dimension is valid for a tensor of rank `N`.
+fn check_dim<const N: usize>(dim: usize) -> Result<(), TensorError> {
+    if dim >= N {
+        return Err(TensorError::DimensionMismatch(format!(
+            "Dimension {dim} is out of range for a tensor of rank {N}"
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the rank `M` of the output of a rank changing operation is valid.
+fn check_rank<const N: usize, const M: usize>(expected: usize) -> Result<(), TensorError> {
+    if M != expected {
+        return Err(TensorError::DimensionMismatch(format!(
+            "Cannot convert a view of rank {N} to rank {M}, expected rank {expected}"
+        )));
+    }
+    Ok(())
+}
+
+/// Compute the range of indices kept by a narrow.
+fn narrow_range(start: usize, len: usize) -> Result<Range<usize>, TensorError> {
+    let end = start.checked_add(len).ok_or_else(|| {
+        TensorError::DimensionMismatch(format!(
+            "Narrow of {len} indices from {start} overflows the index range"
+        ))
+    })?;
+    Ok(start..end)
+}
+
+/// Compute the layout of a slice along a dimension.
+///
+/// # Returns
+///
+/// The new shape, strides and offset of the sliced view.
+fn slice_layout<const N: usize>(
+    shape: [usize; N],
+    strides: [usize; N],
+    offset: usize,
+    dim: usize,
+    range: Range<usize>,
+    step: usize,
+) -> Result<([usize; N], [usize; N], usize), TensorError> {
+    check_dim::<N>(dim)?;
+    if step == 0 {
+        return Err(TensorError::UnsupportedOperation(
+            "The slice step must be greater than zero".to_string(),
+        ));
+    }
+    if range.start > range.end || range.end > shape[dim] {
+        return Err(TensorError::DimensionMismatch(format!(
+            "Slice range {range:?} is out of bounds for dimension {dim} of size {}",
+            shape[dim]
+        )));
+    }
+
+    let mut new_shape = shape;
+    let mut new_strides = strides;
+    new_shape[dim] = (range.end - range.start).div_ceil(step);
+    new_strides[dim] = strides[dim] * step;
+
+    Ok((new_shape, new_strides, offset + range.start * strides[dim]))
+}
+
+/// Compute the layout after removing a dimension.
+fn remove_dim<const N: usize, const M: usize>(
+    shape: [usize; N],
+    strides: [usize; N],
+    dim: usize,
+) -> ([usize; M], [usize; M]) {
+    let mut new_shape = [0; M];
+    let mut new_strides = [0; M];
+    for (j, i) in (0..N).filter(|&i| i != dim).enumerate() {
+        new_shape[j] = shape[i];
+        new_strides[j] = strides[i];
+    }
+    (new_shape, new_strides)
+}
+
+/// Check if a layout is contiguous in row-major order.
+fn is_contiguous_layout<const N: usize>(shape: [usize; N], strides: [usize; N]) -> bool {
+    let mut expected = 1;
+    for i in (0..N).rev() {
+        // dimensions of size one can have any stride
+        if shape[i] == 1 {
+            continue;
+        }
+        if strides[i] != expected {
+            return false;
+        }
+        expected *= shape[i];
+    }
+    true
+}
+
+/// Compute the number of storage elements spanned by a view.
+fn span_len<const N: usize>(shape: [usize; N], strides: [usize; N]) -> usize {
+    if shape.contains(&0) {
+        return 0;
+    }
+    shape
+        .iter()
+        .zip(strides.iter())
+        .fold(1, |acc, (&s, &st)| acc + (s - 1) * st)
+}
+
 /// A view into a tensor.
+///
+/// The view does not own the data, it references the storage of another tensor and describes
+/// how to traverse it with a shape, strides and an offset. Slicing, narrowing and transposing
+/// a view is zero-copy.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+///
+/// let data: Vec<u8> = (0..12).collect();
+/// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([3, 4], data, CpuAllocator).unwrap();
+///
+/// // take the columns 1..4 with step 2 of the last two rows
+/// let view = t.view().narrow(0, 1, 2).unwrap().slice(1, 1..4, 2).unwrap();
+/// assert_eq!(view.shape, [2, 2]);
+/// assert!(!view.is_contiguous());
+/// assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![5, 7, 9, 11]);
+/// ```
 pub struct TensorView<'a, T, const N: usize, A: TensorAllocator> {
     /// Reference to the storage held by the another tensor.
     pub storage: &'a TensorStorage<T, A>,
@@ -12,25 +200,46 @@ pub struct TensorView<'a, T, const N: usize, A: TensorAllocator> {
 
     /// The strides of the tensor.
     pub strides: [usize; N],
+
+    /// The offset of the first element of the view in the storage.
+    pub offset: usize,
 }
 
-impl<T, const N: usize, A: TensorAllocator + 'static> TensorView<'_, T, N, A> {
-    /// Returns the data slice of the tensor.
+impl<'a, T, const N: usize, A: TensorAllocator + 'static> TensorView<'a, T, N, A> {
+    /// Returns the storage region spanned by the view.
+    ///
+    /// For contiguous views this is exactly the data of the view in row-major order.
+    /// For strided views use [`TensorView::iter`] to traverse the elements of the view.
     #[inline]
-    pub fn as_slice(&self) -> &[T] {
-        self.storage.as_slice()
+    pub fn as_slice(&self) -> &'a [T] {
+        let len = span_len(self.shape, self.strides);
+        &self.storage.as_slice()[self.offset..self.offset + len]
     }
 
-    /// Returns the data pointer of the tensor.
+    /// Returns the pointer to the first element of the view.
     #[inline]
     pub fn as_ptr(&self) -> *const T {
-        self.storage.as_ptr()
+        self.as_slice().as_ptr()
     }
 
-    /// Returns the length of the tensor.
+    /// Returns the number of elements in the view.
     #[inline]
     pub fn numel(&self) -> usize {
-        self.storage.len() / std::mem::size_of::<T>()
+        self.shape.iter().product()
+    }
+
+    /// Returns true if the elements of the view are laid out contiguously in row-major order.
+    pub fn is_contiguous(&self) -> bool {
+        is_contiguous_layout(self.shape, self.strides)
+    }
+
+    /// Get the storage offset of the element at the given index without checking the bounds.
+    #[inline]
+    fn offset_unchecked(&self, index: [usize; N]) -> usize {
+        index
+            .iter()
+            .zip(self.strides.iter())
+            .fold(self.offset, |acc, (i, s)| acc + i * s)
     }
 
     /// Get the element at the given index.
@@ -42,14 +251,269 @@ impl<T, const N: usize, A: TensorAllocator + 'static> TensorView<'_, T, N, A> {
     /// # Safety
     ///
     /// The caller must ensure that the index is within the bounds of the tensor.
-    pub fn get_unchecked(&self, index: [usize; N]) -> &T {
-        let offset = index
-            .iter()
-            .zip(self.strides.iter())
-            .fold(0, |acc, (i, s)| acc + i * s);
+    pub fn get_unchecked(&self, index: [usize; N]) -> &'a T {
+        let offset = self.offset_unchecked(index);
         unsafe { self.storage.as_slice().get_unchecked(offset) }
     }
 
+    /// Get the element at the given index, checking if the index is out of bounds.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the element at the given index or `None` if out of bounds.
+    pub fn get(&self, index: [usize; N]) -> Option<&'a T> {
+        if index.iter().zip(self.shape.iter()).any(|(i, s)| i >= s) {
+            return None;
+        }
+        self.storage.as_slice().get(self.offset_unchecked(index))
+    }
+
+    /// Returns an iterator over the elements of the view in row-major order.
+    ///
+    /// The iterator honours the strides and offset of the view.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a T> + 'a {
+        let data = self.storage.as_slice();
+        StridedOffsets::new(self.shape, self.strides, self.offset).map(move |i| &data[i])
+    }
+
+    /// Slice the view along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to slice.
+    /// * `range` - The range of indices to keep.
+    /// * `step` - The step between the kept indices. Must be greater than zero.
+    ///
+    /// # Returns
+    ///
+    /// A new view sharing the same storage.
+    ///
+    /// # Errors
+    ///
+    /// If the dimension or the range are out of bounds, or the step is zero.
+    pub fn slice(
+        &self,
+        dim: usize,
+        range: Range<usize>,
+        step: usize,
+    ) -> Result<TensorView<'a, T, N, A>, TensorError> {
+        let (shape, strides, offset) =
+            slice_layout(self.shape, self.strides, self.offset, dim, range, step)?;
+        Ok(TensorView {
+            storage: self.storage,
+            shape,
+            strides,
+            offset,
+        })
+    }
+
+    /// Narrow the view along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to narrow.
+    /// * `start` - The first index to keep.
+    /// * `len` - The number of indices to keep.
+    ///
+    /// # Returns
+    ///
+    /// A new view sharing the same storage.
+    pub fn narrow(
+        &self,
+        dim: usize,
+        start: usize,
+        len: usize,
+    ) -> Result<TensorView<'a, T, N, A>, TensorError> {
+        self.slice(dim, narrow_range(start, len)?, 1)
+    }
+
+    /// Select a single index along a dimension, removing that dimension.
+    ///
+    /// The rank of the output view `M` must be `N - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to select from.
+    /// * `index` - The index to select.
+    ///
+    /// # Returns
+    ///
+    /// A new view of rank `N - 1` sharing the same storage.
+    pub fn select<const M: usize>(
+        &self,
+        dim: usize,
+        index: usize,
+    ) -> Result<TensorView<'a, T, M, A>, TensorError> {
+        check_rank::<N, M>(N.saturating_sub(1))?;
+        check_dim::<N>(dim)?;
+        if index >= self.shape[dim] {
+            return Err(TensorError::IndexOutOfBounds(index));
+        }
+        let (shape, strides) = remove_dim(self.shape, self.strides, dim);
+        Ok(TensorView {
+            storage: self.storage,
+            shape,
+            strides,
+            offset: self.offset + index * self.strides[dim],
+        })
+    }
+
+    /// Permute the dimensions of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - The new order of the dimensions.
+    ///
+    /// # Returns
+    ///
+    /// A new view sharing the same storage.
+    pub fn permute(&self, axes: [usize; N]) -> Result<TensorView<'a, T, N, A>, TensorError> {
+        let mut seen = [false; N];
+        let mut shape = [0; N];
+        let mut strides = [0; N];
+        for (i, &axis) in axes.iter().enumerate() {
+            check_dim::<N>(axis)?;
+            if seen[axis] {
+                return Err(TensorError::DimensionMismatch(format!(
+                    "Invalid permutation {axes:?}: dimension {axis} is repeated"
+                )));
+            }
+            seen[axis] = true;
+            shape[i] = self.shape[axis];
+            strides[i] = self.strides[axis];
+        }
+        Ok(TensorView {
+            storage: self.storage,
+            shape,
+            strides,
+            offset: self.offset,
+        })
+    }
+
+    /// Swap two dimensions of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim0` - The first dimension.
+    /// * `dim1` - The second dimension.
+    ///
+    /// # Returns
+    ///
+    /// A new view sharing the same storage.
+    pub fn transpose(
+        &self,
+        dim0: usize,
+        dim1: usize,
+    ) -> Result<TensorView<'a, T, N, A>, TensorError> {
+        check_dim::<N>(dim0)?;
+        check_dim::<N>(dim1)?;
+        let mut axes: [usize; N] = std::array::from_fn(|i| i);
+        axes.swap(dim0, dim1);
+        self.permute(axes)
+    }
+
+    /// Remove a dimension of size one.
+    ///
+    /// The rank of the output view `M` must be `N - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to remove.
+    ///
+    /// # Returns
+    ///
+    /// A new view of rank `N - 1` sharing the same storage.
+    pub fn squeeze<const M: usize>(
+        &self,
+        dim: usize,
+    ) -> Result<TensorView<'a, T, M, A>, TensorError> {
+        check_dim::<N>(dim)?;
+        if self.shape[dim] != 1 {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot squeeze dimension {dim} of size {}",
+                self.shape[dim]
+            )));
+        }
+        self.select(dim, 0)
+    }
+
+    /// Insert a dimension of size one.
+    ///
+    /// The rank of the output view `M` must be `N + 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The position of the new dimension.
+    ///
+    /// # Returns
+    ///
+    /// A new view of rank `N + 1` sharing the same storage.
+    pub fn unsqueeze<const M: usize>(
+        &self,
+        dim: usize,
+    ) -> Result<TensorView<'a, T, M, A>, TensorError> {
+        check_rank::<N, M>(N + 1)?;
+        check_dim::<M>(dim)?;
+        let mut shape = [1; M];
+        let mut strides = [0; M];
+        for (j, i) in (0..M).filter(|&i| i != dim).enumerate() {
+            shape[i] = self.shape[j];
+            strides[i] = self.strides[j];
+        }
+        // the stride of a unit dimension is irrelevant, use the one of a contiguous layout
+        strides[dim] = if dim + 1 < M {
+            strides[dim + 1] * shape[dim + 1]
+        } else {
+            1
+        };
+        Ok(TensorView {
+            storage: self.storage,
+            shape,
+            strides,
+            offset: self.offset,
+        })
+    }
+
+    /// Reshape a contiguous view to a new shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The new shape of the view.
+    ///
+    /// # Returns
+    ///
+    /// A new view sharing the same storage.
+    ///
+    /// # Errors
+    ///
+    /// If the view is not contiguous or the number of elements does not match.
+    pub fn reshape<const M: usize>(
+        &self,
+        shape: [usize; M],
+    ) -> Result<TensorView<'a, T, M, A>, TensorError> {
+        if !self.is_contiguous() {
+            return Err(TensorError::UnsupportedOperation(
+                "Cannot reshape a non contiguous view".to_string(),
+            ));
+        }
+        let numel = shape.iter().product::<usize>();
+        if numel != self.numel() {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot reshape view of shape {:?} with {} elements to shape {:?} with {} elements",
+                self.shape,
+                self.numel(),
+                shape,
+                numel
+            )));
+        }
+        Ok(TensorView {
+            storage: self.storage,
+            shape,
+            strides: get_strides_from_shape(shape),
+            offset: self.offset,
+        })
+    }
+
     /// Convert the view an owned tensor with contiguous memory.
     ///
     /// # Returns
@@ -59,25 +523,7 @@ impl<T, const N: usize, A: TensorAllocator + 'static> TensorView<'_, T, N, A> {
     where
         T: Clone,
     {
-        let mut data = Vec::<T>::with_capacity(self.numel());
-        let mut index = [0; N];
-
-        loop {
-            let val = self.get_unchecked(index);
-            data.push(val.clone());
-
-            // Increment index
-            let mut i = N - 1;
-            while i > 0 && index[i] == self.shape[i] - 1 {
-                index[i] = 0;
-                i -= 1;
-            }
-            if i == 0 && index[0] == self.shape[0] - 1 {
-                break;
-            }
-            index[i] += 1;
-        }
-
+        let data = self.iter().cloned().collect::<Vec<_>>();
         let strides = get_strides_from_shape(self.shape);
 
         Tensor {
@@ -88,6 +534,212 @@ impl<T, const N: usize, A: TensorAllocator + 'static> TensorView<'_, T, N, A> {
     }
 }
 
+/// A mutable view into a tensor.
+///
+/// Like [`TensorView`] the view does not own the data, but it allows to modify the elements
+/// of the referenced storage in place, e.g. to process a region of interest of an image.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+///
+/// let mut t = Tensor::<u8, 2, CpuAllocator>::zeros([3, 3], CpuAllocator);
+///
+/// // fill the central column
+/// t.view_mut().slice(1, 1..2, 1).unwrap().fill(1);
+/// assert_eq!(t.as_slice(), vec![0, 1, 0, 0, 1, 0, 0, 1, 0]);
+/// ```
+pub struct TensorViewMut<'a, T, const N: usize, A: TensorAllocator> {
+    /// Mutable reference to the storage held by the another tensor.
+    pub storage: &'a mut TensorStorage<T, A>,
+
+    /// The shape of the tensor.
+    pub shape: [usize; N],
+
+    /// The strides of the tensor.
+    pub strides: [usize; N],
+
+    /// The offset of the first element of the view in the storage.
+    pub offset: usize,
+}
+
+impl<'a, T, const N: usize, A: TensorAllocator + 'static> TensorViewMut<'a, T, N, A> {
+    /// Returns an immutable view with the same layout.
+    pub fn as_view(&self) -> TensorView<'_, T, N, A> {
+        TensorView {
+            storage: self.storage,
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    #[inline]
+    pub fn numel(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Returns true if the elements of the view are laid out contiguously in row-major order.
+    pub fn is_contiguous(&self) -> bool {
+        is_contiguous_layout(self.shape, self.strides)
+    }
+
+    /// Returns the storage region spanned by the view as a mutable slice.
+    ///
+    /// For contiguous views this is exactly the data of the view in row-major order.
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        let len = span_len(self.shape, self.strides);
+        &mut self.storage.as_mut_slice()[self.offset..self.offset + len]
+    }
+
+    /// Get a mutable reference to the element at the given index.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the element at the given index or `None` if out of bounds.
+    pub fn get_mut(&mut self, index: [usize; N]) -> Option<&mut T> {
+        if index.iter().zip(self.shape.iter()).any(|(i, s)| i >= s) {
+            return None;
+        }
+        let offset = index
+            .iter()
+            .zip(self.strides.iter())
+            .fold(self.offset, |acc, (i, s)| acc + i * s);
+        self.storage.as_mut_slice().get_mut(offset)
+    }
+
+    /// Apply a function to each element of the view in row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to apply to each element.
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        let data = self.storage.as_mut_slice();
+        StridedOffsets::new(self.shape, self.strides, self.offset).for_each(|i| f(&mut data[i]));
+    }
+
+    /// Fill the view with a value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to fill the view with.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.for_each_mut(|x| *x = value.clone());
+    }
+
+    /// Copy the elements of another view with the same shape into this view.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The view to copy the elements from.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes of the views do not match.
+    pub fn copy_from<B>(&mut self, src: &TensorView<'_, T, N, B>) -> Result<(), TensorError>
+    where
+        T: Clone,
+        B: TensorAllocator + 'static,
+    {
+        if self.shape != src.shape {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot copy a view of shape {:?} into a view of shape {:?}",
+                src.shape, self.shape
+            )));
+        }
+        let mut values = src.iter();
+        self.for_each_mut(|x| {
+            if let Some(v) = values.next() {
+                *x = v.clone();
+            }
+        });
+        Ok(())
+    }
+
+    /// Slice the view along a dimension, consuming it.
+    ///
+    /// See [`TensorView::slice`].
+    pub fn slice(
+        self,
+        dim: usize,
+        range: Range<usize>,
+        step: usize,
+    ) -> Result<TensorViewMut<'a, T, N, A>, TensorError> {
+        let (shape, strides, offset) =
+            slice_layout(self.shape, self.strides, self.offset, dim, range, step)?;
+        Ok(TensorViewMut {
+            storage: self.storage,
+            shape,
+            strides,
+            offset,
+        })
+    }
+
+    /// Narrow the view along a dimension, consuming it.
+    ///
+    /// See [`TensorView::narrow`].
+    pub fn narrow(
+        self,
+        dim: usize,
+        start: usize,
+        len: usize,
+    ) -> Result<TensorViewMut<'a, T, N, A>, TensorError> {
+        self.slice(dim, narrow_range(start, len)?, 1)
+    }
+
+    /// Select a single index along a dimension, consuming the view.
+    ///
+    /// See [`TensorView::select`].
+    pub fn select<const M: usize>(
+        self,
+        dim: usize,
+        index: usize,
+    ) -> Result<TensorViewMut<'a, T, M, A>, TensorError> {
+        check_rank::<N, M>(N.saturating_sub(1))?;
+        check_dim::<N>(dim)?;
+        if index >= self.shape[dim] {
+            return Err(TensorError::IndexOutOfBounds(index));
+        }
+        let (shape, strides) = remove_dim(self.shape, self.strides, dim);
+        Ok(TensorViewMut {
+            storage: self.storage,
+            shape,
+            strides,
+            offset: self.offset + index * self.strides[dim],
+        })
+    }
+
+    /// Swap two dimensions of the view, consuming it.
+    ///
+    /// See [`TensorView::transpose`].
+    pub fn transpose(
+        self,
+        dim0: usize,
+        dim1: usize,
+    ) -> Result<TensorViewMut<'a, T, N, A>, TensorError> {
+        check_dim::<N>(dim0)?;
+        check_dim::<N>(dim1)?;
+        let mut shape = self.shape;
+        let mut strides = self.strides;
+        shape.swap(dim0, dim1);
+        strides.swap(dim0, dim1);
+        Ok(TensorViewMut {
+            storage: self.storage,
+            shape,
+            strides,
+            offset: self.offset,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,6 +754,7 @@ mod tests {
             storage: &storage,
             shape: [8],
             strides: [1],
+            offset: 0,
         };
 
         assert_eq!(view.numel(), 8);
@@ -131,4 +784,115 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_tensor_view_slice() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..24).collect();
+        let t = Tensor::<u8, 3, _>::from_shape_vec([4, 3, 2], data, CpuAllocator)?;
+
+        let view = t.view().slice(0, 1..4, 2)?;
+        assert_eq!(view.shape, [2, 3, 2]);
+        assert_eq!(view.strides, [12, 2, 1]);
+        assert_eq!(view.offset, 6);
+        assert!(!view.is_contiguous());
+        assert_eq!(
+            view.iter().copied().collect::<Vec<_>>(),
+            vec![6, 7, 8, 9, 10, 11, 18, 19, 20, 21, 22, 23]
+        );
+
+        let view = view.narrow(1, 1, 2)?.slice(2, 1..2, 1)?;
+        assert_eq!(view.shape, [2, 2, 1]);
+        assert_eq!(view.get([1, 1, 0]), Some(&23));
+        assert_eq!(view.get([2, 0, 0]), None);
+        assert_eq!(view.as_contiguous().as_slice(), vec![9, 11, 21, 23]);
+
+        assert!(t.view().slice(0, 2..5, 1).is_err());
+        assert!(t.view().slice(3, 0..1, 1).is_err());
+        assert!(t.view().slice(0, 0..1, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tensor_view_contiguous_slice() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..12).collect();
+        let t = Tensor::<u8, 2, _>::from_shape_vec([4, 3], data, CpuAllocator)?;
+        let view = t.view().narrow(0, 1, 2)?;
+        assert!(view.is_contiguous());
+        assert_eq!(view.as_slice(), &[3, 4, 5, 6, 7, 8]);
+        assert_eq!(view.reshape([6])?.as_slice(), &[3, 4, 5, 6, 7, 8]);
+        assert!(view.transpose(0, 1)?.reshape([6]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tensor_view_select_squeeze() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..12).collect();
+        let t = Tensor::<u8, 3, _>::from_shape_vec([2, 3, 2], data, CpuAllocator)?;
+
+        let channel = t.view().select::<2>(2, 1)?;
+        assert_eq!(channel.shape, [2, 3]);
+        assert_eq!(channel.strides, [6, 2]);
+        assert_eq!(
+            channel.iter().copied().collect::<Vec<_>>(),
+            vec![1, 3, 5, 7, 9, 11]
+        );
+        assert!(t.view().select::<2>(2, 2).is_err());
+        assert!(t.view().select::<3>(2, 0).is_err());
+
+        let unsqueezed = channel.unsqueeze::<3>(0)?;
+        assert_eq!(unsqueezed.shape, [1, 2, 3]);
+        let squeezed = unsqueezed.squeeze::<2>(0)?;
+        assert_eq!(squeezed.shape, [2, 3]);
+        assert_eq!(squeezed.strides, [6, 2]);
+        assert!(squeezed.squeeze::<1>(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tensor_view_transpose() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..6).collect();
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+        let view = t.view().transpose(0, 1)?;
+        assert_eq!(view.shape, [3, 2]);
+        assert_eq!(view.strides, [1, 3]);
+        assert!(!view.is_contiguous());
+        assert_eq!(
+            view.iter().copied().collect::<Vec<_>>(),
+            vec![0, 3, 1, 4, 2, 5]
+        );
+        assert!(t.view().permute([0, 0]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tensor_view_mut() -> Result<(), TensorError> {
+        let mut t = Tensor::<u8, 3, _>::zeros([3, 3, 2], CpuAllocator);
+
+        // fill the second channel of the bottom right 2x2 region
+        t.view_mut()
+            .narrow(0, 1, 2)?
+            .narrow(1, 1, 2)?
+            .select::<2>(2, 1)?
+            .fill(1);
+        assert_eq!(
+            t.as_slice(),
+            vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 1]
+        );
+
+        let src = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator)?;
+        let mut roi = t.view_mut().select::<2>(2, 0)?.narrow(0, 0, 2)?;
+        roi = roi.narrow(1, 0, 2)?.transpose(0, 1)?;
+        roi.copy_from(&src.view())?;
+        assert_eq!(roi.get_mut([0, 1]).copied(), Some(2));
+        assert_eq!(
+            t.as_slice(),
+            vec![1, 0, 3, 0, 0, 0, 2, 0, 4, 1, 0, 1, 0, 0, 0, 1, 0, 1]
+        );
+
+        // the end of the range overflows
+        assert!(t.view_mut().narrow(0, 2, usize::MAX).is_err());
+        assert!(t.view().narrow(1, usize::MAX, 1).is_err());
+        Ok(())
+    }
 }
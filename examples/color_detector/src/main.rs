@@ -9,6 +9,9 @@ use kornia::io::functional as F;
 struct Args {
     #[arg(short, long)]
     image_path: PathBuf,
+
+    #[arg(short, long, default_value = "100")]
+    min_area: usize,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -29,6 +32,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut mask = Image::<u8, 1>::from_size_val(hsv.size(), 0)?;
     imgproc::threshold::in_range(&hsv, &mut mask, &[40.0, 110.0, 50.0], &[90.0, 255.0, 255.0])?;
 
+    // find the blobs in the mask and drop the small ones
+    let mut labels = Image::<u32, 1>::from_size_val(mask.size(), 0)?;
+    let stats = imgproc::connected_components::connected_components(
+        &mask,
+        &mut labels,
+        imgproc::connected_components::Connectivity::Eight,
+    )?;
+    let blobs = imgproc::connected_components::filter_components_by_area(
+        &mut labels,
+        &stats,
+        args.min_area,
+    );
+    println!("Found {} blobs", blobs.len());
+
     // apply the mask to the image
     let mut out = Image::<u8, 3>::from_size_val(mask.size(), 0)?;
     imgproc::core::bitwise_and(&rgb, &rgb, &mut out, &mask)?;
@@ -56,5 +73,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &rerun::Image::from_elements(out.as_slice(), out.size().into(), rerun::ColorModel::RGB),
     )?;
 
+    rec.log(
+        "output/blobs",
+        &rerun::Boxes2D::from_mins_and_sizes(
+            blobs
+                .iter()
+                .map(|b| (b.bbox.x as f32, b.bbox.y as f32))
+                .collect::<Vec<_>>(),
+            blobs
+                .iter()
+                .map(|b| (b.bbox.width as f32, b.bbox.height as f32))
+                .collect::<Vec<_>>(),
+        ),
+    )?;
+
+    rec.log(
+        "output/centroids",
+        &rerun::Points2D::new(
+            blobs
+                .iter()
+                .map(|b| (b.centroid[0] as f32, b.centroid[1] as f32)),
+        ),
+    )?;
+
     Ok(())
 }
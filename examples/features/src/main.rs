@@ -5,7 +5,10 @@ use std::sync::{
 
 use kornia::{
     image::{ops, Image},
-    imgproc,
+    imgproc::{
+        self,
+        features::{Keypoint, OrbDescriptor},
+    },
     io::stream::V4L2CameraConfig,
 };
 
@@ -37,8 +40,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // preallocate images
     let mut img_f32 = Image::from_size_val(size, 0f32)?;
     let mut gray = Image::from_size_val(size, 0f32)?;
-    let mut hessian = Image::from_size_val(size, 0f32)?;
-    let mut corners = Image::from_size_val(size, 0f32)?;
+
+    // the ORB detector configuration and the features of the previous frame
+    let orb_config = imgproc::features::OrbConfig::default();
+    let mut previous: Option<(Vec<Keypoint>, Vec<OrbDescriptor>)> = None;
 
     // start grabbing frames from the camera
     while !cancel_token.load(Ordering::SeqCst) {
@@ -50,11 +55,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ops::cast_and_scale(&img, &mut img_f32, 1. / 255.)?;
         imgproc::color::gray_from_rgb(&img_f32, &mut gray)?;
 
-        // compute the hessian response
-        imgproc::features::hessian_response(&gray, &mut hessian)?;
-
-        // compute the corners
-        imgproc::threshold::threshold_binary(&hessian, &mut corners, 0.01, 1.0)?;
+        // detect the keypoints and compute their descriptors
+        let (keypoints, descriptors) =
+            imgproc::features::orb_detect_and_compute(&gray, &orb_config)?;
 
         // log the image
         rec.log_static(
@@ -62,15 +65,35 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             &rerun::Image::from_elements(img.as_slice(), img.size().into(), rerun::ColorModel::RGB),
         )?;
 
-        // log the corners
+        // log the keypoints, with a radius proportional to their scale
         rec.log_static(
-            "corners",
-            &rerun::Image::from_elements(
-                corners.as_slice(),
-                corners.size().into(),
-                rerun::ColorModel::L,
-            ),
+            "image/keypoints",
+            &rerun::Points2D::new(keypoints.iter().map(|k| (k.position[0], k.position[1])))
+                .with_radii(keypoints.iter().map(|k| 2.0 * k.scale)),
         )?;
+
+        // match the descriptors with the ones of the previous frame and log the motion of the
+        // matched keypoints
+        if let Some((previous_keypoints, previous_descriptors)) = &previous {
+            let matches = imgproc::features::match_descriptors(
+                previous_descriptors,
+                &descriptors,
+                Some(0.8),
+                true,
+            );
+
+            rec.log_static(
+                "image/matches",
+                &rerun::LineStrips2D::new(matches.iter().map(|m| {
+                    [
+                        previous_keypoints[m.query].position,
+                        keypoints[m.train].position,
+                    ]
+                })),
+            )?;
+        }
+
+        previous = Some((keypoints, descriptors));
     }
 
     // NOTE: this is important to close the webcam properly, otherwise the app will hang
@@ -6,7 +6,7 @@ use std::sync::{
 
 use kornia::{
     image::{ops, Image},
-    imgproc,
+    imgproc::{self, filter::BorderType},
     io::stream::V4L2CameraConfig,
 };
 
@@ -75,7 +75,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         match args.filter.to_lowercase().as_str() {
             "box" => {
-                imgproc::filter::box_blur(&img_f32, &mut img_f32_filtered, (args.kx, args.ky))?;
+                imgproc::filter::box_blur(
+                    &img_f32,
+                    &mut img_f32_filtered,
+                    (args.kx, args.ky),
+                    BorderType::Reflect101,
+                )?;
             }
             "gaussian" => {
                 let sigma_x = args.sigma_x.unwrap_or(0.5);
@@ -85,11 +90,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     &mut img_f32_filtered,
                     (args.kx, args.ky),
                     (sigma_x, sigma_y),
+                    BorderType::Reflect101,
                 )?;
             }
             "sobel" => {
                 let mut img_f32_filtered_sobel = Image::from_size_val(size, 0f32)?;
-                imgproc::filter::sobel(&img_f32, &mut img_f32_filtered_sobel, args.kx)?;
+                imgproc::filter::sobel(
+                    &img_f32,
+                    &mut img_f32_filtered_sobel,
+                    args.kx,
+                    BorderType::Reflect101,
+                )?;
 
                 // we need to normalize the sobel filter to 0-1
                 imgproc::normalize::normalize_min_max(
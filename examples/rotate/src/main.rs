@@ -39,6 +39,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             &mut output,
             &rotation_matrix,
             imgproc::interpolation::InterpolationMode::Bilinear,
+            imgproc::interpolation::BorderType::Constant(0.0),
         )?;
 
         imgproc::normalize::normalize_min_max(&output, &mut output_norm, 0.0, 255.0)?;
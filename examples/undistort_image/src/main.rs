@@ -69,6 +69,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &map_x,
         &map_y,
         imgproc::interpolation::InterpolationMode::Bilinear,
+        imgproc::interpolation::BorderType::Constant(0.0),
     )?;
 
     // create a Rerun recording stream
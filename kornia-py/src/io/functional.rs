@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
+use numpy::PyUntypedArray;
 use pyo3::prelude::*;
 
 use crate::image::{FromPyImage, PyImage, ToPyImage};
+use crate::tensor::{FromPyArrayDyn, ToPyArrayDyn};
 use kornia_image::Image;
-use kornia_io::functional as F;
+use kornia_io::{functional as F, npy, safetensors};
+use kornia_tensor::DynTensor;
 
 #[pyfunction]
 pub fn read_image_jpeg(file_path: &str) -> PyResult<PyImage> {
@@ -26,3 +31,52 @@ pub fn read_image_any(file_path: &str) -> PyResult<PyImage> {
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyFileExistsError, _>(format!("{}", e)))?;
     Ok(image.to_pyimage())
 }
+
+#[pyfunction]
+pub fn read_npy(py: Python<'_>, file_path: &str) -> PyResult<PyObject> {
+    let tensor = npy::read_npy_dyn(file_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyFileExistsError, _>(format!("{}", e)))?;
+    tensor.to_pyarray_dyn(py)
+}
+
+#[pyfunction]
+pub fn write_npy(file_path: &str, array: &Bound<'_, PyUntypedArray>) -> PyResult<()> {
+    let tensor = DynTensor::from_pyarray_dyn(array)?;
+    npy::write_npy_dyn(file_path, &tensor)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+    Ok(())
+}
+
+#[pyfunction]
+pub fn read_safetensors(py: Python<'_>, file_path: &str) -> PyResult<HashMap<String, PyObject>> {
+    let file = safetensors::SafeTensorsFile::open(file_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyFileExistsError, _>(format!("{}", e)))?;
+    file.names()
+        .into_iter()
+        .map(|name| {
+            let tensor = file
+                .dyn_tensor(&name)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+            Ok((name, tensor.to_pyarray_dyn(py)?))
+        })
+        .collect()
+}
+
+#[pyfunction]
+pub fn write_safetensors(
+    file_path: &str,
+    tensors: HashMap<String, Bound<'_, PyUntypedArray>>,
+) -> PyResult<()> {
+    let tensors = tensors
+        .iter()
+        .map(|(name, array)| Ok((name.as_str(), DynTensor::from_pyarray_dyn(array)?)))
+        .collect::<PyResult<Vec<_>>>()?;
+    let mut writer = safetensors::SafeTensorsWriter::new();
+    for (name, tensor) in tensors.iter() {
+        writer.add_dyn_tensor(name, tensor);
+    }
+    writer
+        .write(file_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+    Ok(())
+}
@@ -6,11 +6,15 @@ mod image;
 mod io;
 mod pointcloud;
 mod resize;
+mod tensor;
 mod warp;
 
 use crate::icp::{PyICPConvergenceCriteria, PyICPResult};
 use crate::image::PyImageSize;
-use crate::io::functional::{read_image_any, read_image_jpeg, write_image_jpeg};
+use crate::io::functional::{
+    read_image_any, read_image_jpeg, read_npy, read_safetensors, write_image_jpeg, write_npy,
+    write_safetensors,
+};
 use crate::io::jpeg::{PyImageDecoder, PyImageEncoder};
 use pyo3::prelude::*;
 
@@ -35,6 +39,10 @@ pub fn kornia_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(read_image_jpeg, m)?)?;
     m.add_function(wrap_pyfunction!(write_image_jpeg, m)?)?;
     m.add_function(wrap_pyfunction!(read_image_any, m)?)?;
+    m.add_function(wrap_pyfunction!(read_npy, m)?)?;
+    m.add_function(wrap_pyfunction!(write_npy, m)?)?;
+    m.add_function(wrap_pyfunction!(read_safetensors, m)?)?;
+    m.add_function(wrap_pyfunction!(write_safetensors, m)?)?;
     m.add_function(wrap_pyfunction!(resize::resize, m)?)?;
     m.add_function(wrap_pyfunction!(warp::warp_affine, m)?)?;
     m.add_function(wrap_pyfunction!(warp::warp_perspective, m)?)?;
@@ -6,7 +6,7 @@ use kornia_imgproc::{interpolation::InterpolationMode, resize::resize_fast};
 
 #[pyfunction]
 pub fn resize(image: PyImage, new_size: (usize, usize), interpolation: &str) -> PyResult<PyImage> {
-    let image = Image::from_pyimage(image)
+    let image: Image<u8, 3> = Image::from_pyimage(image)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
     let new_size = ImageSize {
@@ -17,6 +17,9 @@ pub fn resize(image: PyImage, new_size: (usize, usize), interpolation: &str) ->
     let interpolation = match interpolation.to_lowercase().as_str() {
         "nearest" => InterpolationMode::Nearest,
         "bilinear" => InterpolationMode::Bilinear,
+        "bicubic" => InterpolationMode::Bicubic,
+        "lanczos" => InterpolationMode::Lanczos,
+        "area" => InterpolationMode::Area,
         _ => {
             return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                 "Invalid interpolation mode",
@@ -0,0 +1,89 @@
+use numpy::{
+    dtype, Element, PyArray1, PyArrayDescrMethods, PyArrayDyn, PyArrayMethods, PyUntypedArray,
+    PyUntypedArrayMethods,
+};
+use pyo3::prelude::*;
+
+use kornia_tensor::{DType, DTypeElement, DynTensor};
+
+/// Trait to convert a dynamic tensor to a numpy array of the same dtype and shape
+pub trait ToPyArrayDyn {
+    fn to_pyarray_dyn(&self, py: Python<'_>) -> PyResult<PyObject>;
+}
+
+fn to_pyarray<T: Element + DTypeElement>(py: Python<'_>, tensor: &DynTensor) -> PyResult<PyObject> {
+    let data = tensor
+        .as_slice::<T>()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+    let array = PyArray1::from_slice(py, data).reshape(tensor.shape())?;
+    Ok(array.into_any().unbind())
+}
+
+impl ToPyArrayDyn for DynTensor {
+    fn to_pyarray_dyn(&self, py: Python<'_>) -> PyResult<PyObject> {
+        match self.dtype() {
+            DType::U8 => to_pyarray::<u8>(py, self),
+            DType::I8 => to_pyarray::<i8>(py, self),
+            DType::U16 => to_pyarray::<u16>(py, self),
+            DType::I16 => to_pyarray::<i16>(py, self),
+            DType::U32 => to_pyarray::<u32>(py, self),
+            DType::I32 => to_pyarray::<i32>(py, self),
+            DType::U64 => to_pyarray::<u64>(py, self),
+            DType::I64 => to_pyarray::<i64>(py, self),
+            DType::F32 => to_pyarray::<f32>(py, self),
+            DType::F64 => to_pyarray::<f64>(py, self),
+            dtype => Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                "Unsupported dtype {dtype}"
+            ))),
+        }
+    }
+}
+
+/// Trait to convert a numpy array of any dtype and shape to a dynamic tensor
+pub trait FromPyArrayDyn {
+    fn from_pyarray_dyn(array: &Bound<'_, PyUntypedArray>) -> PyResult<DynTensor>;
+}
+
+fn from_pyarray<T: Element + DTypeElement>(
+    array: &Bound<'_, PyUntypedArray>,
+) -> PyResult<DynTensor> {
+    let array = array.downcast::<PyArrayDyn<T>>()?.readonly();
+    let array = array.as_array();
+    // iterate in logical order to also support non contiguous arrays
+    let data = array.iter().copied().collect::<Vec<_>>();
+    DynTensor::from_shape_vec(array.shape().to_vec(), data)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))
+}
+
+impl FromPyArrayDyn for DynTensor {
+    fn from_pyarray_dyn(array: &Bound<'_, PyUntypedArray>) -> PyResult<DynTensor> {
+        let py = array.py();
+        let descr = array.dtype();
+        if descr.is_equiv_to(&dtype::<u8>(py)) {
+            from_pyarray::<u8>(array)
+        } else if descr.is_equiv_to(&dtype::<i8>(py)) {
+            from_pyarray::<i8>(array)
+        } else if descr.is_equiv_to(&dtype::<u16>(py)) {
+            from_pyarray::<u16>(array)
+        } else if descr.is_equiv_to(&dtype::<i16>(py)) {
+            from_pyarray::<i16>(array)
+        } else if descr.is_equiv_to(&dtype::<u32>(py)) {
+            from_pyarray::<u32>(array)
+        } else if descr.is_equiv_to(&dtype::<i32>(py)) {
+            from_pyarray::<i32>(array)
+        } else if descr.is_equiv_to(&dtype::<u64>(py)) {
+            from_pyarray::<u64>(array)
+        } else if descr.is_equiv_to(&dtype::<i64>(py)) {
+            from_pyarray::<i64>(array)
+        } else if descr.is_equiv_to(&dtype::<f32>(py)) {
+            from_pyarray::<f32>(array)
+        } else if descr.is_equiv_to(&dtype::<f64>(py)) {
+            from_pyarray::<f64>(array)
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                "Unsupported dtype {}",
+                descr
+            )))
+        }
+    }
+}
@@ -2,15 +2,43 @@ use pyo3::prelude::*;
 
 use crate::image::{FromPyImage, PyImage, ToPyImage};
 use kornia_image::{Image, ImageSize};
-use kornia_imgproc::interpolation::InterpolationMode;
+use kornia_imgproc::interpolation::{BorderType, InterpolationMode};
 use kornia_imgproc::warp;
 
+fn parse_interpolation(interpolation: &str) -> PyResult<InterpolationMode> {
+    match interpolation.to_lowercase().as_str() {
+        "nearest" => Ok(InterpolationMode::Nearest),
+        "bilinear" => Ok(InterpolationMode::Bilinear),
+        "bicubic" => Ok(InterpolationMode::Bicubic),
+        "lanczos" => Ok(InterpolationMode::Lanczos),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Invalid interpolation mode",
+        )),
+    }
+}
+
+fn parse_border(border: &str, border_value: f32) -> PyResult<BorderType> {
+    match border.to_lowercase().as_str() {
+        "constant" => Ok(BorderType::Constant(border_value)),
+        "replicate" => Ok(BorderType::Replicate),
+        "reflect" => Ok(BorderType::Reflect),
+        "reflect101" => Ok(BorderType::Reflect101),
+        "wrap" => Ok(BorderType::Wrap),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Invalid border type",
+        )),
+    }
+}
+
 #[pyfunction]
+#[pyo3(signature = (image, m, new_size, interpolation, border="constant", border_value=0.0))]
 pub fn warp_affine(
     image: PyImage,
     m: [f32; 6],
     new_size: (usize, usize),
     interpolation: &str,
+    border: &str,
+    border_value: f32,
 ) -> PyResult<PyImage> {
     // have to add annotation Image<u8, 3>, otherwise the compiler will complain
     // NOTE: do we support images with channels != 3?
@@ -22,41 +50,28 @@ pub fn warp_affine(
         width: new_size.1,
     };
 
-    let interpolation = match interpolation.to_lowercase().as_str() {
-        "nearest" => InterpolationMode::Nearest,
-        "bilinear" => InterpolationMode::Bilinear,
-        _ => {
-            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "Invalid interpolation mode",
-            ))
-        }
-    };
-
-    // we need to cast to f32 for now since kornia-rs interpolation function only works with f32
-    let image = image
-        .cast::<f32>()
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
-
-    let mut image_warped = Image::from_size_val(new_size, 0f32)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+    let interpolation = parse_interpolation(interpolation)?;
+    let border = parse_border(border, border_value)?;
 
-    warp::warp_affine(&image, &mut image_warped, &m, interpolation)
+    let mut image_warped = Image::from_size_val(new_size, 0u8)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
-    // NOTE: for bicubic interpolation (not implemented yet), f32 may overshoot 255
-    let image_warped = image_warped
-        .cast::<u8>()
+    // the interpolated values are rounded and saturated to u8
+    warp::warp_affine(&image, &mut image_warped, &m, interpolation, border)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
     Ok(image_warped.to_pyimage())
 }
 
 #[pyfunction]
+#[pyo3(signature = (image, m, new_size, interpolation, border="constant", border_value=0.0))]
 pub fn warp_perspective(
     image: PyImage,
     m: [f32; 9],
     new_size: (usize, usize),
     interpolation: &str,
+    border: &str,
+    border_value: f32,
 ) -> PyResult<PyImage> {
     let image: Image<u8, 3> = Image::from_pyimage(image)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
@@ -66,28 +81,13 @@ pub fn warp_perspective(
         width: new_size.1,
     };
 
-    let interpolation = match interpolation.to_lowercase().as_str() {
-        "nearest" => InterpolationMode::Nearest,
-        "bilinear" => InterpolationMode::Bilinear,
-        _ => {
-            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "Invalid interpolation mode",
-            ))
-        }
-    };
-
-    let image = image
-        .cast::<f32>()
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
-
-    let mut image_warped = Image::from_size_val(new_size, 0f32)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+    let interpolation = parse_interpolation(interpolation)?;
+    let border = parse_border(border, border_value)?;
 
-    warp::warp_perspective(&image, &mut image_warped, &m, interpolation)
+    let mut image_warped = Image::from_size_val(new_size, 0u8)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
-    let image_warped = image_warped
-        .cast::<u8>()
+    warp::warp_perspective(&image, &mut image_warped, &m, interpolation, border)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
     Ok(image_warped.to_pyimage())
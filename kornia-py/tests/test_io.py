@@ -92,3 +92,30 @@ def test_write_read_jpeg(tmpdir):
     # check the image properties
     assert img_read.shape == (4, 5, 3)
     np.allclose(img, img_read)
+
+
+def test_npy_roundtrip(tmp_path: Path):
+    arr = np.arange(24, dtype=np.float32).reshape(2, 3, 4)
+    file_path = tmp_path / "arr.npy"
+    K.write_npy(str(file_path), arr)
+
+    # the file is readable by numpy, and the dtype and shape are preserved
+    assert np.array_equal(np.load(file_path), arr)
+    arr2 = K.read_npy(str(file_path))
+    assert arr2.dtype == np.float32
+    assert np.array_equal(arr2, arr)
+
+
+def test_safetensors_roundtrip(tmp_path: Path):
+    tensors = {
+        "weights": np.ones((4, 2), dtype=np.float64),
+        "labels": np.arange(4, dtype=np.int64),
+    }
+    file_path = tmp_path / "model.safetensors"
+    K.write_safetensors(str(file_path), tensors)
+
+    tensors2 = K.read_safetensors(str(file_path))
+    assert sorted(tensors2.keys()) == ["labels", "weights"]
+    for name, arr in tensors.items():
+        assert tensors2[name].dtype == arr.dtype
+        assert np.array_equal(tensors2[name], arr)
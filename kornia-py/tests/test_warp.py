@@ -22,6 +22,9 @@ def test_warp_affine():
     )
     assert (img_transformed == img).all()
 
+    img_transformed = K.warp_affine(img, affine_matrix, img.shape[:2], "bicubic")
+    assert (img_transformed == img).all()
+
 
 def test_warp_perspective():
     img_path: Path = DATA_DIR / "dog.jpeg"
@@ -35,3 +38,26 @@ def test_warp_perspective():
         img, perspective_matrix, img.shape[:2], "bilinear"
     )
     assert (img_transformed == img).all()
+
+
+def test_warp_affine_border():
+    img = np.arange(16, dtype=np.uint8).reshape(4, 4, 1).repeat(3, axis=2)
+
+    # shift left by 1 pixel
+    affine_matrix = (1.0, 0.0, -1.0, 0.0, 1.0, 0.0)
+
+    img_transformed: np.ndarray = K.warp_affine(
+        img, affine_matrix, img.shape[:2], "bilinear"
+    )
+    assert (img_transformed[:, :3] == img[:, 1:]).all()
+    assert (img_transformed[:, 3] == 0).all()
+
+    img_transformed = K.warp_affine(
+        img, affine_matrix, img.shape[:2], "bilinear", "constant", 255.0
+    )
+    assert (img_transformed[:, 3] == 255).all()
+
+    img_transformed = K.warp_affine(
+        img, affine_matrix, img.shape[:2], "bilinear", border="replicate"
+    )
+    assert (img_transformed[:, 3] == img[:, 3]).all()